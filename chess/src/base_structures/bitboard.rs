@@ -34,6 +34,8 @@ impl Bitboard {
     pub const FILES_AB: Self = Bitboard::FILE_A.or(Bitboard::FILE_B);
     pub const FILES_GH: Self = Bitboard::FILE_G.or(Bitboard::FILE_H);
 
+    pub const CENTER: Self = Self(0x0000001818000000);
+
     pub const FULL: Self = Self(0xFFFFFFFFFFFFFFFF);
     pub const EMPTY: Self = Self(0);
 
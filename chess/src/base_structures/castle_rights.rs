@@ -44,12 +44,25 @@ impl CastleRights {
         self.rooks[index]
     }
 
+    //Castling that starts from a shuffled back rank, which only king takes rook notation can express
+    pub fn is_chess960(&self) -> bool {
+        (0..4).any(|index| {
+            let corner_file = if index % 2 == 0 { 0 } else { 7 };
+            self.has_right(0b1000 >> index)
+                && (self.kings[index / 2].file() != 4 || self.rooks[index].file() != corner_file)
+        })
+    }
+
     #[inline]
     pub fn get_castle_mask(&self) -> [u8; 64] {
         let mut result = [0u8; 64];
 
-        result[usize::from(self.kings[0])] = 0b1100;
-        result[usize::from(self.kings[1])] = 0b0011;
+        //Horde side has no king to lose its rights with
+        for (side, rights) in [0b1100, 0b0011].into_iter().enumerate() {
+            if self.kings[side] != Square::NULL {
+                result[usize::from(self.kings[side])] = rights;
+            }
+        }
 
         for idx in 0..4 {
             if self.rooks[idx] != Square::NULL {
@@ -0,0 +1,167 @@
+use crate::FEN;
+
+const KNIGHT_PLACEMENTS: [(usize, usize); 10] = [
+    (0, 1),
+    (0, 2),
+    (0, 3),
+    (0, 4),
+    (1, 2),
+    (1, 3),
+    (1, 4),
+    (2, 3),
+    (2, 4),
+    (3, 4),
+];
+
+impl FEN {
+    pub fn chess960(index: u16) -> Self {
+        Self::double_fischer_random(index, index)
+    }
+
+    pub fn double_fischer_random(white_index: u16, black_index: u16) -> Self {
+        assert!(white_index < 960 && black_index < 960);
+
+        let white_rank = back_rank(white_index);
+        let black_rank = back_rank(black_index);
+
+        let castle_rights = format!(
+            "{}{}",
+            rook_files(&white_rank).to_ascii_uppercase(),
+            rook_files(&black_rank)
+        );
+
+        Self::from(format!(
+            "{}/pppppppp/8/8/8/8/PPPPPPPP/{} w {castle_rights} - 0 1",
+            black_rank.iter().collect::<String>(),
+            white_rank.iter().collect::<String>().to_ascii_uppercase()
+        ))
+    }
+
+    pub fn chess960_index(&self) -> Option<u16> {
+        match self.double_fischer_random_index()? {
+            (white_index, black_index) if white_index == black_index => Some(white_index),
+            _ => None,
+        }
+    }
+
+    pub fn double_fischer_random_index(&self) -> Option<(u16, u16)> {
+        let white_rank = expand_rank(&self.board[7])?;
+        let black_rank = expand_rank(&self.board[0])?;
+
+        if white_rank.iter().any(|char| !char.is_ascii_uppercase())
+            || black_rank.iter().any(|char| !char.is_ascii_lowercase())
+        {
+            return None;
+        }
+
+        let white_rank = white_rank.map(|char| char.to_ascii_lowercase());
+
+        Some((back_rank_index(&white_rank)?, back_rank_index(&black_rank)?))
+    }
+}
+
+fn back_rank(index: u16) -> [char; 8] {
+    let mut rank = [' '; 8];
+    let mut index = index as usize;
+
+    rank[index % 4 * 2 + 1] = 'b';
+    index /= 4;
+
+    rank[index % 4 * 2] = 'b';
+    index /= 4;
+
+    let queen_index = index % 6;
+    index /= 6;
+
+    let mut empty_squares = (0..8).filter(|&file| rank[file] == ' ');
+    let queen_file = empty_squares.nth(queen_index).unwrap();
+    rank[queen_file] = 'q';
+
+    let empty_squares = (0..8).filter(|&file| rank[file] == ' ').collect::<Vec<_>>();
+    let (first_knight, second_knight) = KNIGHT_PLACEMENTS[index];
+    rank[empty_squares[first_knight]] = 'n';
+    rank[empty_squares[second_knight]] = 'n';
+
+    for (file, piece) in (0..8)
+        .filter(|&file| rank[file] == ' ')
+        .collect::<Vec<_>>()
+        .into_iter()
+        .zip(['r', 'k', 'r'])
+    {
+        rank[file] = piece;
+    }
+
+    rank
+}
+
+fn back_rank_index(rank: &[char; 8]) -> Option<u16> {
+    let files = |piece: char| {
+        (0..8)
+            .filter(|&file| rank[file] == piece)
+            .collect::<Vec<_>>()
+    };
+
+    let bishops = files('b');
+    let queens = files('q');
+    let knights = files('n');
+    let rooks = files('r');
+    let kings = files('k');
+
+    if bishops.len() != 2
+        || queens.len() != 1
+        || knights.len() != 2
+        || rooks.len() != 2
+        || kings.len() != 1
+        || !(rooks[0] < kings[0] && kings[0] < rooks[1])
+        || bishops[0] % 2 == bishops[1] % 2
+    {
+        return None;
+    }
+
+    let (light_bishop, dark_bishop) = if bishops[0] % 2 == 1 {
+        (bishops[0], bishops[1])
+    } else {
+        (bishops[1], bishops[0])
+    };
+
+    let queen_index = (0..queens[0]).filter(|&file| rank[file] != 'b').count();
+
+    let empty_squares = (0..8)
+        .filter(|&file| rank[file] != 'b' && rank[file] != 'q')
+        .collect::<Vec<_>>();
+    let first_knight = empty_squares.iter().position(|&file| file == knights[0])?;
+    let second_knight = empty_squares.iter().position(|&file| file == knights[1])?;
+    let knight_index = KNIGHT_PLACEMENTS
+        .iter()
+        .position(|&placement| placement == (first_knight, second_knight))?;
+
+    Some((((knight_index * 6 + queen_index) * 4 + dark_bishop / 2) * 4 + light_bishop / 2) as u16)
+}
+
+fn rook_files(rank: &[char; 8]) -> String {
+    let rooks = (0..8).filter(|&file| rank[file] == 'r');
+    rooks
+        .rev()
+        .map(|file| (b'a' + file as u8) as char)
+        .collect()
+}
+
+fn expand_rank(rank: &str) -> Option<[char; 8]> {
+    let mut result = [' '; 8];
+    let mut file = 0;
+
+    for char in rank.chars() {
+        if let Some(digit) = char.to_digit(10) {
+            file += digit as usize;
+        } else {
+            *result.get_mut(file)? = char;
+            file += 1;
+        }
+    }
+
+    if file == 8 {
+        Some(result)
+    } else {
+        None
+    }
+}
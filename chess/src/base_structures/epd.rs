@@ -0,0 +1,343 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{BoardError, ChessBoard, FenError, Move, FEN};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Epd {
+    fen: FEN,
+    operations: Vec<(String, Vec<String>)>,
+}
+
+impl Epd {
+    pub fn new(fen: FEN) -> Self {
+        Self {
+            fen,
+            operations: Vec::new(),
+        }
+    }
+
+    pub fn parse(epd_string: &str) -> std::result::Result<Self, EpdError> {
+        let mut fields = Vec::new();
+        let mut rest = epd_string.trim();
+
+        while fields.len() < 4 {
+            let (field, remainder) = split_token(rest).ok_or(EpdError::MissingFields)?;
+            fields.push(field);
+            rest = remainder;
+        }
+
+        //Some suites store the clocks as bare fields instead of hmvc/fmvn operations
+        let mut clocks = Vec::new();
+        while clocks.len() < 2 {
+            match split_token(rest) {
+                Some((token, remainder)) if token.chars().all(|char| char.is_ascii_digit()) => {
+                    clocks.push(token);
+                    rest = remainder;
+                }
+                _ => break,
+            }
+        }
+
+        let mut operations = parse_operations(rest)?;
+
+        let find_operand = |opcode: &str| {
+            operations
+                .iter()
+                .find(|(name, _)| name == opcode)
+                .and_then(|(_, operands)| operands.first())
+                .map(|operand| operand.as_str())
+        };
+
+        let half_moves = find_operand("hmvc")
+            .or(clocks.first().copied())
+            .unwrap_or("0");
+        let full_moves = find_operand("fmvn")
+            .or(clocks.get(1).copied())
+            .unwrap_or("1");
+
+        let fen = FEN::parse(&format!("{} {half_moves} {full_moves}", fields.join(" ")))
+            .map_err(EpdError::Fen)?;
+
+        operations.retain(|(opcode, _)| opcode != "hmvc" && opcode != "fmvn");
+
+        Ok(Self { fen, operations })
+    }
+
+    #[inline]
+    pub fn fen(&self) -> &FEN {
+        &self.fen
+    }
+
+    #[inline]
+    pub fn board(&self) -> std::result::Result<ChessBoard, BoardError> {
+        ChessBoard::from_fen_checked(&self.fen)
+    }
+
+    pub fn operations(&self) -> impl Iterator<Item = (&str, &[String])> {
+        self.operations
+            .iter()
+            .map(|(opcode, operands)| (opcode.as_str(), operands.as_slice()))
+    }
+
+    pub fn operation(&self, opcode: &str) -> Option<&[String]> {
+        self.operations
+            .iter()
+            .find(|(name, _)| name == opcode)
+            .map(|(_, operands)| operands.as_slice())
+    }
+
+    pub fn set_operation(&mut self, opcode: &str, operands: Vec<String>) {
+        match self.operations.iter_mut().find(|(name, _)| name == opcode) {
+            Some((_, current)) => *current = operands,
+            None => self.operations.push((opcode.to_string(), operands)),
+        }
+    }
+
+    pub fn remove_operation(&mut self, opcode: &str) {
+        self.operations.retain(|(name, _)| name != opcode)
+    }
+
+    pub fn id(&self) -> Option<&str> {
+        self.string_operand("id")
+    }
+
+    pub fn comment(&self, index: u8) -> Option<&str> {
+        self.string_operand(&format!("c{index}"))
+    }
+
+    pub fn best_moves(&self) -> Vec<Move> {
+        self.move_operands("bm")
+    }
+
+    pub fn avoid_moves(&self) -> Vec<Move> {
+        self.move_operands("am")
+    }
+
+    pub fn set_best_moves(&mut self, moves: &[Move]) -> std::result::Result<(), BoardError> {
+        let board = self.board()?;
+        let operands = moves.iter().map(|&mv| board.to_san(mv)).collect();
+        self.set_operation("bm", operands);
+        Ok(())
+    }
+
+    pub fn set_avoid_moves(&mut self, moves: &[Move]) -> std::result::Result<(), BoardError> {
+        let board = self.board()?;
+        let operands = moves.iter().map(|&mv| board.to_san(mv)).collect();
+        self.set_operation("am", operands);
+        Ok(())
+    }
+
+    //Empty when the position is not a valid board
+    pub fn pv(&self) -> Vec<Move> {
+        let Ok(mut board) = self.board() else {
+            return Vec::new();
+        };
+        let mut result = Vec::new();
+
+        for operand in self.operation("pv").unwrap_or(&[]) {
+            match board.parse_move(operand) {
+                Some(mv) => {
+                    result.push(mv);
+                    board.make_move_no_mask(mv);
+                }
+                None => break,
+            }
+        }
+
+        result
+    }
+
+    pub fn direct_mate(&self) -> Option<u32> {
+        self.numeric_operand("dm")
+    }
+
+    pub fn centipawn_evaluation(&self) -> Option<i32> {
+        self.numeric_operand("ce")
+    }
+
+    pub fn analysis_depth(&self) -> Option<u32> {
+        self.numeric_operand("acd")
+    }
+
+    fn string_operand(&self, opcode: &str) -> Option<&str> {
+        self.operation(opcode)
+            .and_then(|operands| operands.first())
+            .map(|operand| operand.as_str())
+    }
+
+    fn numeric_operand<T: std::str::FromStr>(&self, opcode: &str) -> Option<T> {
+        self.string_operand(opcode)?.parse::<T>().ok()
+    }
+
+    fn move_operands(&self, opcode: &str) -> Vec<Move> {
+        let Ok(board) = self.board() else {
+            return Vec::new();
+        };
+        self.operation(opcode)
+            .unwrap_or(&[])
+            .iter()
+            .filter_map(|operand| board.parse_move(operand))
+            .collect()
+    }
+}
+
+impl From<FEN> for Epd {
+    fn from(value: FEN) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Display for Epd {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        write!(
+            formatter,
+            "{} {} {} {}",
+            self.fen.formatted_board(),
+            self.fen.side_to_move,
+            self.fen.formatted_castle_rights(),
+            self.fen.en_passant_square
+        )?;
+
+        if self.fen.half_move_counter != "0" {
+            write!(formatter, " hmvc {};", self.fen.half_move_counter)?;
+        }
+
+        if self.fen.full_move_counter != "1" {
+            write!(formatter, " fmvn {};", self.fen.full_move_counter)?;
+        }
+
+        for (opcode, operands) in &self.operations {
+            write!(formatter, " {opcode}")?;
+
+            let is_string_operation = opcode == "id"
+                || (opcode.len() == 2
+                    && opcode.starts_with('c')
+                    && opcode.as_bytes()[1].is_ascii_digit());
+
+            for operand in operands {
+                if is_string_operation
+                    || operand.is_empty()
+                    || operand.contains(|char: char| char.is_whitespace() || char == ';')
+                {
+                    write!(formatter, " \"{operand}\"")?;
+                } else {
+                    write!(formatter, " {operand}")?;
+                }
+            }
+
+            write!(formatter, ";")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EpdError {
+    MissingFields,
+    Fen(FenError),
+    InvalidOpcode(String),
+    UnterminatedString(String),
+}
+
+impl Display for EpdError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        match self {
+            EpdError::MissingFields => write!(formatter, "expected at least 4 fields"),
+            EpdError::Fen(err) => write!(formatter, "{err}"),
+            EpdError::InvalidOpcode(opcode) => write!(formatter, "invalid opcode '{opcode}'"),
+            EpdError::UnterminatedString(opcode) => {
+                write!(formatter, "unterminated string in operation '{opcode}'")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EpdError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EpdError::Fen(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+fn split_token(value: &str) -> Option<(&str, &str)> {
+    let value = value.trim_start();
+    if value.is_empty() || value.starts_with(';') {
+        return None;
+    }
+
+    match value.find(|char: char| char.is_whitespace() || char == ';') {
+        Some(index) => Some((&value[..index], &value[index..])),
+        None => Some((value, "")),
+    }
+}
+
+fn parse_operations(value: &str) -> std::result::Result<Vec<(String, Vec<String>)>, EpdError> {
+    let mut operations = Vec::new();
+    let mut chars = value.chars().peekable();
+
+    loop {
+        while chars
+            .next_if(|char| char.is_whitespace() || *char == ';')
+            .is_some()
+        {}
+
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let mut opcode = String::new();
+        while let Some(char) = chars.next_if(|char| !char.is_whitespace() && *char != ';') {
+            opcode.push(char);
+        }
+
+        let valid_opcode = opcode.len() <= 15
+            && opcode.starts_with(|char: char| char.is_ascii_alphabetic())
+            && opcode
+                .chars()
+                .all(|char| char.is_ascii_alphanumeric() || char == '_');
+        if !valid_opcode {
+            return Err(EpdError::InvalidOpcode(opcode));
+        }
+
+        let mut operands = Vec::new();
+        loop {
+            while chars.next_if(|char| char.is_whitespace()).is_some() {}
+
+            match chars.peek() {
+                None => break,
+                Some(';') => {
+                    chars.next();
+                    break;
+                }
+                Some('"') => {
+                    chars.next();
+                    let mut operand = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('"') => break,
+                            Some(char) => operand.push(char),
+                            None => return Err(EpdError::UnterminatedString(opcode)),
+                        }
+                    }
+                    operands.push(operand);
+                }
+                Some(_) => {
+                    let mut operand = String::new();
+                    while let Some(char) =
+                        chars.next_if(|char| !char.is_whitespace() && *char != ';')
+                    {
+                        operand.push(char);
+                    }
+                    operands.push(operand);
+                }
+            }
+        }
+
+        operations.push((opcode, operands));
+    }
+
+    Ok(operations)
+}
@@ -3,10 +3,6 @@ use std::{
     fmt::{Display, Formatter, Result},
 };
 
-use crate::Bitboard;
-
-use super::Square;
-
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct FEN {
     pub(crate) board: [String; 8],
@@ -15,6 +11,16 @@ pub struct FEN {
     pub(crate) en_passant_square: String,
     pub(crate) half_move_counter: String,
     pub(crate) full_move_counter: String,
+    pub(crate) check_counters: String,
+    pub(crate) pocket: Option<String>,
+    pub(crate) castle_notation: CastleNotation,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CastleNotation {
+    #[default]
+    Shredder,
+    XFen,
 }
 
 impl FEN {
@@ -26,45 +32,233 @@ impl FEN {
         Self::from("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w HAha - 0 1")
     }
 
+    #[inline]
+    pub fn castle_notation(&self) -> CastleNotation {
+        self.castle_notation
+    }
+
+    #[inline]
+    pub fn set_castle_notation(&mut self, castle_notation: CastleNotation) {
+        self.castle_notation = castle_notation
+    }
+
+    pub fn formatted_castle_rights(&self) -> String {
+        if self.castle_notation == CastleNotation::Shredder || self.castle_rights == "-" {
+            return self.castle_rights.clone();
+        }
+
+        //X-FEN only falls back to the rook file when another rook stands further out on the same side
+        self.castle_rights
+            .chars()
+            .map(|char| {
+                let rank = if char.is_ascii_uppercase() {
+                    &self.board[7]
+                } else {
+                    &self.board[0]
+                };
+                let (king, rook) = if char.is_ascii_uppercase() {
+                    ('K', 'R')
+                } else {
+                    ('k', 'r')
+                };
+
+                let king_file = find_files(rank, king).first().copied().unwrap_or(0);
+                let rook_file = char.to_ascii_uppercase() as u8 - b'A';
+                let rooks = find_files(rank, rook);
+
+                let (is_outer_rook, notation) = if rook_file > king_file {
+                    (rooks.iter().all(|&file| file <= rook_file), 'K')
+                } else {
+                    (rooks.iter().all(|&file| file >= rook_file), 'Q')
+                };
+
+                match (is_outer_rook, char.is_ascii_uppercase()) {
+                    (true, true) => notation,
+                    (true, false) => notation.to_ascii_lowercase(),
+                    (false, _) => char,
+                }
+            })
+            .collect()
+    }
+
+    //Remaining checks for white and black in Three-check, if the fen carries them
+    pub fn remaining_checks(&self) -> Option<(u8, u8)> {
+        let (white, black) = self.check_counters.split_once('+')?;
+        Some((white.parse().ok()?, black.parse().ok()?))
+    }
+
+    //Crazyhouse pieces in hand, if the fen carries a pocket
+    #[inline]
+    pub fn pocket(&self) -> Option<&str> {
+        self.pocket.as_deref()
+    }
+
+    pub(crate) fn formatted_board(&self) -> String {
+        match &self.pocket {
+            Some(pocket) => format!("{}[{pocket}]", self.board.join("/")),
+            None => self.board.join("/"),
+        }
+    }
+
     pub fn validate_fen(fen_string: &str) -> bool {
-        let fen_parts: Vec<&str> = fen_string.split_whitespace().collect();
+        Self::parse(fen_string).is_ok()
+    }
+
+    pub fn parse(fen_string: &str) -> std::result::Result<Self, FenError> {
+        let mut fen_parts: Vec<&str> = fen_string.split_whitespace().collect();
+
+        if let Some(counters) = split_check_counters(&mut fen_parts) {
+            if normalize_check_counters(counters).is_none() {
+                return Err(FenError::InvalidCheckCounters(counters.to_string()));
+            }
+        }
 
-        if fen_parts.len() < 4 {
-            return false;
+        if fen_parts.len() < 4 || fen_parts.len() > 6 {
+            return Err(FenError::InvalidFieldCount(fen_parts.len()));
         }
 
-        let board_parts: Vec<&str> = fen_parts[0].split('/').collect();
+        let (board, pocket) = split_pocket(fen_parts[0]);
+        if let Some(pocket) = pocket {
+            if !pocket.chars().all(|char| "PNBRQpnbrq".contains(char)) {
+                return Err(FenError::InvalidPocket(pocket.to_string()));
+            }
+        }
+
+        let board_parts: Vec<&str> = board.split('/').collect();
         if board_parts.len() != 8 {
-            return false;
+            return Err(FenError::InvalidRankCount(board_parts.len()));
+        }
+
+        for (index, rank) in board_parts.iter().enumerate() {
+            let rank_number = 8 - index as u8;
+            let mut file_count = 0u8;
+            let mut previous_digit = false;
+            let mut previous_piece = false;
+
+            for char in rank.chars() {
+                match char {
+                    '1'..='8' => {
+                        if previous_digit {
+                            return Err(FenError::InvalidRank(rank_number));
+                        }
+
+                        file_count += char as u8 - b'0';
+                        previous_digit = true;
+                        previous_piece = false;
+                    }
+                    'p' | 'n' | 'b' | 'r' | 'q' | 'k' | 'P' | 'N' | 'B' | 'R' | 'Q' | 'K' => {
+                        file_count += 1;
+                        previous_digit = false;
+                        previous_piece = true;
+                    }
+                    //Crazyhouse marks promoted pieces with a trailing '~'
+                    '~' if previous_piece => previous_piece = false,
+                    _ => return Err(FenError::InvalidPiece(char)),
+                }
+
+                if file_count > 8 {
+                    return Err(FenError::InvalidRank(rank_number));
+                }
+            }
+
+            if file_count != 8 {
+                return Err(FenError::InvalidRank(rank_number));
+            }
         }
 
         if fen_parts[1] != "w" && fen_parts[1] != "b" {
-            return false;
+            return Err(FenError::InvalidSideToMove(fen_parts[1].to_string()));
         }
 
-        let valid_en_passant = if fen_parts[3] != "-" {
-            (Bitboard::from(Square::from(fen_parts[3])) & Bitboard::RANK_3.or(Bitboard::RANK_6))
-                .is_not_empty()
-        } else {
-            true
-        };
+        if fen_parts[2] != "-" {
+            let mut seen = Vec::new();
+            for char in fen_parts[2].chars() {
+                if !matches!(char, 'K' | 'Q' | 'k' | 'q' | 'A'..='H' | 'a'..='h')
+                    || seen.contains(&char)
+                    || seen.len() >= 4
+                {
+                    return Err(FenError::InvalidCastleRights(fen_parts[2].to_string()));
+                }
+
+                seen.push(char);
+            }
+        }
 
-        if fen_parts[3].len() > 2 || !valid_en_passant {
-            return false;
+        if fen_parts[3] != "-" {
+            let signatures: Vec<char> = fen_parts[3].chars().collect();
+            if signatures.len() != 2
+                || !('a'..='h').contains(&signatures[0])
+                || (signatures[1] != '3' && signatures[1] != '6')
+            {
+                return Err(FenError::InvalidEnPassant(fen_parts[3].to_string()));
+            }
         }
 
         if fen_parts.len() > 4 && fen_parts[4].parse::<u8>().is_err() {
-            return false;
+            return Err(FenError::InvalidHalfMoveCounter(fen_parts[4].to_string()));
         }
 
         if fen_parts.len() > 5 && fen_parts[5].parse::<u16>().is_err() {
-            return false;
+            return Err(FenError::InvalidFullMoveCounter(fen_parts[5].to_string()));
         }
 
-        true
+        Ok(Self::from(fen_string))
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum FenError {
+    InvalidFieldCount(usize),
+    InvalidRankCount(usize),
+    InvalidRank(u8),
+    InvalidPiece(char),
+    InvalidSideToMove(String),
+    InvalidCastleRights(String),
+    InvalidEnPassant(String),
+    InvalidHalfMoveCounter(String),
+    InvalidFullMoveCounter(String),
+    InvalidCheckCounters(String),
+    InvalidPocket(String),
+}
+
+impl Display for FenError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        match self {
+            FenError::InvalidFieldCount(count) => {
+                write!(formatter, "expected 4 to 6 fields, found {count}")
+            }
+            FenError::InvalidRankCount(count) => {
+                write!(formatter, "expected 8 ranks, found {count}")
+            }
+            FenError::InvalidRank(rank) => {
+                write!(formatter, "rank {rank} does not describe exactly 8 squares")
+            }
+            FenError::InvalidPiece(char) => write!(formatter, "invalid piece character '{char}'"),
+            FenError::InvalidSideToMove(value) => {
+                write!(formatter, "invalid side to move '{value}'")
+            }
+            FenError::InvalidCastleRights(value) => {
+                write!(formatter, "invalid castle rights '{value}'")
+            }
+            FenError::InvalidEnPassant(value) => {
+                write!(formatter, "invalid en passant square '{value}'")
+            }
+            FenError::InvalidHalfMoveCounter(value) => {
+                write!(formatter, "invalid half move counter '{value}'")
+            }
+            FenError::InvalidFullMoveCounter(value) => {
+                write!(formatter, "invalid full move counter '{value}'")
+            }
+            FenError::InvalidCheckCounters(value) => {
+                write!(formatter, "invalid check counters '{value}'")
+            }
+            FenError::InvalidPocket(value) => write!(formatter, "invalid pocket '{value}'"),
+        }
+    }
+}
+
+impl std::error::Error for FenError {}
+
 impl From<String> for FEN {
     fn from(value: String) -> Self {
         FEN::from(value.as_str())
@@ -74,9 +268,16 @@ impl From<String> for FEN {
 impl From<&str> for FEN {
     fn from(value: &str) -> Self {
         let mut result: Self = Self::default();
-        let fen_parts: Vec<&str> = value.split_whitespace().collect();
+        let mut fen_parts: Vec<&str> = value.split_whitespace().collect();
+
+        if let Some(counters) = split_check_counters(&mut fen_parts) {
+            result.check_counters = normalize_check_counters(counters).unwrap_or_default();
+        }
+
+        let (board, pocket) = split_pocket(fen_parts[0]);
+        result.pocket = pocket.map(String::from);
 
-        let board_parts: Vec<&str> = fen_parts[0].split('/').collect();
+        let board_parts: Vec<&str> = board.split('/').collect();
         for (index, part) in board_parts.into_iter().enumerate() {
             result.board[index] = part.to_string()
         }
@@ -106,19 +307,18 @@ impl From<&str> for FEN {
 
 impl From<FEN> for String {
     fn from(value: FEN) -> Self {
+        let en_passant_square = if value.check_counters.is_empty() {
+            value.en_passant_square.clone()
+        } else {
+            format!("{} {}", value.en_passant_square, value.check_counters)
+        };
+
         format!(
-            "{}/{}/{}/{}/{}/{}/{}/{} {} {} {} {} {}",
-            value.board[0],
-            value.board[1],
-            value.board[2],
-            value.board[3],
-            value.board[4],
-            value.board[5],
-            value.board[6],
-            value.board[7],
+            "{} {} {} {} {} {}",
+            value.formatted_board(),
             value.side_to_move,
-            value.castle_rights,
-            value.en_passant_square,
+            value.formatted_castle_rights(),
+            en_passant_square,
             value.half_move_counter,
             value.full_move_counter
         )
@@ -132,24 +332,6 @@ impl Display for FEN {
 }
 
 fn normalize_castle_rights(fen: &FEN, rights: &str) -> String {
-    //Helper method to find occurance of the character in the fen string
-    let find_files = |rank_str: &String, target: char| -> Vec<u8> {
-        let mut result = Vec::new();
-        let mut file_idx = 0;
-        for char in rank_str.chars() {
-            if char.is_ascii_digit() {
-                file_idx += char as u8 - b'0'
-            } else {
-                if char == target {
-                    result.push(file_idx);
-                }
-                file_idx += 1;
-            }
-        }
-
-        result
-    };
-
     if rights == "-" {
         return String::from("-");
     }
@@ -239,3 +421,67 @@ fn normalize_castle_rights(fen: &FEN, rights: &str) -> String {
         result
     }
 }
+
+//Three-check counters are either remaining checks after the en passant square ("3+3")
+//or checks already given at the end of the fen ("+0+0")
+fn split_check_counters<'a>(fen_parts: &mut Vec<&'a str>) -> Option<&'a str> {
+    if fen_parts.len() > 4 && fen_parts[4].contains('+') && !fen_parts[4].starts_with('+') {
+        return Some(fen_parts.remove(4));
+    }
+
+    if fen_parts.len() > 4 && fen_parts[fen_parts.len() - 1].starts_with('+') {
+        return fen_parts.pop();
+    }
+
+    None
+}
+
+fn normalize_check_counters(counters: &str) -> Option<String> {
+    let (given, counters) = match counters.strip_prefix('+') {
+        Some(counters) => (true, counters),
+        None => (false, counters),
+    };
+
+    let (white, black) = counters.split_once('+')?;
+    let (white, black) = (white.parse::<u8>().ok()?, black.parse::<u8>().ok()?);
+    if white > 3 || black > 3 {
+        return None;
+    }
+
+    Some(if given {
+        format!("{}+{}", 3 - white, 3 - black)
+    } else {
+        format!("{white}+{black}")
+    })
+}
+
+//Crazyhouse pockets are written either in brackets after the board ("...RNBQKBNR[Pp]")
+//or as a ninth rank ("...RNBQKBNR/Pp")
+fn split_pocket(board: &str) -> (&str, Option<&str>) {
+    if let Some((board, pocket)) = board.split_once('[') {
+        return (board, Some(pocket.trim_end_matches(']')));
+    }
+
+    match board.match_indices('/').nth(7) {
+        Some((index, _)) => (&board[..index], Some(&board[index + 1..])),
+        None => (board, None),
+    }
+}
+
+//Helper method to find occurance of the character in the fen string
+fn find_files(rank_str: &str, target: char) -> Vec<u8> {
+    let mut result = Vec::new();
+    let mut file_idx = 0;
+    for char in rank_str.chars() {
+        if char.is_ascii_digit() {
+            file_idx += char as u8 - b'0'
+        } else if char != '~' {
+            if char == target {
+                result.push(file_idx);
+            }
+            file_idx += 1;
+        }
+    }
+
+    result
+}
@@ -1,18 +1,29 @@
 mod bitboard;
 mod castle_rights;
+mod chess960;
+mod epd;
 mod fen;
 mod r#move;
+mod move_list;
 mod piece;
 mod side;
 mod square;
+mod variant;
 mod zobrist_key;
 
 pub use bitboard::Bitboard;
 pub use castle_rights::CastleRights;
+pub use epd::Epd;
+pub use epd::EpdError;
+pub use fen::CastleNotation;
+pub use fen::FenError;
 pub use fen::FEN;
+pub use move_list::MoveList;
+pub use move_list::MAX_MOVES;
 pub use piece::Piece;
 pub use r#move::Move;
 pub use r#move::MoveFlag;
 pub use side::Side;
 pub use square::Square;
+pub use variant::Variant;
 pub use zobrist_key::ZobristKey;
@@ -11,6 +11,7 @@ impl MoveFlag {
     pub const QUEEN_SIDE_CASTLE: u16 = 0b0011 << 6;
     pub const CAPTURE: u16 = 0b0100 << 6;
     pub const EN_PASSANT: u16 = 0b0101 << 6;
+    pub const DROP: u16 = 0b0110 << 6;
     pub const KNIGHT_PROMOTION: u16 = 0b1000 << 6;
     pub const BISHOP_PROMOTION: u16 = 0b1001 << 6;
     pub const ROOK_PROMOTION: u16 = 0b1010 << 6;
@@ -22,7 +23,7 @@ impl MoveFlag {
 }
 
 //16 bit move
-//0..5 -> from square
+//0..5 -> from square (dropped piece for drops)
 //6..9 -> flag
 //10..15 -> to square
 
@@ -36,6 +37,11 @@ impl Move {
         Self(u16::from(to_square) << 10 | flag | u16::from(from_square))
     }
 
+    #[inline]
+    pub fn from_drop(piece: Piece, to_square: Square) -> Self {
+        Self(u16::from(to_square) << 10 | MoveFlag::DROP | u16::from(u8::from(piece)))
+    }
+
     #[inline]
     pub fn from_square(&self) -> Square {
         Square::from((self.0 & 63) as u8)
@@ -53,7 +59,17 @@ impl Move {
 
     #[inline]
     pub fn is_capture(&self) -> bool {
-        self.0 & MoveFlag::CAPTURE != 0
+        self.0 & MoveFlag::CAPTURE != 0 && !self.is_drop()
+    }
+
+    #[inline]
+    pub fn is_drop(&self) -> bool {
+        self.flag() == MoveFlag::DROP
+    }
+
+    #[inline]
+    pub fn drop_piece(&self) -> Piece {
+        Piece::from((self.0 & 63) as u8)
     }
 
     #[inline]
@@ -78,6 +94,14 @@ impl Move {
     }
 
     pub fn to_string(&self, chess960: bool) -> String {
+        if self.is_drop() {
+            return format!(
+                "{}@{}",
+                char::from(self.drop_piece()).to_ascii_uppercase(),
+                self.to_square()
+            );
+        }
+
         if !chess960 && self.is_castle() {
             let side = if u8::from(self.from_square()) < 32 {
                 Side::WHITE
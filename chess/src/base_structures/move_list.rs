@@ -0,0 +1,132 @@
+use std::{
+    fmt::{Debug, Formatter, Result},
+    ops::{Deref, DerefMut},
+};
+
+use crate::Move;
+
+//Crazyhouse drops can push the move count well past the 218 possible in standard chess
+pub const MAX_MOVES: usize = 512;
+
+#[derive(Clone, Copy)]
+pub struct MoveList {
+    moves: [Move; MAX_MOVES],
+    len: usize,
+}
+
+impl MoveList {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            moves: [Move::NULL; MAX_MOVES],
+            len: 0,
+        }
+    }
+
+    #[inline]
+    pub fn push(&mut self, mv: Move) {
+        debug_assert!(self.len < MAX_MOVES);
+        self.moves[self.len] = mv;
+        self.len += 1;
+    }
+
+    #[inline]
+    pub fn pop(&mut self) -> Option<Move> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        Some(self.moves[self.len])
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    #[inline]
+    pub fn swap_remove(&mut self, index: usize) -> Move {
+        assert!(index < self.len);
+        let mv = self.moves[index];
+        self.len -= 1;
+        self.moves[index] = self.moves[self.len];
+        mv
+    }
+
+    #[inline]
+    pub fn retain<F: FnMut(&Move) -> bool>(&mut self, mut keep: F) {
+        let mut len = 0;
+        for idx in 0..self.len {
+            if keep(&self.moves[idx]) {
+                self.moves[len] = self.moves[idx];
+                len += 1;
+            }
+        }
+
+        self.len = len;
+    }
+}
+
+impl Default for MoveList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for MoveList {
+    type Target = [Move];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.moves[..self.len]
+    }
+}
+
+impl DerefMut for MoveList {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.moves[..self.len]
+    }
+}
+
+impl PartialEq for MoveList {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl Debug for MoveList {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        formatter.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a> IntoIterator for &'a MoveList {
+    type Item = &'a Move;
+    type IntoIter = std::slice::Iter<'a, Move>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for MoveList {
+    type Item = Move;
+    type IntoIter = std::iter::Take<std::array::IntoIter<Move, MAX_MOVES>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.moves.into_iter().take(self.len)
+    }
+}
+
+impl FromIterator<Move> for MoveList {
+    fn from_iter<T: IntoIterator<Item = Move>>(iter: T) -> Self {
+        let mut result = Self::new();
+        for mv in iter {
+            result.push(mv);
+        }
+
+        result
+    }
+}
@@ -0,0 +1,70 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::FEN;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Variant {
+    #[default]
+    Standard,
+    KingOfTheHill,
+    ThreeCheck,
+    Horde,
+    Crazyhouse,
+}
+
+impl Variant {
+    pub const ALL: [Variant; 5] = [
+        Variant::Standard,
+        Variant::KingOfTheHill,
+        Variant::ThreeCheck,
+        Variant::Horde,
+        Variant::Crazyhouse,
+    ];
+
+    //Accepts the names used by UCI_Variant as well as a few common aliases
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "chess" | "standard" | "normal" => Some(Variant::Standard),
+            "kingofthehill" | "koth" => Some(Variant::KingOfTheHill),
+            "3check" | "threecheck" => Some(Variant::ThreeCheck),
+            "horde" => Some(Variant::Horde),
+            "crazyhouse" | "zh" => Some(Variant::Crazyhouse),
+            _ => None,
+        }
+    }
+
+    //Variants that can end the game before checkmate or stalemate, whose positions need a
+    //variant_result check before generating moves
+    pub fn has_variant_results(&self) -> bool {
+        !matches!(self, Variant::Standard | Variant::Crazyhouse)
+    }
+
+    pub fn start_position(&self) -> FEN {
+        match self {
+            Variant::Standard | Variant::KingOfTheHill => FEN::start_position(),
+            Variant::ThreeCheck => {
+                FEN::from("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w HAha - 3+3 0 1")
+            }
+            Variant::Crazyhouse => {
+                FEN::from("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[] w HAha - 0 1")
+            }
+            Variant::Horde => FEN::from(
+                "rnbqkbnr/pppppppp/8/1PP2PP1/PPPPPPPP/PPPPPPPP/PPPPPPPP/PPPPPPPP w ha - 0 1",
+            ),
+        }
+    }
+}
+
+impl Display for Variant {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        let name = match self {
+            Variant::Standard => "chess",
+            Variant::KingOfTheHill => "kingofthehill",
+            Variant::ThreeCheck => "3check",
+            Variant::Horde => "horde",
+            Variant::Crazyhouse => "crazyhouse",
+        };
+
+        write!(formatter, "{name}")
+    }
+}
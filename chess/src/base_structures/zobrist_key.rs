@@ -12,6 +12,12 @@ impl ZobristKey {
         self.0 ^= SEEDS[(usize::from(piece) + usize::from(side) * 6) * 64 + usize::from(square)];
     }
 
+    //Reuses the piece square seeds with the piece count in place of the square
+    #[inline]
+    pub(crate) fn update_material_hash(&mut self, piece: Piece, side: Side, count: u32) {
+        self.0 ^= SEEDS[(usize::from(piece) + usize::from(side) * 6) * 64 + count as usize];
+    }
+
     #[inline]
     pub(crate) fn add_side_to_move(&mut self, side: Side) {
         self.0 ^= SEEDS[768] * usize::from(side) as u64
@@ -26,6 +32,22 @@ impl ZobristKey {
     pub(crate) fn add_en_passant(&mut self, square: Square) {
         self.0 ^= SEEDS[785 + usize::from(square) % 8]
     }
+
+    #[inline]
+    pub(crate) fn add_checks(&mut self, checks: [u8; 2]) {
+        self.0 ^= SEEDS[793 + usize::from(checks[0])] ^ SEEDS[797 + usize::from(checks[1])]
+    }
+
+    #[inline]
+    pub(crate) fn add_pockets(&mut self, pockets: &[[u8; 5]; 2]) {
+        for (side, pocket) in pockets.iter().enumerate() {
+            for (piece, &count) in pocket.iter().enumerate() {
+                if count > 0 {
+                    self.0 ^= SEEDS[801 + (side * 5 + piece) * 17 + usize::from(count.min(16))];
+                }
+            }
+        }
+    }
 }
 
 impl From<ZobristKey> for u64 {
@@ -41,7 +63,7 @@ impl Display for ZobristKey {
     }
 }
 
-const SEEDS: [u64; 793] = [
+const SEEDS: [u64; 971] = [
     6010607256382380006,
     386869187810051925,
     6942428122597393202,
@@ -835,4 +857,182 @@ const SEEDS: [u64; 793] = [
     7224224972951806000,
     13322976826524058764,
     10911771378663354087,
+    10932295209482665981,
+    2405875930906139466,
+    16896199536424608164,
+    8744744311366254845,
+    10714829862921516198,
+    11171339666664619993,
+    16764740455796505125,
+    8655808914197340073,
+    3459210509601373969,
+    16161644282813686486,
+    1107385675281028284,
+    8331064068911535430,
+    12767672537648131607,
+    11700397603555460357,
+    4425623569301877015,
+    6195701624392218578,
+    6520494210271486038,
+    8373349118395250938,
+    3589828503762834479,
+    9731169120614013527,
+    17573075796336553118,
+    15694172522560223921,
+    548945898422325131,
+    11026159250510368231,
+    4475957606751005579,
+    8019149399711167091,
+    16573898673044337496,
+    8106376530726289430,
+    9628061928700888763,
+    10226278634870355346,
+    7109418122919048437,
+    7431797507842221331,
+    11670268270145854200,
+    5225942937772189346,
+    7439891318383032604,
+    7364138353979731395,
+    7221229598804744865,
+    14940592031383583207,
+    12062538362724484856,
+    5696426728240239078,
+    10756148185266862594,
+    16217165513024456034,
+    8166557560864557816,
+    16118751979861712647,
+    5247526571969384567,
+    1145643093356756305,
+    7418721947152801066,
+    17523010862186465765,
+    16842507041344762702,
+    6026252425448386309,
+    17365412422649052467,
+    12449267883001917709,
+    8971997153952662135,
+    17484096791540560274,
+    6454167516273271903,
+    7229699494473539420,
+    3556395977228205735,
+    6652839001027285164,
+    10072985351388216737,
+    17290382183788395315,
+    11858428376711487415,
+    11136149277493759792,
+    473511051211591765,
+    10056975472538100375,
+    4804540492851885504,
+    10561837387431908104,
+    3005429693433522662,
+    4529711528924764895,
+    5063107211650999982,
+    9826717494164681846,
+    2587878975743369884,
+    8099229810553728104,
+    7896802481724897949,
+    13758361968857239499,
+    16115368259137361504,
+    7290907191329009386,
+    17025529447519394624,
+    16613856666608232014,
+    7278022469081608755,
+    16800923439261478592,
+    14175645560907533177,
+    18102153306212776397,
+    3316827077694509360,
+    2130665388893564376,
+    14864300583969560630,
+    11599941546631824801,
+    6401341207446454715,
+    1899103418354288097,
+    2827118888657142897,
+    12233313712708139552,
+    14919946238782287609,
+    7326528611047388508,
+    9557304063793984702,
+    5898545741797697964,
+    2216860127302924619,
+    13776993401249770846,
+    8227844112629675056,
+    16300671448137407261,
+    11834121600230260620,
+    14253358760499407092,
+    1885019612303575467,
+    12810517960646811369,
+    6257797957564578821,
+    730409007711372718,
+    3507400133615426855,
+    14917494981810141627,
+    5634685058124213549,
+    12267252572791649481,
+    8423661905453363834,
+    590892141341336443,
+    1377661537124748112,
+    18113422202615388486,
+    14047591435018057244,
+    16255494449049757682,
+    10963050392187523948,
+    14684566983855969127,
+    14440448425531340384,
+    15194491507256047674,
+    14101628225005207985,
+    17236203518449622303,
+    5121321119466092448,
+    11894203931124951748,
+    9888456349322045191,
+    2620578161839775635,
+    951997478374553090,
+    8705283866256507217,
+    6589703333559421872,
+    14823191256047387015,
+    16729063109898014994,
+    1421865739780155245,
+    3057554463081436883,
+    12286782127413418525,
+    9022434911276220587,
+    4831802110951193669,
+    5584778956615125698,
+    11848902751198578806,
+    2379283080210978384,
+    5582111629161070244,
+    134847741710487933,
+    2860363131306748194,
+    8484164973546927106,
+    17681218399592473358,
+    16752811083921667304,
+    14616268155006056268,
+    17334802766265696841,
+    1554613207968504932,
+    2050998505525051910,
+    689143899685465819,
+    13856376034620249193,
+    9961706285119915659,
+    10611331844508793160,
+    18030631700352036846,
+    4948336349136462461,
+    14711160286340682044,
+    1529551430455326143,
+    10734469714455022931,
+    10959514433447503972,
+    11783635285257519536,
+    2451348774422926248,
+    1147464597272915720,
+    14744882416713761550,
+    2138815053758711081,
+    9846058342681731463,
+    18237636373408506664,
+    10917737256225501689,
+    3973400545140342321,
+    11135827050383401629,
+    1937743028740806667,
+    5864805374866881215,
+    7462054431043257928,
+    14961203068814343196,
+    11653074823078082269,
+    2104441918530072220,
+    5787178265035376653,
+    17765345367343356985,
+    4848357344633920166,
+    6740063917950104867,
+    637418467094265260,
 ];
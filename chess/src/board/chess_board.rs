@@ -1,6 +1,6 @@
 use crate::{
     base_structures::{CastleRights, ZobristKey},
-    Bitboard, Piece, Side, Square,
+    Bitboard, Move, Piece, Side, Square, Variant,
 };
 
 const PHASE_VALUES: [u8; 6] = [0, 1, 1, 2, 4, 0];
@@ -12,11 +12,18 @@ pub struct ChessBoard {
     occupancy: [Bitboard; 2],
     pieces: [Bitboard; 6],
     hash: ZobristKey,
+    pawn_key: ZobristKey,
+    non_pawn_keys: [ZobristKey; 2],
+    material_key: ZobristKey,
     phase: u8,
     pub(super) side: Side,
     pub(super) castle_rights: CastleRights,
     pub(super) en_passant_square: Square,
     pub(super) half_moves: u8,
+    pub(super) variant: Variant,
+    pub(super) checks: [u8; 2],
+    pub(super) pockets: [[u8; 5]; 2],
+    pub(super) promoted: Bitboard,
 }
 
 impl ChessBoard {
@@ -78,9 +85,34 @@ impl ChessBoard {
         result.add_side_to_move(self.side);
         result.add_castle_rights(&self.castle_rights);
 
+        if self.variant == Variant::ThreeCheck {
+            result.add_checks(self.checks);
+        }
+
+        if self.variant == Variant::Crazyhouse {
+            result.add_pockets(&self.pockets);
+        }
+
         result
     }
 
+    //Pawn structure only, shared by positions that differ in pieces
+    #[inline]
+    pub fn pawn_key(&self) -> ZobristKey {
+        self.pawn_key
+    }
+
+    #[inline]
+    pub fn non_pawn_key(&self, side: Side) -> ZobristKey {
+        self.non_pawn_keys[usize::from(side)]
+    }
+
+    //Depends only on the piece counts, not on where the pieces stand
+    #[inline]
+    pub fn material_key(&self) -> ZobristKey {
+        self.material_key
+    }
+
     #[inline]
     pub fn phase(&self) -> u8 {
         self.phase
@@ -106,13 +138,61 @@ impl ChessBoard {
         self.half_moves
     }
 
+    #[inline]
+    pub fn variant(&self) -> Variant {
+        self.variant
+    }
+
+    #[inline]
+    pub fn set_variant(&mut self, variant: Variant) {
+        self.variant = variant;
+
+        if variant != Variant::ThreeCheck {
+            self.checks = [0; 2];
+        }
+
+        if variant != Variant::Crazyhouse {
+            self.pockets = [[0; 5]; 2];
+            self.promoted = Bitboard::EMPTY;
+        }
+    }
+
+    //Number of checks given by the side, only tracked in Three-check
+    #[inline]
+    pub fn checks_given(&self, side: Side) -> u8 {
+        self.checks[usize::from(side)]
+    }
+
+    //Pieces captured by the side that can be dropped back, only tracked in Crazyhouse
+    #[inline]
+    pub fn pocket(&self, side: Side, piece: Piece) -> u8 {
+        self.pockets[usize::from(side)][usize::from(piece)]
+    }
+
+    //Promoted pieces return to the pocket as pawns when captured
+    #[inline]
+    pub fn promoted_pieces(&self) -> Bitboard {
+        self.promoted
+    }
+
+    #[inline]
+    pub fn moved_piece(&self, mv: Move) -> Piece {
+        if mv.is_drop() {
+            mv.drop_piece()
+        } else {
+            self.piece_on_square(mv.from_square())
+        }
+    }
+
     #[inline]
     pub fn set_piece_on_square(&mut self, square: Square, piece: Piece, side: Side) {
         debug_assert_ne!(square, Square::NULL);
         debug_assert_ne!(piece, Piece::NONE);
+        let count = self.piece_mask_for_side(piece, side).pop_count();
         self.occupancy[usize::from(side)].set_bit(square);
         self.pieces[usize::from(piece)].set_bit(square);
         self.hash.update_piece_hash(square, piece, side);
+        self.update_partial_keys(square, piece, side, count);
         self.phase += PHASE_VALUES[usize::from(piece)];
     }
 
@@ -123,9 +203,23 @@ impl ChessBoard {
         self.occupancy[usize::from(side)].pop_bit(square);
         self.pieces[usize::from(piece)].pop_bit(square);
         self.hash.update_piece_hash(square, piece, side);
+        let count = self.piece_mask_for_side(piece, side).pop_count();
+        self.update_partial_keys(square, piece, side, count);
         self.phase -= PHASE_VALUES[usize::from(piece)];
     }
 
+    //Count is the number of pieces of that type without the one being added or removed
+    #[inline]
+    fn update_partial_keys(&mut self, square: Square, piece: Piece, side: Side, count: u32) {
+        if piece == Piece::PAWN {
+            self.pawn_key.update_piece_hash(square, piece, side);
+        } else {
+            self.non_pawn_keys[usize::from(side)].update_piece_hash(square, piece, side);
+        }
+
+        self.material_key.update_material_hash(piece, side, count);
+    }
+
     #[inline]
     pub fn flip(&mut self) {
         self.occupancy.swap(0, 1);
@@ -136,6 +230,9 @@ impl ChessBoard {
             piece_mask.flip_mut();
         }
 
+        self.checks.swap(0, 1);
+        self.pockets.swap(0, 1);
+        self.promoted.flip_mut();
         self.side.flip();
     }
 
@@ -147,6 +244,8 @@ impl ChessBoard {
         for piece_mask in &mut self.pieces {
             *piece_mask = flip_horizontal(*piece_mask);
         }
+
+        self.promoted = flip_horizontal(self.promoted);
     }
 }
 
@@ -1,7 +1,215 @@
-use crate::{base_structures::CastleRights, board::ChessBoard, Piece, Side, Square, FEN};
+use std::fmt::{Display, Formatter, Result};
 
-impl From<&FEN> for ChessBoard {
-    fn from(value: &FEN) -> Self {
+use crate::{
+    base_structures::CastleRights, board::ChessBoard, Bitboard, CastleNotation, FenError, Piece,
+    Side, Square, Variant, FEN,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BoardError {
+    InvalidFen(FenError),
+    InvalidKingCount(Side, u32),
+    TooManyPieces(Side, u32),
+    TooManyPawns(Side, u32),
+    PawnOnBackRank(Square),
+    OpponentInCheck,
+    TooManyCheckers(u32),
+    InvalidCastleRights(char),
+    InvalidEnPassant(Square),
+}
+
+impl Display for BoardError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        match self {
+            BoardError::InvalidFen(error) => write!(formatter, "{error}"),
+            BoardError::InvalidKingCount(side, count) => {
+                write!(formatter, "{side} has an invalid number of kings ({count})")
+            }
+            BoardError::TooManyPieces(side, count) => {
+                write!(formatter, "{side} has too many pieces ({count})")
+            }
+            BoardError::TooManyPawns(side, count) => {
+                write!(formatter, "{side} has too many pawns ({count})")
+            }
+            BoardError::PawnOnBackRank(square) => write!(formatter, "pawn on back rank {square}"),
+            BoardError::OpponentInCheck => write!(formatter, "side not to move is in check"),
+            BoardError::TooManyCheckers(count) => {
+                write!(formatter, "king is attacked by {count} pieces")
+            }
+            BoardError::InvalidCastleRights(right) => {
+                write!(
+                    formatter,
+                    "castle right '{right}' has no matching king and rook"
+                )
+            }
+            BoardError::InvalidEnPassant(square) => {
+                write!(formatter, "en passant square {square} has no matching pawn")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BoardError {}
+
+impl From<FenError> for BoardError {
+    fn from(value: FenError) -> Self {
+        BoardError::InvalidFen(value)
+    }
+}
+
+impl TryFrom<&FEN> for ChessBoard {
+    type Error = BoardError;
+
+    fn try_from(value: &FEN) -> std::result::Result<Self, Self::Error> {
+        Self::from_fen_checked(value)
+    }
+}
+
+impl TryFrom<&str> for ChessBoard {
+    type Error = BoardError;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        Self::from_fen_checked(&FEN::parse(value)?)
+    }
+}
+
+impl ChessBoard {
+    pub fn from_fen_checked(value: &FEN) -> std::result::Result<Self, BoardError> {
+        let board = Self::from_fen_unchecked(value)?;
+        board.validate_fen(value)?;
+        Ok(board)
+    }
+
+    pub fn from_fen_with_variant(
+        value: &FEN,
+        variant: Variant,
+    ) -> std::result::Result<Self, BoardError> {
+        let mut board = Self::from_fen_unchecked(value)?;
+        board.set_variant(variant);
+        board.validate_fen(value)?;
+        Ok(board)
+    }
+
+    //Letters that name the same right twice collapse when parsed, so only the fen shows them
+    fn validate_fen(&self, value: &FEN) -> std::result::Result<(), BoardError> {
+        self.validate()?;
+
+        let rights = u8::from(self.castle_rights());
+        if value.castle_rights != "-"
+            && value.castle_rights.chars().count() != rights.count_ones() as usize
+        {
+            return Err(BoardError::InvalidCastleRights(
+                value.castle_rights.chars().last().unwrap_or('-'),
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn validate(&self) -> std::result::Result<(), BoardError> {
+        for side in [Side::WHITE, Side::BLACK] {
+            //In Horde white plays with an army of up to 36 pawns and no king,
+            //in Crazyhouse captured pieces can be dropped back for the other side
+            let (max_kings, max_pieces, max_pawns) = match self.variant {
+                Variant::Horde if side == Side::WHITE => (0, 36, 36),
+                Variant::Crazyhouse => (1, 32, 16),
+                _ => (1, 16, 8),
+            };
+
+            let kings = self.piece_mask_for_side(Piece::KING, side).pop_count();
+            if kings != max_kings {
+                return Err(BoardError::InvalidKingCount(side, kings));
+            }
+
+            let pieces = self.occupancy_for_side(side).pop_count();
+            if pieces > max_pieces {
+                return Err(BoardError::TooManyPieces(side, pieces));
+            }
+
+            let pawns = self.piece_mask_for_side(Piece::PAWN, side).pop_count();
+            if pawns > max_pawns {
+                return Err(BoardError::TooManyPawns(side, pawns));
+            }
+        }
+
+        let mut back_rank_pawns =
+            self.piece_mask(Piece::PAWN) & (Bitboard::RANK_1 | Bitboard::RANK_8);
+        if self.variant == Variant::Horde {
+            back_rank_pawns &= !self.piece_mask_for_side(Piece::PAWN, Side::WHITE);
+        }
+
+        if back_rank_pawns.is_not_empty() {
+            return Err(BoardError::PawnOnBackRank(back_rank_pawns.ls1b_square()));
+        }
+
+        if self.is_king_attacked(self.side.flipped()) {
+            return Err(BoardError::OpponentInCheck);
+        }
+
+        let checkers = self.generate_checkers_mask(self.side).pop_count();
+        if checkers > 2 {
+            return Err(BoardError::TooManyCheckers(checkers));
+        }
+
+        let rights = u8::from(self.castle_rights());
+        //In fen order, king side first
+        for index in [1, 0, 3, 2] {
+            if rights & (0b1000 >> index) == 0 {
+                continue;
+            }
+
+            let side = Side::from((index / 2) as u8);
+            let king_square = self.king_square(side);
+            let rook_square = self.castle_rights().rook_square(index);
+
+            let valid = rook_square != Square::NULL
+                && king_square.get_rank() == 7 * u8::from(side)
+                && rook_square.get_rank() == king_square.get_rank()
+                && (rook_square.file() > king_square.file()) == (index % 2 == 1)
+                && self
+                    .piece_mask_for_side(Piece::ROOK, side)
+                    .get_bit(rook_square);
+
+            if !valid {
+                let file = if rook_square == Square::NULL {
+                    '-'
+                } else {
+                    (b'A' + rook_square.file()) as char
+                };
+
+                return Err(BoardError::InvalidCastleRights(if side == Side::WHITE {
+                    file
+                } else {
+                    file.to_ascii_lowercase()
+                }));
+            }
+        }
+
+        let en_passant = self.en_passant_square;
+        if en_passant != Square::NULL {
+            let (target_rank, pawn_square, origin_square) = if self.side == Side::WHITE {
+                (5, en_passant >> 8, en_passant << 8)
+            } else {
+                (2, en_passant << 8, en_passant >> 8)
+            };
+
+            let valid = en_passant.get_rank() == target_rank
+                && !self.occupancy().get_bit(en_passant)
+                && !self.occupancy().get_bit(origin_square)
+                && self
+                    .piece_mask_for_side(Piece::PAWN, self.side.flipped())
+                    .get_bit(pawn_square);
+
+            if !valid {
+                return Err(BoardError::InvalidEnPassant(en_passant));
+            }
+        }
+
+        Ok(())
+    }
+
+    //Parses the fen without checking that the position is legal
+    pub fn from_fen_unchecked(value: &FEN) -> std::result::Result<Self, BoardError> {
         let mut board = Self::default();
 
         for (rank_index, rank) in value.board.clone().into_iter().enumerate() {
@@ -25,24 +233,21 @@ impl From<&FEN> for ChessBoard {
                     'r' => board.set_piece_on_square(square, Piece::ROOK, side),
                     'q' => board.set_piece_on_square(square, Piece::QUEEN, side),
                     'k' => board.set_piece_on_square(square, Piece::KING, side),
-                    _ => println!("Incorrect character in fen string!"),
+                    _ => return Err(FenError::InvalidPiece(piece_char).into()),
                 }
 
                 index += 1;
                 file += 1;
+
+                if rank.as_bytes().get(index) == Some(&b'~') {
+                    board.promoted.set_bit(square);
+                    index += 1;
+                }
             }
         }
 
         board.side = Side::from(value.side_to_move == "b");
 
-        if board.is_square_attacked(
-            board.king_square(board.side.flipped()),
-            board.side.flipped(),
-        ) {
-            println!("Tried to parse illegal position. Defaulting to starting position instead.");
-            return Self::default();
-        }
-
         let kings = [
             board.king_square(Side::WHITE),
             board.king_square(Side::BLACK),
@@ -61,6 +266,10 @@ impl From<&FEN> for ChessBoard {
                 Side::BLACK
             };
             let king_square = board.king_square(side);
+            if king_square == Square::NULL {
+                continue;
+            }
+
             let file = char.to_ascii_uppercase() as u8 - b'A';
             let index = 2 * u8::from(side) + if file < king_square.file() { 0 } else { 1 };
             rights |= 0b1000 >> index;
@@ -76,7 +285,29 @@ impl From<&FEN> for ChessBoard {
 
         board.half_moves = value.half_move_counter.parse::<u8>().unwrap_or(0);
 
-        board
+        if let Some((white, black)) = value.remaining_checks() {
+            board.variant = Variant::ThreeCheck;
+            board.checks = [3 - white.min(3), 3 - black.min(3)];
+        }
+
+        if let Some(pocket) = value.pocket() {
+            board.variant = Variant::Crazyhouse;
+            for piece_char in pocket.chars() {
+                let side = Side::from(piece_char.is_ascii_lowercase());
+                let piece = match piece_char.to_ascii_lowercase() {
+                    'p' => Piece::PAWN,
+                    'n' => Piece::KNIGHT,
+                    'b' => Piece::BISHOP,
+                    'r' => Piece::ROOK,
+                    'q' => Piece::QUEEN,
+                    _ => continue,
+                };
+
+                board.pockets[usize::from(side)][usize::from(piece)] += 1;
+            }
+        }
+
+        Ok(board)
     }
 }
 
@@ -102,6 +333,11 @@ impl From<&ChessBoard> for FEN {
                     } else {
                         fen.push(piece_char);
                     }
+                    if value.variant() == Variant::Crazyhouse
+                        && value.promoted_pieces().get_bit(square)
+                    {
+                        fen.push('~');
+                    }
                 } else {
                     empty_count += 1;
                 }
@@ -114,6 +350,32 @@ impl From<&ChessBoard> for FEN {
             }
         }
 
+        // Pieces in hand
+        if value.variant() == Variant::Crazyhouse {
+            fen.push('[');
+            for side in [Side::WHITE, Side::BLACK] {
+                for piece in [
+                    Piece::QUEEN,
+                    Piece::ROOK,
+                    Piece::BISHOP,
+                    Piece::KNIGHT,
+                    Piece::PAWN,
+                ] {
+                    let piece_char = char::from(piece);
+                    let piece_char = if side == Side::WHITE {
+                        piece_char.to_ascii_uppercase()
+                    } else {
+                        piece_char
+                    };
+
+                    for _ in 0..value.pocket(side, piece) {
+                        fen.push(piece_char);
+                    }
+                }
+            }
+            fen.push(']');
+        }
+
         // Side to move
         fen.push(' ');
         fen.push(if value.side() == Side::WHITE {
@@ -134,6 +396,13 @@ impl From<&ChessBoard> for FEN {
             fen.push_str(String::from(value.en_passant_square()).as_str());
         }
 
+        // Remaining checks
+        if value.variant() == Variant::ThreeCheck {
+            let white = 3 - value.checks_given(Side::WHITE);
+            let black = 3 - value.checks_given(Side::BLACK);
+            fen.push_str(&format!(" {white}+{black}"));
+        }
+
         // Halfmove clock and fullmove number
         fen.push(' ');
         fen.push_str(&value.half_moves().to_string());
@@ -143,3 +412,11 @@ impl From<&ChessBoard> for FEN {
         FEN::from(fen)
     }
 }
+
+impl FEN {
+    pub fn from_board(board: &ChessBoard, castle_notation: CastleNotation) -> Self {
+        let mut result = Self::from(board);
+        result.set_castle_notation(castle_notation);
+        result
+    }
+}
@@ -1,7 +1,13 @@
-use crate::{attacks::Rays, board::ChessBoard, Attacks, Bitboard, Piece, Side, Square};
+use crate::{attacks::Rays, board::ChessBoard, Attacks, Bitboard, Piece, Side, Square, Variant};
 
 impl ChessBoard {
     pub fn is_insufficient_material(&self) -> bool {
+        match self.variant() {
+            Variant::Standard => (),
+            Variant::ThreeCheck => return self.occupancy().pop_count() <= 2,
+            Variant::KingOfTheHill | Variant::Horde | Variant::Crazyhouse => return false,
+        }
+
         if (self.piece_mask(Piece::PAWN)
             | self.piece_mask(Piece::ROOK)
             | self.piece_mask(Piece::QUEEN))
@@ -69,23 +75,35 @@ impl ChessBoard {
         self.is_square_attacked_with_occupancy(square, self.occupancy(), defender_side)
     }
 
+    //Horde side has no king, so it can never be in check
+    #[inline]
+    pub fn is_king_attacked(&self, side: Side) -> bool {
+        let king_square = self.king_square(side);
+        king_square != Square::NULL && self.is_square_attacked(king_square, side)
+    }
+
     #[inline]
     pub fn is_in_check(&self) -> bool {
-        self.is_square_attacked(self.king_square(self.side()), self.side())
+        self.is_king_attacked(self.side())
     }
 
     #[inline]
     pub fn generate_checkers_mask(&self, defender_side: Side) -> Bitboard {
-        self.all_attackers_to_square_for_side(
-            self.occupancy(),
-            self.king_square(defender_side),
-            defender_side,
-        )
+        let king_square = self.king_square(defender_side);
+        if king_square == Square::NULL {
+            return Bitboard::EMPTY;
+        }
+
+        self.all_attackers_to_square_for_side(self.occupancy(), king_square, defender_side)
     }
 
     pub fn generate_pin_masks(&self, defender_side: Side) -> (Bitboard, Bitboard) {
         let attacker_side = defender_side.flipped();
         let king_square = self.king_square(defender_side);
+        if king_square == Square::NULL {
+            return (Bitboard::EMPTY, Bitboard::EMPTY);
+        }
+
         let defender_occupancy = self.occupancy_for_side(defender_side);
         let attacker_occupancy = self.occupancy_for_side(attacker_side);
         let queens = self.piece_mask_for_side(Piece::QUEEN, attacker_side);
@@ -117,8 +135,8 @@ impl ChessBoard {
     pub fn generate_attack_map(&self, attacker_side: Side) -> Bitboard {
         let mut threats = Bitboard::EMPTY;
 
-        let king_square = self.king_square(attacker_side.flipped());
-        let occupancy = self.occupancy() ^ Bitboard::from(king_square);
+        let occupancy =
+            self.occupancy() & !self.piece_mask_for_side(Piece::KING, attacker_side.flipped());
 
         let attacker_pieces = self.occupancy_for_side(attacker_side);
         let queens = self.piece_mask(Piece::QUEEN);
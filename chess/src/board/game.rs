@@ -0,0 +1,140 @@
+use crate::{
+    board::{move_history::MoveHistory, unmake_move::UndoInfo},
+    BoardError, ChessBoard, ChessPosition, Move, ZobristKey, FEN,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Game {
+    initial_board: ChessBoard,
+    board: ChessBoard,
+    moves: Vec<Move>,
+    undo_history: Vec<UndoInfo>,
+    hashes: Vec<ZobristKey>,
+}
+
+impl Game {
+    pub fn new(board: ChessBoard) -> Self {
+        Self {
+            initial_board: board,
+            board,
+            moves: Vec::new(),
+            undo_history: Vec::new(),
+            hashes: vec![board.hash()],
+        }
+    }
+
+    #[inline]
+    pub fn initial_board(&self) -> &ChessBoard {
+        &self.initial_board
+    }
+
+    #[inline]
+    pub fn board(&self) -> &ChessBoard {
+        &self.board
+    }
+
+    #[inline]
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    #[inline]
+    pub fn hashes(&self) -> &[ZobristKey] {
+        &self.hashes
+    }
+
+    #[inline]
+    pub fn ply(&self) -> usize {
+        self.moves.len()
+    }
+
+    #[inline]
+    pub fn last_move(&self) -> Option<Move> {
+        self.moves.last().copied()
+    }
+
+    pub fn make_move(&mut self, mv: Move) {
+        let mask = self.board.castle_rights().get_castle_mask();
+        let undo_info = self.board.make_move_with_undo(mv, &mask);
+
+        self.moves.push(mv);
+        self.undo_history.push(undo_info);
+        self.hashes.push(self.board.hash());
+    }
+
+    pub fn unmake_move(&mut self) -> Option<Move> {
+        let mv = self.moves.pop()?;
+        let undo_info = self.undo_history.pop()?;
+
+        self.board.unmake_move(mv, &undo_info);
+        self.hashes.pop();
+
+        Some(mv)
+    }
+
+    pub fn board_at(&self, ply: usize) -> Option<ChessBoard> {
+        if ply > self.ply() {
+            return None;
+        }
+
+        let mut board = self.board;
+        for idx in (ply..self.ply()).rev() {
+            board.unmake_move(self.moves[idx], &self.undo_history[idx]);
+        }
+
+        Some(board)
+    }
+
+    pub fn repetitions(&self) -> usize {
+        let key = self.board.hash();
+        let reversible_plies = (self.board.half_moves() as usize).min(self.ply());
+
+        self.hashes[self.ply() - reversible_plies..]
+            .iter()
+            .filter(|&&hash| hash == key)
+            .count()
+    }
+
+    //Replays only the reversible plies the position history can hold
+    pub fn position(&self) -> ChessPosition {
+        let reversible_plies = (self.board.half_moves() as usize)
+            .min(self.ply())
+            .min(MoveHistory::CAPACITY - 1);
+        let start = self.ply() - reversible_plies;
+
+        let mut position = ChessPosition::from(self.board_at(start).unwrap());
+        for &mv in &self.moves[start..] {
+            position.make_move_no_mask(mv);
+        }
+
+        position
+    }
+
+    pub fn common_prefix(&self, other: &Game) -> Option<usize> {
+        if self.initial_board != other.initial_board {
+            return None;
+        }
+
+        Some(
+            self.moves
+                .iter()
+                .zip(other.moves.iter())
+                .take_while(|(a, b)| a == b)
+                .count(),
+        )
+    }
+}
+
+impl From<ChessBoard> for Game {
+    fn from(value: ChessBoard) -> Self {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<&FEN> for Game {
+    type Error = BoardError;
+
+    fn try_from(value: &FEN) -> Result<Self, Self::Error> {
+        Ok(Self::new(ChessBoard::try_from(value)?))
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{ChessBoard, Move, MoveFlag, Piece, Side, Square};
+use crate::{ChessBoard, Move, MoveFlag, Piece, Side, Square, Variant};
 
 impl ChessBoard {
     pub fn make_move_no_mask(&mut self, mv: Move) {
@@ -37,6 +37,7 @@ impl ChessBoard {
         mask: &[u8; 64],
     ) {
         match mv.flag() {
+            MoveFlag::DROP => self.make_drop::<COLOR>(mv.drop_piece(), to_square),
             MoveFlag::QUIET_MOVE => self.make_move_moved_piece::<COLOR, { MoveFlag::QUIET_MOVE }>(
                 mv,
                 from_square,
@@ -136,6 +137,20 @@ impl ChessBoard {
         }
     }
 
+    fn make_drop<const COLOR: u8>(&mut self, piece: Piece, to_square: Square) {
+        self.pockets[usize::from(COLOR)][usize::from(piece)] -= 1;
+        self.set_piece_on_square(to_square, piece, Side::from(COLOR));
+
+        if piece == Piece::PAWN {
+            self.half_moves = 0;
+        } else {
+            self.half_moves += 1;
+        }
+
+        self.en_passant_square = Square::NULL;
+        self.side.flip();
+    }
+
     #[inline]
     fn make_move_moved_piece<const COLOR: u8, const MOVE_FLAG: u16>(
         &mut self,
@@ -268,6 +283,28 @@ impl ChessBoard {
         to_square: Square,
         mask: &[u8; 64],
     ) {
+        //Captured pieces go to the capturing side's pocket, promoted pieces return as pawns
+        if self.variant == Variant::Crazyhouse {
+            let pocket = &mut self.pockets[usize::from(COLOR)];
+            if CAPTURED_PIECE != NONE {
+                let captured = if self.promoted.get_bit(to_square) {
+                    PAWN
+                } else {
+                    CAPTURED_PIECE
+                };
+                pocket[usize::from(captured)] += 1;
+            } else if MOVE_FLAG == MoveFlag::EN_PASSANT {
+                pocket[usize::from(PAWN)] += 1;
+            }
+
+            let was_promoted = self.promoted.get_bit(from_square);
+            self.promoted.pop_bit(from_square);
+            self.promoted.pop_bit(to_square);
+            if was_promoted || MOVE_FLAG >= MoveFlag::KNIGHT_PROMOTION {
+                self.promoted.set_bit(to_square);
+            }
+        }
+
         if CAPTURED_PIECE != NONE {
             self.remove_piece_on_square(
                 to_square,
@@ -299,7 +336,8 @@ impl ChessBoard {
 
         let side_flip = usize::from(COLOR) * 56;
         match MOVE_FLAG {
-            MoveFlag::DOUBLE_PUSH => {
+            //Horde pawns pushed from the first rank cannot be captured en passant
+            MoveFlag::DOUBLE_PUSH if from_square.get_rank() != 0 => {
                 self.en_passant_square = to_square ^ 8;
             }
             MoveFlag::QUEEN_SIDE_CASTLE => {
@@ -347,6 +385,11 @@ impl ChessBoard {
         }
 
         self.side.flip();
+
+        if self.variant == Variant::ThreeCheck && self.is_in_check() {
+            let checks = &mut self.checks[usize::from(COLOR)];
+            *checks = (*checks + 1).min(3);
+        }
     }
 }
 
@@ -3,9 +3,19 @@ mod chess_board_from;
 mod chess_board_utils;
 mod chess_position;
 mod draw_chess_board;
+mod game;
 mod make_move;
 mod move_history;
+mod packed_board;
+mod san;
 mod see;
+mod threats;
+mod unmake_move;
 
 pub use chess_board::ChessBoard;
+pub use chess_board_from::BoardError;
 pub use chess_position::ChessPosition;
+pub use game::Game;
+pub use packed_board::PackedBoard;
+pub use packed_board::PACKED_BOARD_SIZE;
+pub use unmake_move::UndoInfo;
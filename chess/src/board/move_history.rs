@@ -1,10 +1,12 @@
 use crate::base_structures::ZobristKey;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct MoveHistory([ZobristKey; 101], usize);
+pub struct MoveHistory([ZobristKey; MoveHistory::CAPACITY], usize);
 impl MoveHistory {
+    pub const CAPACITY: usize = 101;
+
     pub fn new() -> Self {
-        Self([ZobristKey::default(); 101], 0)
+        Self([ZobristKey::default(); Self::CAPACITY], 0)
     }
 
     #[inline]
@@ -31,6 +33,13 @@ impl MoveHistory {
 
     #[inline]
     pub fn push(&mut self, key: ZobristKey) {
+        //Past the fifty move rule the oldest keys are dropped, the game is already claimable
+        //as a draw by then
+        if self.1 == Self::CAPACITY {
+            self.0.copy_within(1.., 0);
+            self.1 -= 1;
+        }
+
         self.0[self.1] = key;
         self.1 += 1;
     }
@@ -0,0 +1,145 @@
+use crate::{
+    base_structures::CastleRights, Bitboard, BoardError, ChessBoard, Piece, Side, Square, Variant,
+};
+
+pub const PACKED_BOARD_SIZE: usize = 32;
+
+//Fixed 32 byte encoding of a board. Pieces are stored as 4 bit codes (piece | side << 3)
+//in the order of the occupied squares, so at most 32 pieces can be packed.
+//
+//  bytes  0..8   occupancy
+//  bytes  8..24  piece codes, two per byte
+//  byte   24     side to move << 7 | en passant square (64 when none)
+//  byte   25     half move clock
+//  byte   26     variant | white checks << 4 | black checks << 6
+//  byte   27     castle rights
+//  bytes 28..32  castle rook squares (64 when the right is missing)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PackedBoard([u8; PACKED_BOARD_SIZE]);
+
+impl PackedBoard {
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; PACKED_BOARD_SIZE] {
+        &self.0
+    }
+
+    //Checks that every field decodes to a valid value, the position itself is checked when unpacked
+    pub fn from_bytes(bytes: [u8; PACKED_BOARD_SIZE]) -> Option<Self> {
+        let packed = Self(bytes);
+
+        let occupancy = packed.occupancy();
+        if occupancy.pop_count() > 32 {
+            return None;
+        }
+
+        for index in 0..occupancy.pop_count() as usize {
+            let code = packed.piece_code(index);
+            if code & 0b111 > 5 {
+                return None;
+            }
+        }
+
+        for index in occupancy.pop_count() as usize..32 {
+            if packed.piece_code(index) != 0 {
+                return None;
+            }
+        }
+
+        if bytes[24] & 0x7F > 64 || usize::from(bytes[26] & 0xF) >= Variant::ALL.len() {
+            return None;
+        }
+
+        if bytes[27] > 0b1111 || bytes[28..].iter().any(|&square| square > 64) {
+            return None;
+        }
+
+        Some(packed)
+    }
+
+    #[inline]
+    fn occupancy(&self) -> Bitboard {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&self.0[..8]);
+        Bitboard::from(u64::from_le_bytes(bytes))
+    }
+
+    #[inline]
+    fn piece_code(&self, index: usize) -> u8 {
+        (self.0[8 + index / 2] >> (4 * (index % 2))) & 0xF
+    }
+}
+
+impl ChessBoard {
+    //Crazyhouse pockets and boards with more than 32 pieces do not fit the format
+    pub fn to_packed(&self) -> Option<PackedBoard> {
+        let occupancy = self.occupancy();
+        if self.variant == Variant::Crazyhouse || occupancy.pop_count() > 32 {
+            return None;
+        }
+
+        let mut bytes = [0u8; PACKED_BOARD_SIZE];
+        bytes[..8].copy_from_slice(&occupancy.get_value().to_le_bytes());
+
+        let mut index = 0;
+        occupancy.map(|square| {
+            let piece = u8::from(self.piece_on_square(square));
+            let side = u8::from(self.color_on_square(square));
+            bytes[8 + index / 2] |= (piece | side << 3) << (4 * (index % 2));
+            index += 1;
+        });
+
+        bytes[24] = u8::from(self.side) << 7 | u8::from(self.en_passant_square);
+        bytes[25] = self.half_moves;
+
+        let variant = Variant::ALL
+            .iter()
+            .position(|&variant| variant == self.variant)
+            .unwrap_or(0) as u8;
+        bytes[26] = variant | self.checks[0] << 4 | self.checks[1] << 6;
+
+        let rights = u8::from(&self.castle_rights);
+        bytes[27] = rights;
+        for (index, rook_square) in bytes[28..].iter_mut().enumerate() {
+            *rook_square = if rights & (0b1000 >> index) != 0 {
+                u8::from(self.castle_rights.rook_square(index))
+            } else {
+                u8::from(Square::NULL)
+            };
+        }
+
+        Some(PackedBoard(bytes))
+    }
+
+    pub fn from_packed(packed: &PackedBoard) -> Result<Self, BoardError> {
+        let bytes = packed.as_bytes();
+        let mut board = Self::default();
+
+        let mut index = 0;
+        packed.occupancy().map(|square| {
+            let code = packed.piece_code(index);
+            board.set_piece_on_square(square, Piece::from(code & 0b111), Side::from(code >> 3));
+            index += 1;
+        });
+
+        board.side = Side::from(bytes[24] >> 7);
+        board.en_passant_square = Square::from(bytes[24] & 0x7F);
+        board.half_moves = bytes[25];
+        board.variant = Variant::ALL[usize::from(bytes[26] & 0xF)];
+        board.checks = [(bytes[26] >> 4) & 0b11, bytes[26] >> 6];
+
+        let mut rooks = [Square::NULL; 4];
+        for (rook, &square) in rooks.iter_mut().zip(&bytes[28..]) {
+            *rook = Square::from(square);
+        }
+
+        let kings = [
+            board.king_square(Side::WHITE),
+            board.king_square(Side::BLACK),
+        ];
+        board.castle_rights = CastleRights::create_base(rooks, kings);
+        board.castle_rights.set_rights(bytes[27]);
+
+        board.validate()?;
+        Ok(board)
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{ChessBoard, Move, MoveFlag, Piece};
+
+impl ChessBoard {
+    pub fn to_san(&self, mv: Move) -> String {
+        let mut result = match mv.flag() {
+            MoveFlag::KING_SIDE_CASTLE => String::from("O-O"),
+            MoveFlag::QUEEN_SIDE_CASTLE => String::from("O-O-O"),
+            MoveFlag::DROP => mv.to_string(false),
+            _ => {
+                let from = mv.from_square();
+                let to = mv.to_square();
+                let piece = self.piece_on_square(from);
+                let mut result = String::new();
+
+                if piece == Piece::PAWN {
+                    if mv.is_capture() {
+                        result.push((b'a' + from.file()) as char);
+                    }
+                } else {
+                    result.push(char::from(piece).to_ascii_uppercase());
+
+                    let mut same_file = false;
+                    let mut same_rank = false;
+                    let mut ambiguous = false;
+                    self.map_legal_moves(|legal_mv| {
+                        let legal_from = legal_mv.from_square();
+                        if legal_mv.to_square() != to
+                            || legal_from == from
+                            || legal_mv.is_castle()
+                            || legal_mv.is_drop()
+                            || self.piece_on_square(legal_from) != piece
+                        {
+                            return;
+                        }
+
+                        ambiguous = true;
+                        same_file |= legal_from.file() == from.file();
+                        same_rank |= legal_from.get_rank() == from.get_rank();
+                    });
+
+                    if ambiguous {
+                        if !same_file {
+                            result.push((b'a' + from.file()) as char);
+                        } else if !same_rank {
+                            result.push((b'1' + from.get_rank()) as char);
+                        } else {
+                            result.push_str(&from.to_string());
+                        }
+                    }
+                }
+
+                if mv.is_capture() {
+                    result.push('x');
+                }
+
+                result.push_str(&to.to_string());
+
+                if mv.is_promotion() {
+                    result.push('=');
+                    result.push(char::from(mv.promotion_piece()).to_ascii_uppercase());
+                }
+
+                result
+            }
+        };
+
+        let mut board_copy = *self;
+        board_copy.make_move_no_mask(mv);
+        if board_copy.is_in_check() {
+            let mut has_moves = false;
+            board_copy.map_legal_moves(|_| has_moves = true);
+            result.push(if has_moves { '+' } else { '#' });
+        }
+
+        result
+    }
+
+    pub fn parse_move(&self, text: &str) -> Option<Move> {
+        let text = text.trim();
+        let normalized_text = normalize_san(text);
+
+        let mut result = None;
+        self.map_legal_moves(|mv| {
+            if result.is_some() {
+                return;
+            }
+
+            if mv.to_string(false) == text
+                || mv.to_string(true) == text
+                || normalize_san(&self.to_san(mv)) == normalized_text
+            {
+                result = Some(mv);
+            }
+        });
+
+        result
+    }
+}
+
+fn normalize_san(san: &str) -> String {
+    san.trim_end_matches("e.p.")
+        .chars()
+        .filter(|char| !matches!(char, '+' | '#' | '!' | '?' | '=' | 'x' | ':' | '-'))
+        .map(|char| if char == '0' { 'O' } else { char })
+        .collect()
+}
@@ -19,7 +19,7 @@ impl ChessBoard {
         let mut next_victim = if mv.is_promotion() {
             mv.promotion_piece()
         } else {
-            self.piece_on_square(from)
+            self.moved_piece(mv)
         };
 
         // Balance is the value of the move minus threshold. Function
@@ -46,7 +46,9 @@ impl ChessBoard {
 
         // Let occupied suppose that the move was actually made
         let mut occupancy = self.occupancy().exclude(from).include(to);
-        if mv.is_en_passant() {
+        if mv.is_drop() {
+            occupancy = self.occupancy().include(to);
+        } else if mv.is_en_passant() {
             occupancy = occupancy.exclude(self.en_passant_square())
         }
 
@@ -117,6 +119,84 @@ impl ChessBoard {
         self.side() != side
     }
 
+    //Exact material outcome of the exchange started by the move, with both sides
+    //always recapturing with their least valuable attacker or standing pat
+    pub fn see_score(&self, mv: Move) -> i32 {
+        let from = mv.from_square();
+        let to = mv.to_square();
+
+        let mut gains = [0i32; 64];
+        gains[0] = self.move_value(mv);
+
+        let mut last_victim = if mv.is_promotion() {
+            mv.promotion_piece()
+        } else {
+            self.moved_piece(mv)
+        };
+
+        let bishops = self.piece_mask(Piece::BISHOP) | self.piece_mask(Piece::QUEEN);
+        let rooks = self.piece_mask(Piece::ROOK) | self.piece_mask(Piece::QUEEN);
+
+        let mut occupancy = self.occupancy().exclude(from).include(to);
+        if mv.is_drop() {
+            occupancy = self.occupancy().include(to);
+        } else if mv.is_en_passant() {
+            occupancy = occupancy.exclude(self.en_passant_square())
+        }
+
+        let mut attackers = self.all_attackers_to_square(occupancy, to) & occupancy;
+        let mut side = self.side().flipped();
+        let mut depth = 0;
+
+        loop {
+            let side_attackers = attackers & self.occupancy_for_side(side);
+            if side_attackers.is_empty() {
+                break;
+            }
+
+            let mut attacker = Piece::PAWN;
+            for piece_idx in u8::from(Piece::PAWN)..=u8::from(Piece::KING) {
+                attacker = Piece::from(piece_idx);
+                if (side_attackers & self.piece_mask(attacker)).is_not_empty() {
+                    break;
+                }
+            }
+
+            //King cannot recapture into a defended square
+            if attacker == Piece::KING
+                && (attackers & self.occupancy_for_side(side.flipped())).is_not_empty()
+            {
+                break;
+            }
+
+            depth += 1;
+            gains[depth] = see_value(last_victim) - gains[depth - 1];
+            last_victim = attacker;
+
+            occupancy =
+                occupancy.exclude((side_attackers & self.piece_mask(attacker)).ls1b_square());
+
+            if attacker == Piece::PAWN || attacker == Piece::BISHOP || attacker == Piece::QUEEN {
+                attackers |= Attacks::get_bishop_attacks(to, occupancy) & bishops;
+            }
+
+            if attacker == Piece::ROOK || attacker == Piece::QUEEN {
+                attackers |= Attacks::get_rook_attacks(to, occupancy) & rooks;
+            }
+
+            attackers &= occupancy;
+            side.flip();
+        }
+
+        //Each side can stop capturing when continuing the exchange loses material
+        while depth > 0 {
+            gains[depth - 1] = -(-gains[depth - 1]).max(gains[depth]);
+            depth -= 1;
+        }
+
+        gains[0]
+    }
+
     fn move_value(&self, mv: Move) -> i32 {
         let mut value = 0;
 
@@ -0,0 +1,79 @@
+use crate::{attacks::Rays, Attacks, Bitboard, ChessBoard, Piece, Side, Square};
+
+impl ChessBoard {
+    //Pieces of the side that are attacked by at least one enemy piece
+    pub fn attacked_pieces(&self, side: Side) -> Bitboard {
+        self.generate_attack_map(side.flipped()) & self.occupancy_for_side(side)
+    }
+
+    //Pieces of the side, other than the king, that no friendly piece defends
+    pub fn undefended_pieces(&self, side: Side) -> Bitboard {
+        let mut result = Bitboard::EMPTY;
+        let pieces = self.occupancy_for_side(side) & !self.piece_mask(Piece::KING);
+        pieces.map(|square| {
+            if !self.is_square_attacked(square, side.flipped()) {
+                result.set_bit(square)
+            }
+        });
+
+        result
+    }
+
+    //Attacked pieces that are either undefended or attacked by a less valuable piece
+    pub fn hanging_pieces(&self, side: Side) -> Bitboard {
+        let mut result = Bitboard::EMPTY;
+        let pieces = self.attacked_pieces(side) & !self.piece_mask(Piece::KING);
+        pieces.map(|square| {
+            let attackers = self.all_attackers_to_square(self.occupancy(), square);
+            let enemy_attackers = attackers & self.occupancy_for_side(side.flipped());
+            let defenders = attackers & self.occupancy_for_side(side);
+
+            let value = ChessBoard::see_value(self.piece_on_square(square));
+            let mut lowest_attacker = i32::MAX;
+            enemy_attackers.map(|attacker| {
+                let attacker_value = ChessBoard::see_value(self.piece_on_square(attacker));
+                lowest_attacker = lowest_attacker.min(attacker_value)
+            });
+
+            if defenders.is_empty() || lowest_attacker < value {
+                result.set_bit(square)
+            }
+        });
+
+        result
+    }
+
+    //Pieces of the side that are pinned to their own king
+    pub fn pinned_pieces(&self, side: Side) -> Bitboard {
+        let (bishop_pins, rook_pins) = self.generate_pin_masks(side);
+        (bishop_pins | rook_pins) & self.occupancy_for_side(side)
+    }
+
+    //Pieces of the side standing between a friendly slider and the enemy king,
+    //so moving them away gives a discovered check
+    pub fn discovered_attack_candidates(&self, side: Side) -> Bitboard {
+        let enemy_king = self.king_square(side.flipped());
+        if enemy_king == Square::NULL {
+            return Bitboard::EMPTY;
+        }
+
+        let own_occupancy = self.occupancy_for_side(side);
+        let enemy_occupancy = self.occupancy_for_side(side.flipped());
+        let queens = self.piece_mask_for_side(Piece::QUEEN, side);
+
+        let sliders = (Attacks::get_bishop_attacks(enemy_king, enemy_occupancy)
+            & (self.piece_mask_for_side(Piece::BISHOP, side) | queens))
+            | (Attacks::get_rook_attacks(enemy_king, enemy_occupancy)
+                & (self.piece_mask_for_side(Piece::ROOK, side) | queens));
+
+        let mut result = Bitboard::EMPTY;
+        sliders.map(|slider| {
+            let blockers = Rays::get_ray(enemy_king, slider).exclude(slider) & own_occupancy;
+            if blockers.only_one_bit() {
+                result |= blockers;
+            }
+        });
+
+        result
+    }
+}
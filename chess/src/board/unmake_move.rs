@@ -0,0 +1,99 @@
+use crate::{base_structures::CastleRights, Bitboard, ChessBoard, Move, MoveFlag, Piece, Square};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UndoInfo {
+    captured_piece: Piece,
+    castle_rights: CastleRights,
+    en_passant_square: Square,
+    half_moves: u8,
+    checks: [u8; 2],
+    pockets: [[u8; 5]; 2],
+    promoted: Bitboard,
+}
+
+impl UndoInfo {
+    #[inline]
+    pub fn captured_piece(&self) -> Piece {
+        self.captured_piece
+    }
+}
+
+impl ChessBoard {
+    pub fn undo_info(&self, mv: Move) -> UndoInfo {
+        let captured_piece = if mv.flag() == MoveFlag::EN_PASSANT {
+            Piece::PAWN
+        } else if mv.is_capture() {
+            self.piece_on_square(mv.to_square())
+        } else {
+            Piece::NONE
+        };
+
+        UndoInfo {
+            captured_piece,
+            castle_rights: self.castle_rights,
+            en_passant_square: self.en_passant_square,
+            half_moves: self.half_moves,
+            checks: self.checks,
+            pockets: self.pockets,
+            promoted: self.promoted,
+        }
+    }
+
+    pub fn make_move_with_undo(&mut self, mv: Move, castle_mask: &[u8; 64]) -> UndoInfo {
+        let undo_info = self.undo_info(mv);
+        self.make_move(mv, castle_mask);
+        undo_info
+    }
+
+    pub fn unmake_move(&mut self, mv: Move, undo_info: &UndoInfo) {
+        self.side.flip();
+        self.castle_rights = undo_info.castle_rights;
+        self.en_passant_square = undo_info.en_passant_square;
+        self.half_moves = undo_info.half_moves;
+        self.checks = undo_info.checks;
+        self.pockets = undo_info.pockets;
+        self.promoted = undo_info.promoted;
+
+        let side = self.side;
+        let from_square = mv.from_square();
+        let to_square = mv.to_square();
+        let side_flip = u8::from(side) * 56;
+
+        match mv.flag() {
+            MoveFlag::DROP => self.remove_piece_on_square(to_square, mv.drop_piece(), side),
+            MoveFlag::KING_SIDE_CASTLE | MoveFlag::QUEEN_SIDE_CASTLE => {
+                let (king_square, rook_square, rook_index) =
+                    if mv.flag() == MoveFlag::KING_SIDE_CASTLE {
+                        (side_flip + 6, side_flip + 5, usize::from(side) * 2 + 1)
+                    } else {
+                        (side_flip + 2, side_flip + 3, usize::from(side) * 2)
+                    };
+
+                self.remove_piece_on_square(Square::from(king_square), Piece::KING, side);
+                self.remove_piece_on_square(Square::from(rook_square), Piece::ROOK, side);
+                self.set_piece_on_square(from_square, Piece::KING, side);
+                self.set_piece_on_square(
+                    self.castle_rights.rook_square(rook_index),
+                    Piece::ROOK,
+                    side,
+                );
+            }
+            _ => {
+                if mv.is_promotion() {
+                    self.remove_piece_on_square(to_square, mv.promotion_piece(), side);
+                    self.set_piece_on_square(from_square, Piece::PAWN, side);
+                } else {
+                    let moved_piece = self.piece_on_square(to_square);
+                    self.remove_piece_on_square(to_square, moved_piece, side);
+                    self.set_piece_on_square(from_square, moved_piece, side);
+                }
+
+                if mv.flag() == MoveFlag::EN_PASSANT {
+                    self.set_piece_on_square(to_square ^ 8, Piece::PAWN, side.flipped());
+                } else if undo_info.captured_piece != Piece::NONE {
+                    self.set_piece_on_square(to_square, undo_info.captured_piece, side.flipped());
+                }
+            }
+        }
+    }
+}
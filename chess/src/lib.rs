@@ -2,6 +2,10 @@ mod attacks;
 mod base_structures;
 mod board;
 mod move_gen;
+mod perft_table;
+mod rules;
+#[cfg(feature = "serde")]
+mod serde_impls;
 
 use std::time::Duration;
 use std::time::Instant;
@@ -9,61 +13,112 @@ use std::time::Instant;
 pub use attacks::Attacks;
 pub use attacks::Rays;
 pub use base_structures::Bitboard;
+pub use base_structures::CastleNotation;
+pub use base_structures::Epd;
+pub use base_structures::EpdError;
+pub use base_structures::FenError;
 pub use base_structures::Move;
 pub use base_structures::MoveFlag;
+pub use base_structures::MoveList;
 pub use base_structures::Piece;
 pub use base_structures::Side;
 pub use base_structures::Square;
+pub use base_structures::Variant;
 pub use base_structures::ZobristKey;
 pub use base_structures::FEN;
+pub use base_structures::MAX_MOVES;
+pub use board::BoardError;
 pub use board::ChessBoard;
 pub use board::ChessPosition;
+pub use board::Game;
+pub use board::PackedBoard;
+pub use board::UndoInfo;
+pub use board::PACKED_BOARD_SIZE;
+pub use move_gen::LegalityFilter;
+pub use move_gen::MoveStage;
+pub use move_gen::StagedMoves;
+pub use perft_table::perft_divide;
+pub use perft_table::perft_hashed;
+pub use perft_table::perft_parallel;
+pub use perft_table::PerftTable;
+pub use rules::DrawReason;
+pub use rules::GameResult;
+pub use rules::WinReason;
+pub use rules::FIFTY_MOVE_RULE_PLIES;
+pub use rules::SEVENTY_FIVE_MOVE_RULE_PLIES;
 
 pub const DEFAULT_PERFT_DEPTH: u8 = 5;
 
+//Move generator used by perft_with_mode, for comparing the generators against each other
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PerftMode {
+    #[default]
+    Legal,
+    PseudoLegal,
+    Staged,
+}
+
+impl PerftMode {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "legal" => Some(PerftMode::Legal),
+            "pseudo" | "pseudolegal" => Some(PerftMode::PseudoLegal),
+            "staged" => Some(PerftMode::Staged),
+            _ => None,
+        }
+    }
+}
+
 pub fn perft<const BULK: bool, const SPLIT: bool, const CHESS_960: bool>(
     board: &ChessBoard,
     depth: Option<u8>,
 ) -> (u128, Duration) {
     let timer = Instant::now();
     let mask = board.castle_rights().get_castle_mask();
-    let result = if board.side() == Side::WHITE {
-        perft_internal_white::<BULK, SPLIT, CHESS_960>(
-            board,
-            depth.unwrap_or(DEFAULT_PERFT_DEPTH),
-            &mask,
-        )
-    } else {
-        perft_internal_black::<BULK, SPLIT, CHESS_960>(
-            board,
-            depth.unwrap_or(DEFAULT_PERFT_DEPTH),
-            &mask,
-        )
+    let depth = depth.unwrap_or(DEFAULT_PERFT_DEPTH);
+    let white = board.side() == Side::WHITE;
+    let result = match (white, board.variant().has_variant_results()) {
+        (true, false) => perft_internal_white::<BULK, SPLIT, CHESS_960, false>(board, depth, &mask),
+        (true, true) => perft_internal_white::<BULK, SPLIT, CHESS_960, true>(board, depth, &mask),
+        (false, false) => {
+            perft_internal_black::<BULK, SPLIT, CHESS_960, false>(board, depth, &mask)
+        }
+        (false, true) => perft_internal_black::<BULK, SPLIT, CHESS_960, true>(board, depth, &mask),
     };
     let duration = timer.elapsed();
 
     (result, duration)
 }
 
-fn perft_internal_white<const BULK: bool, const SPLIT: bool, const CHESS_960: bool>(
+fn perft_internal_white<
+    const BULK: bool,
+    const SPLIT: bool,
+    const CHESS_960: bool,
+    const VARIANT: bool,
+>(
     board: &ChessBoard,
     depth: u8,
     mask: &[u8; 64],
 ) -> u128 {
     let mut node_count = 0u128;
 
-    if BULK && depth == 1 {
-        return board.count_legal_moves::<0>() as u128;
-    }
-
     if !BULK && depth == 0 {
         return 1;
     }
 
+    if VARIANT && board.variant_result().is_some() {
+        return 0;
+    }
+
+    if BULK && depth == 1 {
+        return board.count_legal_moves::<0>() as u128;
+    }
+
     board.map_legal_moves_templated::<_, 0>(|mv| {
         let mut board_copy = *board;
         board_copy.make_move_templated::<0>(mv, mask);
-        let result = perft_internal_black::<BULK, false, CHESS_960>(&board_copy, depth - 1, mask);
+        let result =
+            perft_internal_black::<BULK, false, CHESS_960, VARIANT>(&board_copy, depth - 1, mask);
         node_count += result;
 
         if SPLIT {
@@ -74,25 +129,35 @@ fn perft_internal_white<const BULK: bool, const SPLIT: bool, const CHESS_960: bo
     node_count
 }
 
-fn perft_internal_black<const BULK: bool, const SPLIT: bool, const CHESS_960: bool>(
+fn perft_internal_black<
+    const BULK: bool,
+    const SPLIT: bool,
+    const CHESS_960: bool,
+    const VARIANT: bool,
+>(
     board: &ChessBoard,
     depth: u8,
     mask: &[u8; 64],
 ) -> u128 {
     let mut node_count = 0u128;
 
-    if BULK && depth == 1 {
-        return board.count_legal_moves::<1>() as u128;
-    }
-
     if !BULK && depth == 0 {
         return 1;
     }
 
+    if VARIANT && board.variant_result().is_some() {
+        return 0;
+    }
+
+    if BULK && depth == 1 {
+        return board.count_legal_moves::<1>() as u128;
+    }
+
     board.map_legal_moves_templated::<_, 1>(|mv| {
         let mut board_copy = *board;
         board_copy.make_move_templated::<1>(mv, mask);
-        let result = perft_internal_white::<BULK, false, CHESS_960>(&board_copy, depth - 1, mask);
+        let result =
+            perft_internal_white::<BULK, false, CHESS_960, VARIANT>(&board_copy, depth - 1, mask);
         node_count += result;
 
         if SPLIT {
@@ -102,3 +167,55 @@ fn perft_internal_black<const BULK: bool, const SPLIT: bool, const CHESS_960: bo
 
     node_count
 }
+
+pub fn perft_with_mode(board: &ChessBoard, depth: Option<u8>, mode: PerftMode) -> (u128, Duration) {
+    let timer = Instant::now();
+    let mask = board.castle_rights().get_castle_mask();
+    let result = perft_mode_internal(
+        board,
+        depth.unwrap_or(DEFAULT_PERFT_DEPTH),
+        &mask,
+        mode,
+        board.variant().has_variant_results(),
+    );
+
+    (result, timer.elapsed())
+}
+
+fn perft_mode_internal(
+    board: &ChessBoard,
+    depth: u8,
+    mask: &[u8; 64],
+    mode: PerftMode,
+    variant_results: bool,
+) -> u128 {
+    if depth == 0 {
+        return 1;
+    }
+
+    if variant_results && board.variant_result().is_some() {
+        return 0;
+    }
+
+    let mut node_count = 0u128;
+    let mut visit = |mv: Move| {
+        let mut board_copy = *board;
+        board_copy.make_move(mv, mask);
+        node_count += perft_mode_internal(&board_copy, depth - 1, mask, mode, variant_results);
+    };
+
+    match mode {
+        PerftMode::Legal => board.map_legal_moves(visit),
+        PerftMode::PseudoLegal => {
+            let filter = board.legality_filter();
+            board.map_pseudo_legal_moves(|mv| {
+                if filter.is_legal(board, mv) {
+                    visit(mv)
+                }
+            });
+        }
+        PerftMode::Staged => board.staged_moves().for_each(visit),
+    }
+
+    node_count
+}
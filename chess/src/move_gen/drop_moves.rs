@@ -0,0 +1,51 @@
+use crate::{move_gen::generate_moves::MoveGen, Bitboard, ChessBoard, Move, Piece, Side, Variant};
+
+const DROP_PIECES: [Piece; 5] = [
+    Piece::PAWN,
+    Piece::KNIGHT,
+    Piece::BISHOP,
+    Piece::ROOK,
+    Piece::QUEEN,
+];
+
+impl MoveGen {
+    pub fn generate_drops<F: FnMut(Move), const COLOR: u8>(
+        board: &ChessBoard,
+        push_map: Bitboard,
+        apply_move: &mut F,
+    ) {
+        if board.variant() != Variant::Crazyhouse {
+            return;
+        }
+
+        for piece in DROP_PIECES {
+            if board.pocket(Side::from(COLOR), piece) == 0 {
+                continue;
+            }
+
+            (drop_targets(piece, push_map))
+                .map(|to_square| apply_move(Move::from_drop(piece, to_square)));
+        }
+    }
+
+    pub fn count_drops<const COLOR: u8>(board: &ChessBoard, push_map: Bitboard) -> u32 {
+        if board.variant() != Variant::Crazyhouse {
+            return 0;
+        }
+
+        DROP_PIECES
+            .iter()
+            .filter(|&&piece| board.pocket(Side::from(COLOR), piece) > 0)
+            .map(|&piece| drop_targets(piece, push_map).pop_count())
+            .sum()
+    }
+}
+
+//Pawns cannot be dropped on the first or last rank
+fn drop_targets(piece: Piece, push_map: Bitboard) -> Bitboard {
+    if piece == Piece::PAWN {
+        push_map & !(Bitboard::RANK_1 | Bitboard::RANK_8)
+    } else {
+        push_map
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     attacks::Rays,
     move_gen::piece_moves::{BISHOP, KNIGHT, ROOK},
-    Bitboard, ChessBoard, Move, Side,
+    Bitboard, ChessBoard, Move, MoveList, Side, Square,
 };
 
 pub(super) const WHITE: u8 = 0;
@@ -36,12 +36,39 @@ impl ChessBoard {
         }
     }
 
+    //Promotions without a capture, in check only the ones that block the checker
+    #[inline]
+    pub fn map_quiet_promotions<F: FnMut(Move)>(&self, mut apply_move: F) {
+        if self.side() == Side::WHITE {
+            self.map_quiet_promotions_internal::<_, WHITE>(&mut apply_move)
+        } else {
+            self.map_quiet_promotions_internal::<_, BLACK>(&mut apply_move)
+        }
+    }
+
+    pub fn legal_moves(&self) -> MoveList {
+        let mut result = MoveList::new();
+        self.map_legal_moves(|mv| result.push(mv));
+        result
+    }
+
+    pub fn capture_moves(&self) -> MoveList {
+        let mut result = MoveList::new();
+        self.map_capture_moves(|mv| result.push(mv));
+        result
+    }
+
     pub fn map_legal_moves_internal<F: FnMut(Move), const COLOR: u8, const CAPTURE_ONLY: bool>(
         &self,
         apply_move: &mut F,
     ) {
-        let attack_map = self.generate_attack_map(Side::from(COLOR).flipped());
         let king_square = self.king_square(Side::from(COLOR));
+        if king_square == Square::NULL {
+            self.map_kingless_moves::<_, COLOR, CAPTURE_ONLY>(apply_move);
+            return;
+        }
+
+        let attack_map = self.generate_attack_map(Side::from(COLOR).flipped());
         let (bishop_pins, rook_pins) = self.generate_pin_masks(Side::from(COLOR));
         let checkers = if attack_map.get_bit(king_square) {
             self.generate_checkers_mask(Side::from(COLOR))
@@ -102,6 +129,10 @@ impl ChessBoard {
                 rook_pins,
                 apply_move,
             );
+
+            if !CAPTURE_ONLY {
+                MoveGen::generate_drops::<_, COLOR>(self, push_map, apply_move);
+            }
         } else if (checkers & (checkers - 1)).is_empty() {
             let checker = checkers.ls1b_square();
             let push_map = Rays::get_ray(king_square, checker).exclude(checker);
@@ -138,12 +169,23 @@ impl ChessBoard {
                 rook_pins,
                 apply_move,
             );
+
+            //Blocking a slider check with a dropped piece
+            if !CAPTURE_ONLY {
+                MoveGen::generate_drops::<_, COLOR>(self, push_map, apply_move);
+            }
         }
     }
 
     pub fn count_legal_moves<const COLOR: u8>(&self) -> u32 {
-        let attack_map = self.generate_attack_map(Side::from(COLOR).flipped());
         let king_square = self.king_square(Side::from(COLOR));
+        if king_square == Square::NULL {
+            let mut result = 0;
+            self.map_kingless_moves::<_, COLOR, false>(&mut |_| result += 1);
+            return result;
+        }
+
+        let attack_map = self.generate_attack_map(Side::from(COLOR).flipped());
         let (bishop_pins, rook_pins) = self.generate_pin_masks(Side::from(COLOR));
         let checkers = if attack_map.get_bit(king_square) {
             self.generate_checkers_mask(Side::from(COLOR))
@@ -190,6 +232,7 @@ impl ChessBoard {
                 bishop_pins,
                 rook_pins,
             );
+            result += MoveGen::count_drops::<COLOR>(self, push_map);
         } else if (checkers & (checkers - 1)).is_empty() {
             let checker = checkers.ls1b_square();
             let push_map = Rays::get_ray(king_square, checker).exclude(checker);
@@ -222,8 +265,87 @@ impl ChessBoard {
                 bishop_pins,
                 rook_pins,
             );
+            result += MoveGen::count_drops::<COLOR>(self, push_map);
         };
 
         result
     }
+
+    fn map_quiet_promotions_internal<F: FnMut(Move), const COLOR: u8>(&self, apply_move: &mut F) {
+        let last_rank = if COLOR == WHITE {
+            Bitboard::RANK_8
+        } else {
+            Bitboard::RANK_1
+        };
+
+        //Pushes land on the last rank only from the promotion rank, so no other pawn moves
+        let mut push_map = !self.occupancy() & last_rank;
+
+        let king_square = self.king_square(Side::from(COLOR));
+        let (bishop_pins, rook_pins) = if king_square == Square::NULL {
+            (Bitboard::EMPTY, Bitboard::EMPTY)
+        } else {
+            let checkers = self.generate_checkers_mask(Side::from(COLOR));
+            if checkers.is_not_empty() {
+                if (checkers & (checkers - 1)).is_not_empty() {
+                    return;
+                }
+
+                let checker = checkers.ls1b_square();
+                push_map &= Rays::get_ray(king_square, checker).exclude(checker);
+            }
+
+            self.generate_pin_masks(Side::from(COLOR))
+        };
+
+        MoveGen::generate_pawn_pushes::<_, COLOR>(
+            self,
+            push_map,
+            bishop_pins,
+            rook_pins,
+            apply_move,
+        );
+    }
+
+    //Horde side has no king, so every pseudo legal move is legal
+    fn map_kingless_moves<F: FnMut(Move), const COLOR: u8, const CAPTURE_ONLY: bool>(
+        &self,
+        apply_move: &mut F,
+    ) {
+        let push_map = !self.occupancy();
+        let capture_map = self.occupancy_for_side(Side::from(COLOR).flipped());
+
+        MoveGen::generate_pawn_moves::<_, COLOR, CAPTURE_ONLY>(
+            self,
+            push_map,
+            capture_map,
+            Bitboard::EMPTY,
+            Bitboard::EMPTY,
+            apply_move,
+        );
+        MoveGen::generate_piece_moves::<_, COLOR, { KNIGHT }, CAPTURE_ONLY>(
+            self,
+            push_map,
+            capture_map,
+            Bitboard::EMPTY,
+            Bitboard::EMPTY,
+            apply_move,
+        );
+        MoveGen::generate_piece_moves::<_, COLOR, { BISHOP }, CAPTURE_ONLY>(
+            self,
+            push_map,
+            capture_map,
+            Bitboard::EMPTY,
+            Bitboard::EMPTY,
+            apply_move,
+        );
+        MoveGen::generate_piece_moves::<_, COLOR, { ROOK }, CAPTURE_ONLY>(
+            self,
+            push_map,
+            capture_map,
+            Bitboard::EMPTY,
+            Bitboard::EMPTY,
+            apply_move,
+        );
+    }
 }
@@ -0,0 +1,170 @@
+use crate::{
+    move_gen::generate_moves::{MoveGen, BLACK, WHITE},
+    Attacks, ChessBoard, Move, MoveFlag, Piece, Side, Variant,
+};
+
+impl ChessBoard {
+    pub fn is_pseudo_legal(&self, mv: Move) -> bool {
+        let side = self.side();
+        let from_square = mv.from_square();
+        let to_square = mv.to_square();
+        let flag = mv.flag();
+
+        if mv.is_drop() {
+            return self.is_drop_pseudo_legal(mv);
+        }
+
+        if !self.occupancy_for_side(side).get_bit(from_square) {
+            return false;
+        }
+
+        let piece = self.piece_on_square(from_square);
+
+        if mv.is_castle() {
+            return piece == Piece::KING && self.is_castle_legal(mv);
+        }
+
+        if self.occupancy_for_side(side).get_bit(to_square) {
+            return false;
+        }
+
+        let is_enemy_square = self.occupancy_for_side(side.flipped()).get_bit(to_square);
+
+        if flag == MoveFlag::EN_PASSANT {
+            return piece == Piece::PAWN
+                && to_square == self.en_passant_square()
+                && Attacks::get_pawn_attacks(from_square, side).get_bit(to_square);
+        }
+
+        //Flag 0b0111 is not used by any move
+        if flag & MoveFlag::KNIGHT_PROMOTION == 0 && flag > MoveFlag::EN_PASSANT {
+            return false;
+        }
+
+        if mv.is_capture() != is_enemy_square {
+            return false;
+        }
+
+        if piece != Piece::PAWN {
+            if mv.is_promotion() || flag == MoveFlag::DOUBLE_PUSH {
+                return false;
+            }
+
+            let attacks = match piece {
+                Piece::KNIGHT => Attacks::get_knight_attacks(from_square),
+                Piece::BISHOP => Attacks::get_bishop_attacks(from_square, self.occupancy()),
+                Piece::ROOK => Attacks::get_rook_attacks(from_square, self.occupancy()),
+                Piece::QUEEN => {
+                    Attacks::get_bishop_attacks(from_square, self.occupancy())
+                        | Attacks::get_rook_attacks(from_square, self.occupancy())
+                }
+                _ => Attacks::get_king_attacks(from_square),
+            };
+
+            return attacks.get_bit(to_square);
+        }
+
+        let promotion_rank = if side == Side::WHITE { 7 } else { 0 };
+        if mv.is_promotion() != (to_square.get_rank() == promotion_rank) {
+            return false;
+        }
+
+        if mv.is_capture() {
+            return Attacks::get_pawn_attacks(from_square, side).get_bit(to_square);
+        }
+
+        let from = u8::from(from_square);
+        let to = u8::from(to_square);
+        let single_push = if side == Side::WHITE {
+            from + 8
+        } else {
+            from.wrapping_sub(8)
+        };
+
+        if flag == MoveFlag::DOUBLE_PUSH {
+            let double_push_rank = if side == Side::WHITE { 1 } else { 6 };
+            let double_push = if side == Side::WHITE {
+                from + 16
+            } else {
+                from.wrapping_sub(16)
+            };
+
+            //Horde pawns can also double push from the first rank
+            let horde_push = self.variant() == Variant::Horde
+                && side == Side::WHITE
+                && from_square.get_rank() == 0;
+
+            return (from_square.get_rank() == double_push_rank || horde_push)
+                && to == double_push
+                && !self.occupancy().get_bit(single_push.into());
+        }
+
+        to == single_push
+    }
+
+    pub fn is_legal(&self, mv: Move) -> bool {
+        if !self.is_pseudo_legal(mv) {
+            return false;
+        }
+
+        if mv.is_castle() {
+            return true;
+        }
+
+        let side = self.side();
+        let mut board_copy = *self;
+        board_copy.make_move_no_mask(mv);
+
+        !board_copy.is_king_attacked(side)
+    }
+
+    fn is_drop_pseudo_legal(&self, mv: Move) -> bool {
+        let side = self.side();
+        let piece = mv.drop_piece();
+        let to_square = mv.to_square();
+
+        if self.variant() != Variant::Crazyhouse
+            || u8::from(mv.from_square()) > u8::from(Piece::QUEEN)
+            || self.pocket(side, piece) == 0
+            || self.occupancy().get_bit(to_square)
+        {
+            return false;
+        }
+
+        piece != Piece::PAWN || (1..7).contains(&to_square.get_rank())
+    }
+
+    fn is_castle_legal(&self, mv: Move) -> bool {
+        let side = self.side();
+        let attack_map = self.generate_attack_map(side.flipped());
+        let king_square = self.king_square(side);
+
+        if attack_map.get_bit(king_square) {
+            return false;
+        }
+
+        let (_, rook_pins) = self.generate_pin_masks(side);
+
+        let mut result = false;
+        let mut apply_move = |castle_mv: Move| result |= castle_mv == mv;
+        if side == Side::WHITE {
+            MoveGen::generate_castle_moves::<_, WHITE>(
+                self,
+                attack_map,
+                king_square,
+                rook_pins,
+                &mut apply_move,
+            );
+        } else {
+            MoveGen::generate_castle_moves::<_, BLACK>(
+                self,
+                attack_map,
+                king_square,
+                rook_pins,
+                &mut apply_move,
+            );
+        }
+
+        result
+    }
+}
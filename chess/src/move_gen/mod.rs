@@ -1,4 +1,12 @@
+mod drop_moves;
 mod generate_moves;
 mod king_moves;
+mod legality;
 mod pawn_moves;
 mod piece_moves;
+mod pseudo_legal;
+mod staged_moves;
+
+pub use pseudo_legal::LegalityFilter;
+pub use staged_moves::MoveStage;
+pub use staged_moves::StagedMoves;
@@ -1,6 +1,6 @@
 use crate::{
     move_gen::generate_moves::{MoveGen, WHITE},
-    Attacks, Bitboard, ChessBoard, Move, MoveFlag, Piece, Side, Square,
+    Attacks, Bitboard, ChessBoard, Move, MoveFlag, Piece, Side, Square, Variant,
 };
 
 impl MoveGen {
@@ -33,6 +33,23 @@ impl MoveGen {
         );
     }
 
+    pub fn generate_pawn_pushes<F: FnMut(Move), const COLOR: u8>(
+        board: &ChessBoard,
+        push_map: Bitboard,
+        bishop_pins: Bitboard,
+        rook_pins: Bitboard,
+        apply_move: &mut F,
+    ) {
+        let pawns = board.piece_mask_for_side(Piece::PAWN, Side::from(COLOR));
+        handle_pawn_pushes::<_, COLOR>(
+            board,
+            pawns & !bishop_pins,
+            push_map,
+            rook_pins,
+            apply_move,
+        );
+    }
+
     pub fn count_pawn_moves<const COLOR: u8>(
         board: &ChessBoard,
         push_map: Bitboard,
@@ -70,11 +87,7 @@ fn handle_pawn_pushes<F: FnMut(Move), const COLOR: u8>(
     } else {
         Bitboard::RANK_2
     };
-    let double_push_rank = if COLOR == WHITE {
-        Bitboard::RANK_2
-    } else {
-        Bitboard::RANK_7
-    };
+    let double_push_rank = double_push_rank::<COLOR>(board);
 
     let moveable_pawns = pawns & !promotion_rank;
     let moveable_pawns = (moveable_pawns & !rook_pins) | (moveable_pawns & vertical_pin);
@@ -264,7 +277,7 @@ fn handle_en_passant<F: FnMut(Move), const COLOR: u8>(
         let mv = Move::from_squares(from_square, en_passant_square, MoveFlag::EN_PASSANT);
         board.make_move_no_mask(mv);
 
-        if !board.is_square_attacked(board.king_square(Side::from(COLOR)), Side::from(COLOR)) {
+        if !board.is_king_attacked(Side::from(COLOR)) {
             apply_move(mv)
         }
     });
@@ -285,11 +298,7 @@ fn count_pawn_pushes<const COLOR: u8>(
     } else {
         Bitboard::RANK_2
     };
-    let double_push_rank = if COLOR == WHITE {
-        Bitboard::RANK_2
-    } else {
-        Bitboard::RANK_7
-    };
+    let double_push_rank = double_push_rank::<COLOR>(board);
 
     let moveable_pawns = pawns & !promotion_rank;
     let moveable_pawns = (moveable_pawns & !rook_pins) | (moveable_pawns & vertical_pin);
@@ -380,10 +389,23 @@ fn count_en_passant<const COLOR: u8>(board: &ChessBoard, mut pawns: Bitboard) ->
         let mv = Move::from_squares(from_square, en_passant_square, MoveFlag::EN_PASSANT);
         board.make_move_no_mask(mv);
 
-        if !board.is_square_attacked(board.king_square(Side::from(COLOR)), Side::from(COLOR)) {
+        if !board.is_king_attacked(Side::from(COLOR)) {
             result += 1;
         }
     });
 
     result
 }
+
+fn double_push_rank<const COLOR: u8>(board: &ChessBoard) -> Bitboard {
+    if COLOR != WHITE {
+        return Bitboard::RANK_7;
+    }
+
+    //Horde pawns can also double push from the first rank
+    if board.variant() == Variant::Horde {
+        Bitboard::RANK_1 | Bitboard::RANK_2
+    } else {
+        Bitboard::RANK_2
+    }
+}
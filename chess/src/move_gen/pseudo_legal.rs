@@ -0,0 +1,173 @@
+use crate::{
+    attacks::Rays,
+    base_structures::CastleRights,
+    move_gen::{
+        generate_moves::{MoveGen, BLACK, WHITE},
+        piece_moves::{BISHOP, KNIGHT, ROOK},
+    },
+    Bitboard, ChessBoard, Move, MoveList, Side, Square,
+};
+
+//Pins and checks of the side to move, computed once so pseudo legal moves
+//can be verified without making them
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LegalityFilter {
+    king_square: Square,
+    checkers: Bitboard,
+    evasion_mask: Bitboard,
+    pinned: Bitboard,
+}
+
+impl LegalityFilter {
+    pub fn is_legal(&self, board: &ChessBoard, mv: Move) -> bool {
+        let side = board.side();
+
+        //Castles are only generated when legal and kingless sides cannot be in check
+        if self.king_square == Square::NULL || mv.is_castle() {
+            return true;
+        }
+
+        if mv.is_en_passant() {
+            let mut board_copy = *board;
+            board_copy.make_move_no_mask(mv);
+            return !board_copy.is_king_attacked(side);
+        }
+
+        let to_square = mv.to_square();
+        if mv.is_drop() {
+            return self.evasion_mask.get_bit(to_square);
+        }
+
+        let from_square = mv.from_square();
+        if from_square == self.king_square {
+            let occupancy = board.occupancy() ^ Bitboard::from(self.king_square);
+            return board
+                .all_attackers_to_square_for_side(occupancy, to_square, side)
+                .is_empty();
+        }
+
+        if !self.evasion_mask.get_bit(to_square) {
+            return false;
+        }
+
+        //Pinned pieces can only move along the line through their king
+        !self.pinned.get_bit(from_square)
+            || Rays::get_ray(self.king_square, to_square).get_bit(from_square)
+            || Rays::get_ray(self.king_square, from_square).get_bit(to_square)
+    }
+
+    #[inline]
+    pub fn checkers(&self) -> Bitboard {
+        self.checkers
+    }
+}
+
+impl ChessBoard {
+    pub fn legality_filter(&self) -> LegalityFilter {
+        let side = self.side();
+        let king_square = self.king_square(side);
+        let checkers = self.generate_checkers_mask(side);
+        let (bishop_pins, rook_pins) = self.generate_pin_masks(side);
+
+        let evasion_mask = if checkers.is_empty() {
+            Bitboard::FULL
+        } else if (checkers & (checkers - 1)).is_empty() {
+            Rays::get_ray(king_square, checkers.ls1b_square()) | checkers
+        } else {
+            Bitboard::EMPTY
+        };
+
+        LegalityFilter {
+            king_square,
+            checkers,
+            evasion_mask,
+            pinned: (bishop_pins | rook_pins) & self.occupancy_for_side(side),
+        }
+    }
+
+    //Generates moves without checking pins or king safety, except for castles and
+    //en passant, which are always legal. Use LegalityFilter to discard the illegal ones.
+    pub fn map_pseudo_legal_moves<F: FnMut(Move)>(&self, mut apply_move: F) {
+        if self.side() == Side::WHITE {
+            self.map_pseudo_legal_moves_internal::<_, WHITE>(&mut apply_move)
+        } else {
+            self.map_pseudo_legal_moves_internal::<_, BLACK>(&mut apply_move)
+        }
+    }
+
+    pub fn pseudo_legal_moves(&self) -> MoveList {
+        let mut result = MoveList::new();
+        self.map_pseudo_legal_moves(|mv| result.push(mv));
+        result
+    }
+
+    fn map_pseudo_legal_moves_internal<F: FnMut(Move), const COLOR: u8>(&self, apply_move: &mut F) {
+        let side = Side::from(COLOR);
+        let king_square = self.king_square(side);
+        let push_map = !self.occupancy();
+        let capture_map = self.occupancy_for_side(side.flipped());
+
+        if king_square != Square::NULL {
+            MoveGen::generate_king_moves::<_, COLOR, false>(
+                self,
+                Bitboard::EMPTY,
+                king_square,
+                apply_move,
+            );
+
+            let castle_rights = u8::from(self.castle_rights());
+            let side_rights = if COLOR == WHITE {
+                CastleRights::WHITE_KING | CastleRights::WHITE_QUEEN
+            } else {
+                CastleRights::BLACK_KING | CastleRights::BLACK_QUEEN
+            };
+            if castle_rights & side_rights != 0 {
+                let attack_map = self.generate_attack_map(side.flipped());
+                if !attack_map.get_bit(king_square) {
+                    let (_, rook_pins) = self.generate_pin_masks(side);
+                    MoveGen::generate_castle_moves::<_, COLOR>(
+                        self,
+                        attack_map,
+                        king_square,
+                        rook_pins,
+                        apply_move,
+                    );
+                }
+            }
+        }
+
+        MoveGen::generate_pawn_moves::<_, COLOR, false>(
+            self,
+            push_map,
+            capture_map,
+            Bitboard::EMPTY,
+            Bitboard::EMPTY,
+            apply_move,
+        );
+        MoveGen::generate_piece_moves::<_, COLOR, { KNIGHT }, false>(
+            self,
+            push_map,
+            capture_map,
+            Bitboard::EMPTY,
+            Bitboard::EMPTY,
+            apply_move,
+        );
+        MoveGen::generate_piece_moves::<_, COLOR, { BISHOP }, false>(
+            self,
+            push_map,
+            capture_map,
+            Bitboard::EMPTY,
+            Bitboard::EMPTY,
+            apply_move,
+        );
+        MoveGen::generate_piece_moves::<_, COLOR, { ROOK }, false>(
+            self,
+            push_map,
+            capture_map,
+            Bitboard::EMPTY,
+            Bitboard::EMPTY,
+            apply_move,
+        );
+        MoveGen::generate_drops::<_, COLOR>(self, push_map, apply_move);
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{ChessBoard, Move, MoveList, Piece};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MoveStage {
+    Evasions,
+    Captures,
+    Promotions,
+    Quiets,
+    Done,
+}
+
+//Lazily yields legal moves stage by stage, so quiet moves are only generated
+//once all captures and promotions have been consumed
+pub struct StagedMoves<'a> {
+    board: &'a ChessBoard,
+    stage: MoveStage,
+    moves: MoveList,
+    index: usize,
+}
+
+impl ChessBoard {
+    pub fn staged_moves(&self) -> StagedMoves<'_> {
+        let stage = if self.is_in_check() {
+            MoveStage::Evasions
+        } else {
+            MoveStage::Captures
+        };
+
+        let mut result = StagedMoves {
+            board: self,
+            stage,
+            moves: MoveList::new(),
+            index: 0,
+        };
+        result.generate();
+        result
+    }
+}
+
+impl StagedMoves<'_> {
+    //Stage of the moves that are currently being returned
+    #[inline]
+    pub fn stage(&self) -> MoveStage {
+        self.stage
+    }
+
+    fn generate(&mut self) {
+        self.moves.clear();
+        self.index = 0;
+
+        match self.stage {
+            MoveStage::Evasions => {
+                self.board.map_legal_moves(|mv| self.moves.push(mv));
+                let board = self.board;
+                self.moves.sort_unstable_by_key(|&mv| {
+                    if mv.is_capture() {
+                        -mvv_lva(board, mv)
+                    } else {
+                        0
+                    }
+                });
+            }
+            MoveStage::Captures => {
+                self.board.map_capture_moves(|mv| self.moves.push(mv));
+                let board = self.board;
+                self.moves.sort_unstable_by_key(|&mv| -mvv_lva(board, mv));
+            }
+            MoveStage::Promotions => self.board.map_quiet_promotions(|mv| self.moves.push(mv)),
+            MoveStage::Quiets => {
+                self.board.map_legal_moves(|mv| {
+                    if !mv.is_capture() && !mv.is_promotion() {
+                        self.moves.push(mv)
+                    }
+                });
+            }
+            MoveStage::Done => {}
+        }
+    }
+}
+
+impl Iterator for StagedMoves<'_> {
+    type Item = Move;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.index < self.moves.len() {
+                self.index += 1;
+                return Some(self.moves[self.index - 1]);
+            }
+
+            self.stage = match self.stage {
+                MoveStage::Captures => MoveStage::Promotions,
+                MoveStage::Promotions => MoveStage::Quiets,
+                MoveStage::Evasions | MoveStage::Quiets | MoveStage::Done => MoveStage::Done,
+            };
+
+            if self.stage == MoveStage::Done {
+                return None;
+            }
+
+            self.generate();
+        }
+    }
+}
+
+//Most valuable victim first, least valuable attacker breaking the ties
+fn mvv_lva(board: &ChessBoard, mv: Move) -> i32 {
+    let victim = if mv.is_en_passant() {
+        Piece::PAWN
+    } else {
+        board.piece_on_square(mv.to_square())
+    };
+
+    ChessBoard::see_value(victim) * 8 - i32::from(u8::from(board.moved_piece(mv)))
+}
@@ -0,0 +1,186 @@
+use std::{
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{ChessBoard, Move, Side, DEFAULT_PERFT_DEPTH};
+
+//Shared perft transposition table. Entries store key ^ data next to data, so an entry
+//torn by a concurrent write fails the key check instead of returning a wrong count
+pub struct PerftTable {
+    entries: Vec<PerftEntry>,
+}
+
+#[derive(Default)]
+struct PerftEntry {
+    key: AtomicU64,
+    data: AtomicU64,
+}
+
+impl PerftTable {
+    pub fn new(size_mb: usize) -> Self {
+        let len = (size_mb * 1024 * 1024 / std::mem::size_of::<PerftEntry>()).max(1);
+        let mut entries = Vec::with_capacity(len);
+        entries.resize_with(len, PerftEntry::default);
+        Self { entries }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&self) {
+        for entry in &self.entries {
+            entry.key.store(0, Ordering::Relaxed);
+            entry.data.store(0, Ordering::Relaxed);
+        }
+    }
+
+    #[inline]
+    fn entry(&self, key: u64) -> &PerftEntry {
+        &self.entries[(key % self.entries.len() as u64) as usize]
+    }
+
+    fn probe(&self, key: u64, depth: u8) -> Option<u128> {
+        let entry = self.entry(key);
+        let data = entry.data.load(Ordering::Relaxed);
+        let stored_key = entry.key.load(Ordering::Relaxed) ^ data;
+
+        if stored_key == key && data & 0xFF == u64::from(depth) {
+            Some(u128::from(data >> 8))
+        } else {
+            None
+        }
+    }
+
+    fn store(&self, key: u64, depth: u8, count: u128) {
+        //Counts that do not fit next to the depth are simply not cached
+        if count >= 1 << 56 {
+            return;
+        }
+
+        let data = (count as u64) << 8 | u64::from(depth);
+        let entry = self.entry(key);
+        entry.key.store(key ^ data, Ordering::Relaxed);
+        entry.data.store(data, Ordering::Relaxed);
+    }
+}
+
+pub fn perft_hashed(board: &ChessBoard, depth: Option<u8>, table: &PerftTable) -> (u128, Duration) {
+    perft_parallel(board, depth, 1, Some(table))
+}
+
+//Splits the root moves between the threads, each one picking the next unsearched move
+pub fn perft_parallel(
+    board: &ChessBoard,
+    depth: Option<u8>,
+    threads: usize,
+    table: Option<&PerftTable>,
+) -> (u128, Duration) {
+    let timer = Instant::now();
+    let depth = depth.unwrap_or(DEFAULT_PERFT_DEPTH);
+
+    let result = if depth <= 1 || board.variant_result().is_some() {
+        let mask = board.castle_rights().get_castle_mask();
+        let variant_results = board.variant().has_variant_results();
+        perft_internal(board, depth, &mask, table, variant_results)
+    } else {
+        perft_divide(board, depth, threads, table)
+            .iter()
+            .map(|&(_, count)| count)
+            .sum()
+    };
+
+    (result, timer.elapsed())
+}
+
+//Node count below every root move, in move generation order
+pub fn perft_divide(
+    board: &ChessBoard,
+    depth: u8,
+    threads: usize,
+    table: Option<&PerftTable>,
+) -> Vec<(Move, u128)> {
+    if depth == 0 || board.variant_result().is_some() {
+        return Vec::new();
+    }
+
+    let moves = board.legal_moves();
+    let mask = board.castle_rights().get_castle_mask();
+    let variant_results = board.variant().has_variant_results();
+    let counts = (0..moves.len())
+        .map(|_| AtomicU64::new(0))
+        .collect::<Vec<_>>();
+    let next_move = AtomicUsize::new(0);
+
+    thread::scope(|s| {
+        for _ in 0..threads.clamp(1, moves.len().max(1)) {
+            s.spawn(|| loop {
+                let index = next_move.fetch_add(1, Ordering::Relaxed);
+                if index >= moves.len() {
+                    break;
+                }
+
+                let mut board_copy = *board;
+                board_copy.make_move(moves[index], &mask);
+                let count = perft_internal(&board_copy, depth - 1, &mask, table, variant_results);
+                counts[index].store(count as u64, Ordering::Relaxed);
+            });
+        }
+    });
+
+    moves
+        .iter()
+        .zip(counts)
+        .map(|(&mv, count)| (mv, u128::from(count.into_inner())))
+        .collect()
+}
+
+fn perft_internal(
+    board: &ChessBoard,
+    depth: u8,
+    mask: &[u8; 64],
+    table: Option<&PerftTable>,
+    variant_results: bool,
+) -> u128 {
+    if depth == 0 {
+        return 1;
+    }
+
+    if variant_results && board.variant_result().is_some() {
+        return 0;
+    }
+
+    if depth == 1 {
+        return if board.side() == Side::WHITE {
+            board.count_legal_moves::<0>()
+        } else {
+            board.count_legal_moves::<1>()
+        } as u128;
+    }
+
+    let key = u64::from(board.hash());
+    if let Some(count) = table.and_then(|table| table.probe(key, depth)) {
+        return count;
+    }
+
+    let mut node_count = 0u128;
+    board.map_legal_moves(|mv| {
+        let mut board_copy = *board;
+        board_copy.make_move(mv, mask);
+        node_count += perft_internal(&board_copy, depth - 1, mask, table, variant_results);
+    });
+
+    if let Some(table) = table {
+        table.store(key, depth, node_count);
+    }
+
+    node_count
+}
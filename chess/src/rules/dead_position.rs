@@ -0,0 +1,79 @@
+use crate::{Attacks, Bitboard, ChessBoard, Piece, Side, Variant};
+
+impl ChessBoard {
+    pub fn is_dead_position(&self) -> bool {
+        //Both patterns need a board without rooks and queens, a single mask test that rules out
+        //almost every position reached in search
+        if (self.piece_mask(Piece::ROOK) | self.piece_mask(Piece::QUEEN)).is_not_empty() {
+            return false;
+        }
+
+        let kings_cannot_win = match self.variant() {
+            Variant::Standard | Variant::ThreeCheck => true,
+            Variant::KingOfTheHill | Variant::Horde | Variant::Crazyhouse => false,
+        };
+
+        self.is_insufficient_material() || (kings_cannot_win && self.is_blocked_pawn_structure())
+    }
+
+    //Only kings and fully rammed pawns are left and neither king can ever reach an enemy pawn
+    fn is_blocked_pawn_structure(&self) -> bool {
+        let pawns = self.piece_mask(Piece::PAWN);
+        if pawns.is_empty() || (pawns | self.piece_mask(Piece::KING)) != self.occupancy() {
+            return false;
+        }
+
+        if self.is_in_check() {
+            return false;
+        }
+
+        let white_pawns = self.piece_mask_for_side(Piece::PAWN, Side::WHITE);
+        let black_pawns = self.piece_mask_for_side(Piece::PAWN, Side::BLACK);
+
+        if (white_pawns << 8) & !pawns != Bitboard::EMPTY
+            || (black_pawns >> 8) & !pawns != Bitboard::EMPTY
+        {
+            return false;
+        }
+
+        let white_attacks = pawn_attacks(white_pawns, Side::WHITE);
+        let black_attacks = pawn_attacks(black_pawns, Side::BLACK);
+
+        if (white_attacks & black_pawns).is_not_empty()
+            || (black_attacks & white_pawns).is_not_empty()
+        {
+            return false;
+        }
+
+        !self.can_king_reach(Side::WHITE, black_pawns, black_attacks)
+            && !self.can_king_reach(Side::BLACK, white_pawns, white_attacks)
+    }
+
+    fn can_king_reach(&self, side: Side, targets: Bitboard, enemy_attacks: Bitboard) -> bool {
+        let walkable = !self.piece_mask(Piece::PAWN) & !enemy_attacks;
+        let reachable_targets = targets & !enemy_attacks;
+
+        let mut region = Bitboard::from(self.king_square(side));
+        loop {
+            let mut next_region = region;
+            region.map(|square| next_region |= Attacks::get_king_attacks(square));
+
+            if (next_region & reachable_targets).is_not_empty() {
+                return true;
+            }
+
+            next_region &= walkable;
+            if next_region == region {
+                return false;
+            }
+
+            region = next_region;
+        }
+    }
+}
+
+fn pawn_attacks(pawns: Bitboard, side: Side) -> Bitboard {
+    let mut result = Bitboard::EMPTY;
+    pawns.map(|square| result |= Attacks::get_pawn_attacks(square, side));
+    result
+}
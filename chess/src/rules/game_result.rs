@@ -0,0 +1,192 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{ChessBoard, ChessPosition, Game, Side};
+
+pub const FIFTY_MOVE_RULE_PLIES: u8 = 100;
+pub const SEVENTY_FIVE_MOVE_RULE_PLIES: u8 = 150;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WinReason {
+    Checkmate,
+    KingOfTheHill,
+    ThreeChecks,
+    HordeCaptured,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DrawReason {
+    Stalemate,
+    ThreefoldRepetition,
+    FivefoldRepetition,
+    FiftyMoveRule,
+    SeventyFiveMoveRule,
+    InsufficientMaterial,
+    DeadPosition,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameResult {
+    Win(Side, WinReason),
+    Draw(DrawReason),
+}
+
+impl GameResult {
+    pub fn winner(&self) -> Option<Side> {
+        match self {
+            GameResult::Win(side, _) => Some(*side),
+            GameResult::Draw(_) => None,
+        }
+    }
+
+    pub fn white_score(&self) -> f32 {
+        match self {
+            GameResult::Win(side, _) if *side == Side::WHITE => 1.0,
+            GameResult::Win(_, _) => 0.0,
+            GameResult::Draw(_) => 0.5,
+        }
+    }
+}
+
+impl Display for WinReason {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        match self {
+            WinReason::Checkmate => write!(formatter, "checkmate"),
+            WinReason::KingOfTheHill => write!(formatter, "king of the hill"),
+            WinReason::ThreeChecks => write!(formatter, "three checks"),
+            WinReason::HordeCaptured => write!(formatter, "horde captured"),
+        }
+    }
+}
+
+impl Display for DrawReason {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        let reason = match self {
+            DrawReason::Stalemate => "stalemate",
+            DrawReason::ThreefoldRepetition => "threefold repetition",
+            DrawReason::FivefoldRepetition => "fivefold repetition",
+            DrawReason::FiftyMoveRule => "fifty-move rule",
+            DrawReason::SeventyFiveMoveRule => "seventy-five-move rule",
+            DrawReason::InsufficientMaterial => "insufficient material",
+            DrawReason::DeadPosition => "dead position",
+        };
+
+        write!(formatter, "{reason}")
+    }
+}
+
+impl Display for GameResult {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        match self {
+            GameResult::Win(side, reason) if *side == Side::WHITE => {
+                write!(formatter, "1-0 ({reason})")
+            }
+            GameResult::Win(_, reason) => write!(formatter, "0-1 ({reason})"),
+            GameResult::Draw(reason) => write!(formatter, "1/2-1/2 ({reason})"),
+        }
+    }
+}
+
+impl ChessBoard {
+    #[inline]
+    pub fn is_fifty_move_rule(&self) -> bool {
+        self.half_moves() >= FIFTY_MOVE_RULE_PLIES
+    }
+
+    #[inline]
+    pub fn is_seventy_five_move_rule(&self) -> bool {
+        self.half_moves() >= SEVENTY_FIVE_MOVE_RULE_PLIES
+    }
+}
+
+impl Game {
+    pub fn result(&self) -> Option<GameResult> {
+        game_result(self.board(), self.repetitions(), false)
+    }
+
+    pub fn result_with_claims(&self) -> Option<GameResult> {
+        game_result(self.board(), self.repetitions(), true)
+    }
+
+    pub fn claimable_draw(&self) -> Option<DrawReason> {
+        claimable_draw(self.board(), self.repetitions())
+    }
+
+    pub fn automatic_draw(&self) -> Option<DrawReason> {
+        automatic_draw(self.board(), self.repetitions())
+    }
+}
+
+impl ChessPosition {
+    #[inline]
+    pub fn repetitions(&self) -> usize {
+        self.history().get_repetitions(self.board().hash()) as usize
+    }
+
+    pub fn result(&self) -> Option<GameResult> {
+        game_result(self.board(), self.repetitions(), false)
+    }
+
+    pub fn result_with_claims(&self) -> Option<GameResult> {
+        game_result(self.board(), self.repetitions(), true)
+    }
+
+    pub fn claimable_draw(&self) -> Option<DrawReason> {
+        claimable_draw(self.board(), self.repetitions())
+    }
+
+    pub fn automatic_draw(&self) -> Option<DrawReason> {
+        automatic_draw(self.board(), self.repetitions())
+    }
+}
+
+fn game_result(board: &ChessBoard, repetitions: usize, claim_draws: bool) -> Option<GameResult> {
+    if let Some(result) = board.variant_result() {
+        return Some(result);
+    }
+
+    //Checkmate takes precedence over draws triggered by the same move
+    let mut has_moves = false;
+    board.map_legal_moves(|_| has_moves = true);
+
+    if !has_moves {
+        return Some(if board.is_in_check() {
+            GameResult::Win(board.side().flipped(), WinReason::Checkmate)
+        } else {
+            GameResult::Draw(DrawReason::Stalemate)
+        });
+    }
+
+    if let Some(reason) = automatic_draw(board, repetitions) {
+        return Some(GameResult::Draw(reason));
+    }
+
+    if claim_draws {
+        return claimable_draw(board, repetitions).map(GameResult::Draw);
+    }
+
+    None
+}
+
+fn claimable_draw(board: &ChessBoard, repetitions: usize) -> Option<DrawReason> {
+    if repetitions >= 3 {
+        Some(DrawReason::ThreefoldRepetition)
+    } else if board.is_fifty_move_rule() {
+        Some(DrawReason::FiftyMoveRule)
+    } else {
+        None
+    }
+}
+
+fn automatic_draw(board: &ChessBoard, repetitions: usize) -> Option<DrawReason> {
+    if repetitions >= 5 {
+        Some(DrawReason::FivefoldRepetition)
+    } else if board.is_seventy_five_move_rule() {
+        Some(DrawReason::SeventyFiveMoveRule)
+    } else if board.is_insufficient_material() {
+        Some(DrawReason::InsufficientMaterial)
+    } else if board.is_dead_position() {
+        Some(DrawReason::DeadPosition)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,9 @@
+mod dead_position;
+mod game_result;
+mod variant_result;
+
+pub use game_result::DrawReason;
+pub use game_result::GameResult;
+pub use game_result::WinReason;
+pub use game_result::FIFTY_MOVE_RULE_PLIES;
+pub use game_result::SEVENTY_FIVE_MOVE_RULE_PLIES;
@@ -0,0 +1,27 @@
+use crate::{Bitboard, ChessBoard, GameResult, Piece, Side, Variant, WinReason};
+
+impl ChessBoard {
+    //Game end conditions that apply on top of the standard rules, checked before move generation
+    pub fn variant_result(&self) -> Option<GameResult> {
+        match self.variant() {
+            Variant::Standard | Variant::Crazyhouse => None,
+            Variant::KingOfTheHill => [Side::WHITE, Side::BLACK]
+                .into_iter()
+                .find(|&side| {
+                    (self.piece_mask_for_side(Piece::KING, side) & Bitboard::CENTER).is_not_empty()
+                })
+                .map(|side| GameResult::Win(side, WinReason::KingOfTheHill)),
+            Variant::ThreeCheck => [Side::WHITE, Side::BLACK]
+                .into_iter()
+                .find(|&side| self.checks_given(side) >= 3)
+                .map(|side| GameResult::Win(side, WinReason::ThreeChecks)),
+            Variant::Horde => {
+                if self.occupancy_for_side(Side::WHITE).is_empty() {
+                    Some(GameResult::Win(Side::BLACK, WinReason::HordeCaptured))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,306 @@
+use std::fmt::{Formatter, Result as FmtResult};
+
+use serde::{
+    de::{Error, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{Bitboard, ChessBoard, Move, MoveFlag, Piece, Side, Square, Variant, FEN};
+
+//Human readable formats get the text forms, binary ones the raw values
+struct TextVisitor<T> {
+    expecting: &'static str,
+    parse: fn(&str) -> Option<T>,
+}
+
+impl<T> Visitor<'_> for TextVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str(self.expecting)
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+        (self.parse)(value).ok_or_else(|| E::custom(format!("invalid {}: {value}", self.expecting)))
+    }
+}
+
+fn deserialize_text<'de, D: Deserializer<'de>, T>(
+    deserializer: D,
+    expecting: &'static str,
+    parse: fn(&str) -> Option<T>,
+) -> Result<T, D::Error> {
+    deserializer.deserialize_str(TextVisitor { expecting, parse })
+}
+
+fn parse_square(text: &str) -> Option<Square> {
+    if text == "NULL" {
+        return Some(Square::NULL);
+    }
+
+    match text.as_bytes() {
+        &[file @ b'a'..=b'h', rank @ b'1'..=b'8'] => {
+            Some(Square::from_coords(rank - b'1', file - b'a'))
+        }
+        _ => None,
+    }
+}
+
+fn parse_piece(text: &str) -> Option<Piece> {
+    let mut chars = text.chars();
+    let piece = match chars.next()?.to_ascii_lowercase() {
+        'p' => Piece::PAWN,
+        'n' => Piece::KNIGHT,
+        'b' => Piece::BISHOP,
+        'r' => Piece::ROOK,
+        'q' => Piece::QUEEN,
+        'k' => Piece::KING,
+        _ => return None,
+    };
+
+    chars.next().is_none().then_some(piece)
+}
+
+fn parse_side(text: &str) -> Option<Side> {
+    match text.to_ascii_lowercase().as_str() {
+        "white" | "w" => Some(Side::WHITE),
+        "black" | "b" => Some(Side::BLACK),
+        _ => None,
+    }
+}
+
+fn parse_fen(text: &str) -> Option<FEN> {
+    FEN::parse(text).ok()
+}
+
+fn parse_variant(text: &str) -> Option<Variant> {
+    Variant::parse(text)
+}
+
+impl Serialize for Square {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&String::from(*self))
+        } else {
+            serializer.serialize_u8(u8::from(*self))
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Square {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserialize_text(deserializer, "square", parse_square)
+        } else {
+            let value = u8::deserialize(deserializer)?;
+            if value > 64 {
+                return Err(D::Error::custom(format!("invalid square index: {value}")));
+            }
+
+            Ok(Square::from(value))
+        }
+    }
+}
+
+impl Serialize for Piece {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_string())
+        } else {
+            serializer.serialize_u8(u8::from(*self))
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Piece {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserialize_text(deserializer, "piece", parse_piece)
+        } else {
+            let value = u8::deserialize(deserializer)?;
+            if value > u8::from(Piece::KING) {
+                return Err(D::Error::custom(format!("invalid piece index: {value}")));
+            }
+
+            Ok(Piece::from(value))
+        }
+    }
+}
+
+impl Serialize for Side {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(if *self == Side::WHITE {
+                "white"
+            } else {
+                "black"
+            })
+        } else {
+            serializer.serialize_u8(u8::from(*self))
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Side {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserialize_text(deserializer, "side", parse_side)
+        } else {
+            match u8::deserialize(deserializer)? {
+                value @ 0..=1 => Ok(Side::from(value)),
+                value => Err(D::Error::custom(format!("invalid side index: {value}"))),
+            }
+        }
+    }
+}
+
+impl Serialize for Bitboard {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.get_value())
+    }
+}
+
+impl<'de> Deserialize<'de> for Bitboard {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Bitboard::from(u64::deserialize(deserializer)?))
+    }
+}
+
+const MOVE_FLAGS: [(u16, &str); 15] = [
+    (MoveFlag::QUIET_MOVE, "quiet"),
+    (MoveFlag::DOUBLE_PUSH, "double_push"),
+    (MoveFlag::KING_SIDE_CASTLE, "king_side_castle"),
+    (MoveFlag::QUEEN_SIDE_CASTLE, "queen_side_castle"),
+    (MoveFlag::CAPTURE, "capture"),
+    (MoveFlag::EN_PASSANT, "en_passant"),
+    (MoveFlag::DROP, "drop"),
+    (MoveFlag::KNIGHT_PROMOTION, "knight_promotion"),
+    (MoveFlag::BISHOP_PROMOTION, "bishop_promotion"),
+    (MoveFlag::ROOK_PROMOTION, "rook_promotion"),
+    (MoveFlag::QUEEN_PROMOTION, "queen_promotion"),
+    (
+        MoveFlag::KNIGHT_PROMOTION_CAPTURE,
+        "knight_promotion_capture",
+    ),
+    (
+        MoveFlag::BISHOP_PROMOTION_CAPTURE,
+        "bishop_promotion_capture",
+    ),
+    (MoveFlag::ROOK_PROMOTION_CAPTURE, "rook_promotion_capture"),
+    (MoveFlag::QUEEN_PROMOTION_CAPTURE, "queen_promotion_capture"),
+];
+
+//UCI text does not tell captures, castling or en passant apart from quiet moves, so the
+//flag is stored next to it. Castling uses the king takes rook form, which matches the encoding
+#[derive(Serialize, Deserialize)]
+struct MoveText {
+    uci: String,
+    flag: String,
+}
+
+fn parse_move(text: &MoveText) -> Option<Move> {
+    let flag = MOVE_FLAGS
+        .iter()
+        .find(|(_, name)| *name == text.flag)
+        .map(|&(flag, _)| flag)?;
+
+    if text.uci == "0000" {
+        return (flag == MoveFlag::QUIET_MOVE).then_some(Move::NULL);
+    }
+
+    let mv = if flag == MoveFlag::DROP {
+        let (piece, square) = text.uci.split_once('@')?;
+        Move::from_drop(parse_piece(piece)?, parse_square(square)?)
+    } else {
+        let from_square = parse_square(text.uci.get(0..2)?)?;
+        let to_square = parse_square(text.uci.get(2..4)?)?;
+        Move::from_squares(from_square, to_square, flag)
+    };
+
+    //Catches promotion suffixes that do not match the flag
+    (mv.to_string(true) == text.uci).then_some(mv)
+}
+
+impl Serialize for Move {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            return serializer.serialize_u16(u16::from(*self));
+        }
+
+        let uci = if *self == Move::NULL {
+            String::from("0000")
+        } else {
+            self.to_string(true)
+        };
+        let flag = MOVE_FLAGS
+            .iter()
+            .find(|&&(flag, _)| flag == self.flag())
+            .map_or("", |&(_, name)| name);
+
+        MoveText {
+            uci,
+            flag: String::from(flag),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Move {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if !deserializer.is_human_readable() {
+            return Ok(Move::from(u16::deserialize(deserializer)?));
+        }
+
+        let text = MoveText::deserialize(deserializer)?;
+        parse_move(&text)
+            .ok_or_else(|| D::Error::custom(format!("invalid move: {} ({})", text.uci, text.flag)))
+    }
+}
+
+impl Serialize for Variant {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Variant {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_text(deserializer, "variant", parse_variant)
+    }
+}
+
+impl Serialize for FEN {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FEN {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_text(deserializer, "fen", parse_fen)
+    }
+}
+
+//FEN does not tell every variant apart, so the variant is stored next to it
+#[derive(Serialize, Deserialize)]
+struct BoardText {
+    fen: FEN,
+    variant: Variant,
+}
+
+impl Serialize for ChessBoard {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        BoardText {
+            fen: FEN::from(self),
+            variant: self.variant(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ChessBoard {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = BoardText::deserialize(deserializer)?;
+        ChessBoard::from_fen_with_variant(&text.fen, text.variant).map_err(D::Error::custom)
+    }
+}
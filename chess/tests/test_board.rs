@@ -1,8 +1,10 @@
-use chess::{Bitboard, ChessBoard, Move, MoveFlag, Piece, Side, Square, FEN};
+use chess::{
+    Bitboard, BoardError, ChessBoard, FenError, Game, Move, MoveFlag, Piece, Side, Square, FEN,
+};
 
 #[test]
 fn from_fen() {
-    let board = ChessBoard::from(&FEN::start_position());
+    let board = ChessBoard::try_from(&FEN::start_position()).unwrap();
 
     assert_eq!(board.king_square(Side::WHITE), Square::E1);
     assert_eq!(
@@ -15,51 +17,52 @@ fn from_fen() {
 
     assert_eq!(FEN::from(&board), FEN::start_position());
 
-    let board = ChessBoard::from(&FEN::kiwipete_position());
+    let board = ChessBoard::try_from(&FEN::kiwipete_position()).unwrap();
     assert_eq!(FEN::from(&board), FEN::kiwipete_position());
 
     let fen = FEN::from("rrkrrrrr/pp3ppp/3pp3/2p5/5P2/P2P4/1PP1P1PP/RRRRKRRR w FBda - 2 1");
-    assert_eq!(FEN::from(&ChessBoard::from(&fen)), fen);
+    assert_eq!(FEN::from(&ChessBoard::try_from(&fen).unwrap()), fen);
 
     let fen = FEN::from("brkr2rr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQRBKR1R w HChb - 2 1");
-    assert_eq!(FEN::from(&ChessBoard::from(&fen)), fen);
+    assert_eq!(FEN::from(&ChessBoard::try_from(&fen).unwrap()), fen);
 
     let fen = FEN::from("brnr1krr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BRRKR w HEgb - 2 1");
-    assert_eq!(FEN::from(&ChessBoard::from(&fen)), fen);
+    assert_eq!(FEN::from(&ChessBoard::try_from(&fen).unwrap()), fen);
 }
 
 #[test]
 fn insufficient_material() {
-    let board = ChessBoard::from(&FEN::from("2k5/8/8/8/8/1B6/3K4/8 w - - 0 1"));
+    let board = ChessBoard::try_from(&FEN::from("2k5/8/8/8/8/1B6/3K4/8 w - - 0 1")).unwrap();
     assert!(board.is_insufficient_material());
 
-    let board = ChessBoard::from(&FEN::from("2k5/8/8/8/8/1B3B2/3K4/8 w - - 0 1"));
+    let board = ChessBoard::try_from(&FEN::from("2k5/8/8/8/8/1B3B2/3K4/8 w - - 0 1")).unwrap();
     assert!(board.is_insufficient_material());
 
-    let board = ChessBoard::from(&FEN::from("2k5/8/8/8/8/1B2B3/3K4/8 w - - 0 1"));
+    let board = ChessBoard::try_from(&FEN::from("2k5/8/8/8/8/1B2B3/3K4/8 w - - 0 1")).unwrap();
     assert!(!board.is_insufficient_material());
 
-    let board = ChessBoard::from(&FEN::from("2k5/8/8/8/8/1B2N3/3K4/8 w - - 0 1"));
+    let board = ChessBoard::try_from(&FEN::from("2k5/8/8/8/8/1B2N3/3K4/8 w - - 0 1")).unwrap();
     assert!(!board.is_insufficient_material());
 
-    let board = ChessBoard::from(&FEN::from("2k5/8/8/8/8/1N2N3/3K4/8 w - - 0 1"));
+    let board = ChessBoard::try_from(&FEN::from("2k5/8/8/8/8/1N2N3/3K4/8 w - - 0 1")).unwrap();
     assert!(!board.is_insufficient_material());
 
-    let board = ChessBoard::from(&FEN::from("2k5/8/8/8/8/1N6/3K4/8 w - - 0 1"));
+    let board = ChessBoard::try_from(&FEN::from("2k5/8/8/8/8/1N6/3K4/8 w - - 0 1")).unwrap();
     assert!(board.is_insufficient_material());
 
-    let board = ChessBoard::from(&FEN::from("2k5/4b3/8/8/8/1B6/3K4/8 w - - 0 1"));
+    let board = ChessBoard::try_from(&FEN::from("2k5/4b3/8/8/8/1B6/3K4/8 w - - 0 1")).unwrap();
     assert!(!board.is_insufficient_material());
 
-    let board = ChessBoard::from(&FEN::from("2k5/3b4/8/8/8/1B6/3K4/8 w - - 0 1"));
+    let board = ChessBoard::try_from(&FEN::from("2k5/3b4/8/8/8/1B6/3K4/8 w - - 0 1")).unwrap();
     assert!(board.is_insufficient_material());
 }
 
 #[test]
 fn make_move() {
-    let mut board = ChessBoard::from(&FEN::from(
+    let mut board = ChessBoard::try_from(&FEN::from(
         "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w HAha - 0 1",
-    ));
+    ))
+    .unwrap();
     board.make_move_no_mask(Move::from_squares(
         Square::E2,
         Square::E4,
@@ -71,9 +74,10 @@ fn make_move() {
         FEN::from("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b HAha e3 0 1")
     );
 
-    let mut board = ChessBoard::from(&FEN::from(
+    let mut board = ChessBoard::try_from(&FEN::from(
         "5rk1/P4pp1/7p/2pP4/8/8/4PPPP/2RR1K1R w HD c6 0 1",
-    ));
+    ))
+    .unwrap();
     board.make_move_no_mask(Move::from_squares(
         Square::D5,
         Square::C6,
@@ -84,9 +88,10 @@ fn make_move() {
         FEN::from("5rk1/P4pp1/2P4p/8/8/8/4PPPP/2RR1K1R b HD - 0 1")
     );
 
-    let mut board = ChessBoard::from(&FEN::from(
+    let mut board = ChessBoard::try_from(&FEN::from(
         "5rk1/P4pp1/7p/2pP4/8/8/4PPPP/2RR1K1R w HD c6 0 1",
-    ));
+    ))
+    .unwrap();
     board.make_move_no_mask(Move::from_squares(
         Square::F1,
         Square::H1,
@@ -97,7 +102,8 @@ fn make_move() {
         FEN::from("5rk1/P4pp1/7p/2pP4/8/8/4PPPP/2RR1RK1 b - - 1 1")
     );
 
-    let mut board = ChessBoard::from(&FEN::from("5rk1/P4pp1/7p/2pP4/8/8/4PPPP/2RR1RK1 w - - 6 1"));
+    let mut board =
+        ChessBoard::try_from(&FEN::from("5rk1/P4pp1/7p/2pP4/8/8/4PPPP/2RR1RK1 w - - 6 1")).unwrap();
     board.make_move_no_mask(Move::from_squares(
         Square::A7,
         Square::A8,
@@ -108,7 +114,8 @@ fn make_move() {
         FEN::from("R4rk1/5pp1/7p/2pP4/8/8/4PPPP/2RR1RK1 b - - 0 1")
     );
 
-    let mut board = ChessBoard::from(&FEN::from("5rk1/P4pp1/7p/2pP4/8/8/4PPPP/2RR1RK1 w - - 6 1"));
+    let mut board =
+        ChessBoard::try_from(&FEN::from("5rk1/P4pp1/7p/2pP4/8/8/4PPPP/2RR1RK1 w - - 6 1")).unwrap();
     board.make_move_no_mask(Move::from_squares(
         Square::C1,
         Square::A1,
@@ -119,9 +126,10 @@ fn make_move() {
         FEN::from("5rk1/P4pp1/7p/2pP4/8/8/4PPPP/R2R1RK1 b - - 7 1")
     );
 
-    let mut board = ChessBoard::from(&FEN::from(
+    let mut board = ChessBoard::try_from(&FEN::from(
         "5rk1/P4pp1/7p/3P4/2p5/8/4PPPP/R2R1K1R w HD - 0 2",
-    ));
+    ))
+    .unwrap();
     board.make_move_no_mask(Move::from_squares(
         Square::F1,
         Square::D1,
@@ -135,15 +143,18 @@ fn make_move() {
 
 #[test]
 fn checkers_mask() {
-    let board = ChessBoard::from(&FEN::from(
+    let board = ChessBoard::from_fen_unchecked(&FEN::from(
         "k7/3r4/6b1/1b6/1p2P3/3KN1r1/2P5/1b3q2 w - - 0 1",
-    ));
+    ))
+    .unwrap();
     assert_eq!(
         board.generate_checkers_mask(board.side()),
         Bitboard::from(2251808403619872)
     );
 
-    let board = ChessBoard::from(&FEN::from("k7/3r4/6b1/8/2p1P3/3KN1r1/2P5/1b3q2 w - - 0 1"));
+    let board =
+        ChessBoard::from_fen_unchecked(&FEN::from("k7/3r4/6b1/8/2p1P3/3KN1r1/2P5/1b3q2 w - - 0 1"))
+            .unwrap();
     assert_eq!(
         board.generate_checkers_mask(board.side()),
         Bitboard::from(2251799880794144)
@@ -152,16 +163,18 @@ fn checkers_mask() {
 
 #[test]
 fn pin_mask() {
-    let board = ChessBoard::from(&FEN::from(
+    let board = ChessBoard::from_fen_unchecked(&FEN::from(
         "k7/3r4/6b1/1b6/1p2P3/3KN1r1/2P5/1b3q2 w - - 0 1",
-    ));
+    ))
+    .unwrap();
     let (diag, ortho) = board.generate_pin_masks(board.side());
     assert_eq!(diag, Bitboard::from(70506451567618));
     assert_eq!(ortho, Bitboard::from(7340032));
 
-    let board = ChessBoard::from(&FEN::from(
+    let board = ChessBoard::from_fen_unchecked(&FEN::from(
         "k7/3r4/6b1/1b6/2p1P3/3KN1r1/2P5/1b3q2 w - - 0 1",
-    ));
+    ))
+    .unwrap();
     let (diag, ortho) = board.generate_pin_masks(board.side());
     assert_eq!(diag, Bitboard::from(70506451567618));
     assert_eq!(ortho, Bitboard::from(7340032));
@@ -169,15 +182,126 @@ fn pin_mask() {
 
 #[test]
 fn attack_mask() {
-    let board = ChessBoard::from(&FEN::from("k7/3r4/6b1/1b6/1p2P3/3K2r1/2P5/1b3q2 w - - 0 1"));
+    let board = ChessBoard::from_fen_unchecked(&FEN::from(
+        "k7/3r4/6b1/1b6/1p2P3/3K2r1/2P5/1b3q2 w - - 0 1",
+    ))
+    .unwrap();
     let attack_mask = board.generate_attack_map(Side::WHITE);
     assert_eq!(attack_mask, Bitboard::from(172270427136));
     let attack_mask = board.generate_attack_map(Side::BLACK);
     assert_eq!(attack_mask, Bitboard::from(4251237427160514046));
 
-    let board = ChessBoard::from(&FEN::from("k7/3r4/6b1/1b6/2p1P3/3K2r1/2P5/1b3q2 w - - 0 1"));
+    let board = ChessBoard::from_fen_unchecked(&FEN::from(
+        "k7/3r4/6b1/1b6/2p1P3/3K2r1/2P5/1b3q2 w - - 0 1",
+    ))
+    .unwrap();
     let attack_mask = board.generate_attack_map(Side::WHITE);
     assert_eq!(attack_mask, Bitboard::from(172270427136));
     let attack_mask = board.generate_attack_map(Side::BLACK);
     assert_eq!(attack_mask, Bitboard::from(4251237418570579422));
 }
+
+#[test]
+fn try_from_fen() {
+    let board = ChessBoard::from_fen_checked(&FEN::kiwipete_position());
+    assert_eq!(
+        board,
+        Ok(ChessBoard::try_from(&FEN::kiwipete_position()).unwrap())
+    );
+
+    let board = ChessBoard::from_fen_checked(&FEN::from(
+        "rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 3",
+    ));
+    assert_eq!(board.map(|board| board.en_passant_square()), Ok(Square::E3));
+
+    let board = ChessBoard::from_fen_checked(&FEN::from(
+        "brnr1krr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BRRKR w HEgb - 2 9",
+    ));
+    assert!(board.is_ok());
+
+    let board = ChessBoard::try_from(
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+    );
+    assert_eq!(
+        board,
+        Ok(ChessBoard::try_from(&FEN::kiwipete_position()).unwrap())
+    );
+
+    let board = ChessBoard::try_from("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNX w KQkq - 0 1");
+    assert_eq!(
+        board,
+        Err(BoardError::InvalidFen(FenError::InvalidPiece('X')))
+    );
+
+    let board = ChessBoard::try_from("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQ1BNR w KQkq - 0 1");
+    assert_eq!(board, Err(BoardError::InvalidKingCount(Side::WHITE, 0)));
+
+    let fen = FEN::from("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQ1BNR w KQkq - 0 1");
+    assert_eq!(
+        ChessBoard::try_from(&fen),
+        Err(BoardError::InvalidKingCount(Side::WHITE, 0))
+    );
+    assert_eq!(
+        Game::try_from(&fen),
+        Err(BoardError::InvalidKingCount(Side::WHITE, 0))
+    );
+}
+
+#[test]
+fn try_from_fen_errors() {
+    let cases = [
+        (
+            "rnbq1bnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQ - 0 1",
+            BoardError::InvalidKingCount(Side::BLACK, 0),
+        ),
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBKKBNR w - - 0 1",
+            BoardError::InvalidKingCount(Side::WHITE, 2),
+        ),
+        (
+            "rnbqkbnr/pppppppp/8/8/8/QQ6/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            BoardError::TooManyPieces(Side::WHITE, 18),
+        ),
+        (
+            "rnbqkbnr/pppppppp/8/8/8/P7/PPPPPPPP/RNBQKBN1 w Qkq - 0 1",
+            BoardError::TooManyPawns(Side::WHITE, 9),
+        ),
+        (
+            "rnbqkbnP/pppppppp/8/8/8/8/PPPPPPP1/RNBQKBNR w KQq - 0 1",
+            BoardError::PawnOnBackRank(Square::H8),
+        ),
+        (
+            "4k3/8/8/8/8/8/8/p3K3 w - - 0 1",
+            BoardError::PawnOnBackRank(Square::A1),
+        ),
+        (
+            "4k2R/8/8/8/8/8/8/4K3 w - - 0 1",
+            BoardError::OpponentInCheck,
+        ),
+        (
+            "4k3/8/8/8/8/4r3/8/3qKr2 w - - 0 1",
+            BoardError::TooManyCheckers(3),
+        ),
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBN1 w HAha - 0 1",
+            BoardError::InvalidCastleRights('H'),
+        ),
+        (
+            "rnbqkbnr/pppppppp/8/8/8/4K3/PPPPPPPP/RNBQ1BNR w HAha - 0 1",
+            BoardError::InvalidCastleRights('H'),
+        ),
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq e6 0 1",
+            BoardError::InvalidEnPassant(Square::E6),
+        ),
+        (
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e3 0 1",
+            BoardError::InvalidEnPassant(Square::E3),
+        ),
+    ];
+
+    for (fen, error) in cases {
+        let fen = FEN::parse(fen).unwrap();
+        assert_eq!(ChessBoard::from_fen_checked(&fen), Err(error), "{fen}");
+    }
+}
@@ -0,0 +1,101 @@
+use chess::{perft, CastleNotation, ChessBoard, Piece, FEN};
+
+#[test]
+fn scharnagl_numbering() {
+    assert_eq!(FEN::chess960(518), FEN::start_position());
+    assert_eq!(
+        FEN::chess960(0).to_string(),
+        "bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR w HFhf - 0 1"
+    );
+    assert_eq!(
+        FEN::chess960(959).to_string(),
+        "rkrnnqbb/pppppppp/8/8/8/8/PPPPPPPP/RKRNNQBB w CAca - 0 1"
+    );
+}
+
+#[test]
+fn scharnagl_inverse() {
+    let mut back_ranks = Vec::new();
+    for index in 0..960 {
+        let fen = FEN::chess960(index);
+        assert_eq!(fen.chess960_index(), Some(index));
+        assert_eq!(fen.double_fischer_random_index(), Some((index, index)));
+
+        let board = ChessBoard::from_fen_checked(&fen).unwrap();
+        assert_eq!(FEN::from(&board), fen);
+        assert_eq!(
+            board
+                .legal_moves()
+                .iter()
+                .filter(|mv| board.piece_on_square(mv.from_square()) == Piece::PAWN)
+                .count(),
+            16
+        );
+
+        back_ranks.push(fen.to_string()[..8].to_string());
+    }
+
+    back_ranks.sort();
+    back_ranks.dedup();
+    assert_eq!(back_ranks.len(), 960);
+
+    assert_eq!(FEN::kiwipete_position().chess960_index(), None);
+}
+
+#[test]
+fn chess960_castle_rights() {
+    let board = ChessBoard::try_from(&FEN::start_position()).unwrap();
+    assert!(!board.castle_rights().is_chess960());
+
+    let board = ChessBoard::try_from(&FEN::kiwipete_position()).unwrap();
+    assert!(!board.castle_rights().is_chess960());
+
+    for index in [0, 959] {
+        let board = ChessBoard::try_from(&FEN::chess960(index)).unwrap();
+        assert!(board.castle_rights().is_chess960());
+    }
+
+    //Only the rights that are still held decide
+    let board = ChessBoard::try_from(&FEN::from("1k2r2r/8/8/8/8/8/8/RK2R2R w EAe - 0 1")).unwrap();
+    assert!(board.castle_rights().is_chess960());
+    let board = ChessBoard::try_from(&FEN::from("1k5r/8/8/8/8/8/8/4K2R w - - 0 1")).unwrap();
+    assert!(!board.castle_rights().is_chess960());
+    let board = ChessBoard::try_from(&FEN::double_fischer_random(518, 0)).unwrap();
+    assert!(board.castle_rights().is_chess960());
+}
+
+#[test]
+fn double_fischer_random() {
+    let fen = FEN::double_fischer_random(518, 0);
+    assert_eq!(
+        fen.to_string(),
+        "bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w HAhf - 0 1"
+    );
+    assert_eq!(fen.chess960_index(), None);
+    assert_eq!(fen.double_fischer_random_index(), Some((518, 0)));
+
+    let (result, _) = perft::<true, false, true>(&ChessBoard::try_from(&fen).unwrap(), Some(3));
+    assert_eq!(result, 8902);
+}
+
+#[test]
+fn castle_notation() {
+    let board = ChessBoard::try_from(&FEN::start_position()).unwrap();
+    assert_eq!(
+        FEN::from_board(&board, CastleNotation::XFen).to_string(),
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
+    );
+    assert_eq!(
+        FEN::from_board(&board, CastleNotation::Shredder).to_string(),
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w HAha - 0 1"
+    );
+
+    let fen = FEN::from("1k2r2r/8/8/8/8/8/8/RK2R2R w EAe - 0 1");
+    let board = ChessBoard::try_from(&fen).unwrap();
+    let xfen = FEN::from_board(&board, CastleNotation::XFen);
+    assert_eq!(xfen.to_string(), "1k2r2r/8/8/8/8/8/8/RK2R2R w EQe - 0 1");
+    assert_eq!(
+        ChessBoard::try_from(&FEN::from(xfen.to_string())).unwrap(),
+        board
+    );
+}
@@ -0,0 +1,178 @@
+use chess::{ChessBoard, Epd, EpdError, FenError, Move, MoveFlag, Square, FEN};
+
+#[test]
+fn parse_operations() {
+    let epd = Epd::parse(
+        "2rr3k/pp3pp1/1nnqbN1p/3pN3/2pP4/2P3Q1/PPB4P/R4RK1 w - - bm Qg6; id \"WAC.001\"; c0 \"mate in two\"; dm 2; ce 32767; acd 18;",
+    )
+    .unwrap();
+
+    assert_eq!(
+        epd.fen(),
+        &FEN::from("2rr3k/pp3pp1/1nnqbN1p/3pN3/2pP4/2P3Q1/PPB4P/R4RK1 w - - 0 1")
+    );
+    assert_eq!(epd.id(), Some("WAC.001"));
+    assert_eq!(epd.comment(0), Some("mate in two"));
+    assert_eq!(epd.comment(1), None);
+    assert_eq!(epd.direct_mate(), Some(2));
+    assert_eq!(epd.centipawn_evaluation(), Some(32767));
+    assert_eq!(epd.analysis_depth(), Some(18));
+    assert_eq!(
+        epd.best_moves(),
+        vec![Move::from_squares(
+            Square::G3,
+            Square::G6,
+            MoveFlag::QUIET_MOVE
+        )]
+    );
+    assert!(epd.avoid_moves().is_empty());
+}
+
+#[test]
+fn parse_clocks() {
+    let epd = Epd::parse("4k3/8/8/8/8/8/8/4K2R w K - 0 1 ;D1 15 ;D2 66").unwrap();
+    assert_eq!(epd.fen(), &FEN::from("4k3/8/8/8/8/8/8/4K2R w K - 0 1"));
+    assert_eq!(epd.operation("D2"), Some(&[String::from("66")][..]));
+
+    let epd = Epd::parse("4k3/8/8/8/8/8/8/4K2R b K - hmvc 12; fmvn 40;").unwrap();
+    assert_eq!(epd.fen(), &FEN::from("4k3/8/8/8/8/8/8/4K2R b K - 12 40"));
+}
+
+#[test]
+fn moves_in_san_and_uci() {
+    let epd = Epd::parse(
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - bm O-O Nxg6 e5f7; am d5e6 Bxa6;",
+    )
+    .unwrap();
+
+    assert_eq!(
+        epd.best_moves(),
+        vec![
+            Move::from_squares(Square::E1, Square::H1, MoveFlag::KING_SIDE_CASTLE),
+            Move::from_squares(Square::E5, Square::G6, MoveFlag::CAPTURE),
+            Move::from_squares(Square::E5, Square::F7, MoveFlag::CAPTURE),
+        ]
+    );
+    assert_eq!(
+        epd.avoid_moves(),
+        vec![
+            Move::from_squares(Square::D5, Square::E6, MoveFlag::CAPTURE),
+            Move::from_squares(Square::E2, Square::A6, MoveFlag::CAPTURE),
+        ]
+    );
+
+    let epd =
+        Epd::parse("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - pv e4 e7e5 Nf3 Nc6 Bb5;")
+            .unwrap();
+    let pv = epd
+        .pv()
+        .iter()
+        .map(|mv| mv.to_string(false))
+        .collect::<Vec<_>>();
+    assert_eq!(pv, vec!["e2e4", "e7e5", "g1f3", "b8c6", "f1b5"]);
+}
+
+#[test]
+fn san() {
+    let board = ChessBoard::try_from(&FEN::from(
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+    ))
+    .unwrap();
+    let san = |from, to, flag| board.to_san(Move::from_squares(from, to, flag));
+
+    assert_eq!(
+        san(Square::E1, Square::H1, MoveFlag::KING_SIDE_CASTLE),
+        "O-O"
+    );
+    assert_eq!(
+        san(Square::E1, Square::A1, MoveFlag::QUEEN_SIDE_CASTLE),
+        "O-O-O"
+    );
+    assert_eq!(san(Square::E5, Square::F7, MoveFlag::CAPTURE), "Nxf7");
+    assert_eq!(san(Square::D5, Square::E6, MoveFlag::CAPTURE), "dxe6");
+    assert_eq!(san(Square::C3, Square::B5, MoveFlag::QUIET_MOVE), "Nb5");
+    assert_eq!(san(Square::A1, Square::B1, MoveFlag::QUIET_MOVE), "Rb1");
+    assert_eq!(san(Square::F3, Square::F6, MoveFlag::CAPTURE), "Qxf6");
+
+    let board = ChessBoard::try_from(&FEN::from("4k3/8/8/8/R6R/8/8/4K3 w - - 0 1")).unwrap();
+    let mv = Move::from_squares(Square::A4, Square::D4, MoveFlag::QUIET_MOVE);
+    assert_eq!(board.to_san(mv), "Rad4");
+    assert_eq!(board.parse_move("Rad4"), Some(mv));
+    assert_eq!(board.parse_move("Rd4"), None);
+
+    let board = ChessBoard::try_from(&FEN::from("4k3/8/8/R7/8/8/8/R3K3 w - - 0 1")).unwrap();
+    let mv = Move::from_squares(Square::A1, Square::A3, MoveFlag::QUIET_MOVE);
+    assert_eq!(board.to_san(mv), "R1a3");
+    assert_eq!(board.parse_move("R1a3"), Some(mv));
+    assert_eq!(
+        board.to_san(Move::from_squares(
+            Square::A5,
+            Square::A8,
+            MoveFlag::QUIET_MOVE
+        )),
+        "Ra8+"
+    );
+
+    let board = ChessBoard::try_from(&FEN::from("6k1/5ppp/8/8/8/8/8/R3K3 w - - 0 1")).unwrap();
+    let mv = Move::from_squares(Square::A1, Square::A8, MoveFlag::QUIET_MOVE);
+    assert_eq!(board.to_san(mv), "Ra8#");
+    assert_eq!(board.parse_move("Ra8"), Some(mv));
+
+    let board = ChessBoard::try_from(&FEN::from("1n2k3/P7/8/8/8/8/8/4K3 w - - 0 1")).unwrap();
+    let mv = Move::from_squares(Square::A7, Square::B8, MoveFlag::QUEEN_PROMOTION_CAPTURE);
+    assert_eq!(board.to_san(mv), "axb8=Q+");
+    assert_eq!(board.parse_move("axb8Q"), Some(mv));
+    assert_eq!(board.parse_move("a7b8q"), Some(mv));
+}
+
+#[test]
+fn write() {
+    let text = "2rr3k/pp3pp1/1nnqbN1p/3pN3/2pP4/2P3Q1/PPB4P/R4RK1 w - - bm Qg6; id \"WAC.001\"; c0 \"mate in two\"; dm 2;";
+    let epd = Epd::parse(text).unwrap();
+    assert_eq!(epd.to_string(), text);
+    assert_eq!(Epd::parse(&epd.to_string()).unwrap(), epd);
+
+    let mut epd = Epd::new(FEN::from(
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 3 7",
+    ));
+    epd.set_best_moves(&[Move::from_squares(
+        Square::E5,
+        Square::F7,
+        MoveFlag::CAPTURE,
+    )])
+    .unwrap();
+    epd.set_operation("id", vec![String::from("kiwipete")]);
+    epd.set_operation("acd", vec![String::from("12")]);
+    epd.set_operation("acd", vec![String::from("14")]);
+    assert_eq!(
+        epd.to_string(),
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w HAha - hmvc 3; fmvn 7; bm Nxf7; id \"kiwipete\"; acd 14;"
+    );
+
+    epd.remove_operation("acd");
+    assert_eq!(epd.analysis_depth(), None);
+    assert_eq!(Epd::parse(&epd.to_string()).unwrap(), epd);
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(Epd::parse(""), Err(EpdError::MissingFields));
+    assert_eq!(
+        Epd::parse("4k3/8/8/8/8/8/8/4K3 w -"),
+        Err(EpdError::MissingFields)
+    );
+    assert_eq!(
+        Epd::parse("4k3/8/8/8/8/8/8/4K3 x - - bm e4;"),
+        Err(EpdError::Fen(FenError::InvalidSideToMove(String::from(
+            "x"
+        ))))
+    );
+    assert_eq!(
+        Epd::parse("4k3/8/8/8/8/8/8/4K3 w - - 1bm e4;"),
+        Err(EpdError::InvalidOpcode(String::from("1bm")))
+    );
+    assert_eq!(
+        Epd::parse("4k3/8/8/8/8/8/8/4K3 w - - id \"unterminated;"),
+        Err(EpdError::UnterminatedString(String::from("id")))
+    );
+}
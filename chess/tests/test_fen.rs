@@ -1,4 +1,4 @@
-use chess::FEN;
+use chess::{ChessBoard, FenError, FEN};
 
 #[test]
 fn haha() {
@@ -38,3 +38,131 @@ fn fbda() {
     let fen = FEN::from("rrkrrrrr/pp3ppp/3pp3/2p5/5P2/P2P4/1PP1P1PP/RRRRKRRR w FBdq - 2 9");
     assert_eq!(fen.castle_rights, "FBda");
 }
+
+#[test]
+fn parse_valid() {
+    assert_eq!(
+        FEN::parse("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"),
+        Ok(FEN::start_position())
+    );
+    assert!(FEN::parse("4k3/8/8/8/8/8/8/4K3 b - -").is_ok());
+    assert!(FEN::parse("rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 3").is_ok());
+}
+
+#[test]
+fn parse_errors() {
+    let cases = [
+        ("", FenError::InvalidFieldCount(0)),
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq",
+            FenError::InvalidFieldCount(3),
+        ),
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 extra",
+            FenError::InvalidFieldCount(7),
+        ),
+        (
+            "rnbqkbnr/pppppppp/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            FenError::InvalidRankCount(7),
+        ),
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNRR w KQkq - 0 1",
+            FenError::InvalidRank(1),
+        ),
+        (
+            "rnbqkbnr/ppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            FenError::InvalidRank(7),
+        ),
+        (
+            "rnbqkbnr/pppppppp/44/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            FenError::InvalidRank(6),
+        ),
+        (
+            "rnbxkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            FenError::InvalidPiece('x'),
+        ),
+        (
+            "rnbqkbnr/pppppppp/9/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            FenError::InvalidPiece('9'),
+        ),
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR x KQkq - 0 1",
+            FenError::InvalidSideToMove(String::from("x")),
+        ),
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkz - 0 1",
+            FenError::InvalidCastleRights(String::from("KQkz")),
+        ),
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KKkq - 0 1",
+            FenError::InvalidCastleRights(String::from("KKkq")),
+        ),
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq e4 0 1",
+            FenError::InvalidEnPassant(String::from("e4")),
+        ),
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq i3 0 1",
+            FenError::InvalidEnPassant(String::from("i3")),
+        ),
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - -1 1",
+            FenError::InvalidHalfMoveCounter(String::from("-1")),
+        ),
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 x",
+            FenError::InvalidFullMoveCounter(String::from("x")),
+        ),
+    ];
+
+    for (fen, error) in cases {
+        assert_eq!(FEN::parse(fen), Err(error), "{fen}");
+        assert!(!FEN::validate_fen(fen));
+    }
+}
+
+#[test]
+fn parse_fuzz() {
+    const FENS: [&str; 4] = [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 3",
+        "brnr1krr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BRRKR w HEgb - 2 9",
+    ];
+    const ALPHABET: &[u8] = b"pnbrqkPNBRQK12345678/ wbKQkqAHah-e3690";
+
+    let mut seed = 0x2545F4914F6CDD1Du64;
+    let mut random = || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    };
+
+    for fen in FENS {
+        for _ in 0..2000 {
+            let mut bytes = fen.as_bytes().to_vec();
+            for _ in 0..1 + random() % 3 {
+                let index = (random() % bytes.len() as u64) as usize;
+                match random() % 3 {
+                    0 => bytes[index] = ALPHABET[(random() % ALPHABET.len() as u64) as usize],
+                    1 => {
+                        bytes.remove(index);
+                    }
+                    _ => bytes.insert(index, ALPHABET[(random() % ALPHABET.len() as u64) as usize]),
+                }
+
+                if bytes.is_empty() {
+                    break;
+                }
+            }
+
+            let mutated = String::from_utf8(bytes).unwrap();
+            if let Ok(fen) = FEN::parse(&mutated) {
+                if let Ok(board) = ChessBoard::from_fen_checked(&fen) {
+                    board.map_legal_moves(|_| {});
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use chess::{ChessBoard, Epd, Game, FEN};
+
+fn unmake_recursive(board: &mut ChessBoard, depth: u8) {
+    if depth == 0 {
+        return;
+    }
+
+    let original = *board;
+    let mut moves = Vec::new();
+    board.map_legal_moves(|mv| moves.push(mv));
+
+    let mask = board.castle_rights().get_castle_mask();
+    for mv in moves {
+        let undo_info = board.make_move_with_undo(mv, &mask);
+        unmake_recursive(board, depth - 1);
+        board.unmake_move(mv, &undo_info);
+
+        assert_eq!(*board, original, "{}", mv.to_string(true));
+    }
+}
+
+#[test]
+fn unmake_restores_board() {
+    for path in ["./tests/standard.epd", "./tests/fischer.epd"] {
+        let file = File::open(path).unwrap();
+        let reader = BufReader::new(file);
+
+        for line in reader.lines() {
+            let epd = Epd::parse(&line.unwrap()).unwrap();
+            let mut board = epd.board().unwrap();
+            unmake_recursive(&mut board, 2);
+        }
+    }
+}
+
+#[test]
+fn make_and_undo() {
+    let mut game = Game::try_from(&FEN::start_position()).unwrap();
+
+    for mv in ["e2e4", "e7e5", "g1f3", "b8c6", "f1c4", "g8f6", "e1g1"] {
+        let mv = game.board().parse_move(mv).unwrap();
+        game.make_move(mv);
+    }
+
+    assert_eq!(game.ply(), 7);
+    assert_eq!(
+        FEN::from(game.board()).to_string(),
+        "r1bqkb1r/pppp1ppp/2n2n2/4p3/2B1P3/5N2/PPPP1PPP/RNBQ1RK1 b ha - 5 1"
+    );
+
+    let board_at_three = game.board_at(3).unwrap();
+    assert_eq!(
+        FEN::from(&board_at_three).to_string(),
+        "rnbqkbnr/pppp1ppp/8/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R b HAha - 1 1"
+    );
+
+    assert_eq!(
+        game.unmake_move().map(|mv| mv.to_string(false)),
+        Some(String::from("e1g1"))
+    );
+    while game.unmake_move().is_some() {}
+
+    assert_eq!(game.ply(), 0);
+    assert_eq!(game.board(), game.initial_board());
+    assert_eq!(
+        *game.board(),
+        ChessBoard::try_from(&FEN::start_position()).unwrap()
+    );
+}
+
+#[test]
+fn repetitions() {
+    let mut game = Game::try_from(&FEN::start_position()).unwrap();
+
+    assert_eq!(game.repetitions(), 1);
+    for _ in 0..2 {
+        for mv in ["g1f3", "g8f6", "f3g1", "f6g8"] {
+            let mv = game.board().parse_move(mv).unwrap();
+            game.make_move(mv);
+        }
+    }
+
+    assert_eq!(game.repetitions(), 3);
+    assert_eq!(
+        game.position()
+            .history()
+            .get_repetitions(game.board().hash()),
+        3
+    );
+    assert_eq!(game.position().board(), game.board());
+
+    let mv = game.board().parse_move("e2e4").unwrap();
+    game.make_move(mv);
+    assert_eq!(game.repetitions(), 1);
+    assert_eq!(game.position().history().len(), 1);
+
+    game.unmake_move();
+    assert_eq!(game.repetitions(), 3);
+}
+
+#[test]
+fn long_reversible_game() {
+    let mut game = Game::try_from(&FEN::start_position()).unwrap();
+
+    //Legal up to the seventy five move rule, past what the position history holds
+    for _ in 0..35 {
+        for mv in ["g1f3", "g8f6", "f3g1", "f6g8"] {
+            let mv = game.board().parse_move(mv).unwrap();
+            game.make_move(mv);
+        }
+    }
+
+    assert_eq!(game.board().half_moves(), 140);
+
+    let mut position = game.position();
+    assert_eq!(position.board(), game.board());
+    assert_eq!(position.history().len(), 101);
+
+    let mv = position.board().parse_move("g1f3").unwrap();
+    position.make_move_no_mask(mv);
+    assert_eq!(position.history().len(), 101);
+    assert_eq!(
+        position.history().get_repetitions(position.board().hash()),
+        26
+    );
+}
+
+#[test]
+fn common_prefix() {
+    let mut game = Game::try_from(&FEN::start_position()).unwrap();
+    let mut other = game.clone();
+
+    for mv in ["e2e4", "e7e5"] {
+        let mv = game.board().parse_move(mv).unwrap();
+        game.make_move(mv);
+        other.make_move(mv);
+    }
+
+    let mv = game.board().parse_move("g1f3").unwrap();
+    game.make_move(mv);
+    let mv = other.board().parse_move("f2f4").unwrap();
+    other.make_move(mv);
+
+    assert_eq!(game.common_prefix(&other), Some(2));
+    assert_eq!(
+        game.common_prefix(
+            &Game::try_from(&FEN::from(
+                "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1"
+            ))
+            .unwrap()
+        ),
+        None
+    );
+}
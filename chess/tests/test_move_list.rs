@@ -0,0 +1,126 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use chess::{ChessBoard, Epd, Move, MoveList, Square, FEN};
+
+fn load_boards() -> Vec<ChessBoard> {
+    let mut result = Vec::new();
+    for path in ["./tests/standard.epd", "./tests/fischer.epd"] {
+        let file = File::open(path).unwrap();
+        let reader = BufReader::new(file);
+
+        for line in reader.lines() {
+            result.push(Epd::parse(&line.unwrap()).unwrap().board().unwrap());
+        }
+    }
+
+    result
+}
+
+fn verify_all_moves(board: &ChessBoard) {
+    let legal_moves = board.legal_moves();
+
+    for value in 0..=u16::MAX {
+        let mv = Move::from(value);
+        assert_eq!(
+            board.is_legal(mv),
+            legal_moves.contains(&mv),
+            "{} {}",
+            FEN::from(board),
+            mv.to_string(true)
+        );
+    }
+}
+
+#[test]
+fn legal_moves_match_map_legal_moves() {
+    for board in load_boards() {
+        let mut expected = Vec::new();
+        board.map_legal_moves(|mv| expected.push(mv));
+        assert_eq!(board.legal_moves().as_ref(), expected.as_slice());
+
+        let mut expected = Vec::new();
+        board.map_capture_moves(|mv| expected.push(mv));
+        assert_eq!(board.capture_moves().as_ref(), expected.as_slice());
+    }
+}
+
+#[test]
+fn is_legal_matches_legal_moves() {
+    for board in load_boards() {
+        for mv in board.legal_moves() {
+            assert!(board.is_pseudo_legal(mv));
+            assert!(board.is_legal(mv));
+        }
+
+        verify_all_moves(&board);
+    }
+}
+
+#[test]
+fn is_legal_after_moves() {
+    let mut seed = 0x9E3779B97F4A7C15u64;
+    let mut next_random = || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    };
+
+    for board in load_boards().into_iter().step_by(8) {
+        for mv in board.legal_moves() {
+            let mut child = board;
+            child.make_move_no_mask(mv);
+
+            let legal_moves = child.legal_moves();
+            for candidate in board
+                .legal_moves()
+                .into_iter()
+                .chain((0..256).map(|_| Move::from(next_random() as u16)))
+            {
+                assert_eq!(
+                    child.is_legal(candidate),
+                    legal_moves.contains(&candidate),
+                    "{} {}",
+                    FEN::from(&child),
+                    candidate.to_string(true)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn move_list_operations() {
+    let board = ChessBoard::try_from(&FEN::start_position()).unwrap();
+    let mut moves = board.legal_moves();
+
+    assert_eq!(moves.len(), 20);
+    assert!(!moves.is_empty());
+
+    moves.sort_by_key(|mv| u16::from(*mv));
+    assert!(moves
+        .windows(2)
+        .all(|pair| u16::from(pair[0]) <= u16::from(pair[1])));
+
+    moves.retain(|mv| mv.from_square() == Square::G1);
+    assert_eq!(moves.len(), 2);
+
+    let popped = moves.pop();
+    assert!(popped.is_some());
+    assert_eq!(moves.len(), 1);
+
+    let collected = board
+        .legal_moves()
+        .iter()
+        .copied()
+        .filter(|mv| mv.is_capture())
+        .collect::<MoveList>();
+    assert!(collected.is_empty());
+
+    moves.clear();
+    assert_eq!(moves.pop(), None);
+    assert_eq!(moves, MoveList::new());
+}
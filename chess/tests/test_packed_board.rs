@@ -0,0 +1,125 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use chess::{
+    BoardError, ChessBoard, Epd, PackedBoard, Piece, Side, Square, Variant, FEN, PACKED_BOARD_SIZE,
+};
+
+fn load_boards() -> Vec<ChessBoard> {
+    let mut result = Vec::new();
+    for path in ["./tests/standard.epd", "./tests/fischer.epd"] {
+        let file = File::open(path).unwrap();
+        for line in BufReader::new(file).lines() {
+            result.push(Epd::parse(&line.unwrap()).unwrap().board().unwrap());
+        }
+    }
+
+    result
+}
+
+fn round_trip(board: &ChessBoard) -> ChessBoard {
+    let packed = board.to_packed().unwrap();
+    let decoded = PackedBoard::from_bytes(*packed.as_bytes()).unwrap();
+    assert_eq!(decoded, packed);
+
+    let unpacked = ChessBoard::from_packed(&decoded).unwrap();
+    assert_eq!(unpacked.to_packed(), Some(packed));
+    assert_eq!(FEN::from(&unpacked), FEN::from(board));
+    assert_eq!(unpacked.hash(), board.hash());
+    assert_eq!(unpacked.legal_moves(), board.legal_moves());
+
+    unpacked
+}
+
+#[test]
+fn perft_suites_round_trip() {
+    for board in load_boards() {
+        assert_eq!(round_trip(&board), board);
+
+        board.map_legal_moves(|mv| {
+            let mut board_copy = board;
+            board_copy.make_move_no_mask(mv);
+            round_trip(&board_copy);
+
+            board_copy.map_legal_moves(|mv| {
+                let mut board_copy = board_copy;
+                board_copy.make_move_no_mask(mv);
+                round_trip(&board_copy);
+            });
+        });
+    }
+}
+
+#[test]
+fn packed_variants() {
+    let three_check = ChessBoard::try_from(&FEN::from(
+        "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 2+1 0 2",
+    ))
+    .unwrap();
+    assert_eq!(round_trip(&three_check), three_check);
+
+    let koth = ChessBoard::from_fen_with_variant(
+        &FEN::from("8/8/8/8/2K5/8/8/k7 w - - 0 1"),
+        Variant::KingOfTheHill,
+    )
+    .unwrap();
+    assert_eq!(round_trip(&koth).variant(), Variant::KingOfTheHill);
+
+    //Too many pieces or pieces in hand cannot be packed
+    let horde = ChessBoard::from_fen_with_variant(&Variant::Horde.start_position(), Variant::Horde);
+    assert_eq!(horde.unwrap().to_packed(), None);
+
+    let crazyhouse = ChessBoard::try_from(&Variant::Crazyhouse.start_position()).unwrap();
+    assert_eq!(crazyhouse.to_packed(), None);
+}
+
+#[test]
+fn invalid_bytes() {
+    let board = ChessBoard::try_from(&FEN::start_position()).unwrap();
+    let bytes = *board.to_packed().unwrap().as_bytes();
+    assert_eq!(bytes.len(), PACKED_BOARD_SIZE);
+
+    let mut invalid_piece = bytes;
+    invalid_piece[8] |= 0b0110;
+    assert_eq!(PackedBoard::from_bytes(invalid_piece), None);
+
+    let mut invalid_en_passant = bytes;
+    invalid_en_passant[24] = 70;
+    assert_eq!(PackedBoard::from_bytes(invalid_en_passant), None);
+
+    let mut invalid_variant = bytes;
+    invalid_variant[26] = 9;
+    assert_eq!(PackedBoard::from_bytes(invalid_variant), None);
+
+    let mut missing_pieces = bytes;
+    missing_pieces[0] = 0;
+    assert_eq!(PackedBoard::from_bytes(missing_pieces), None);
+}
+
+#[test]
+fn corrupted_boards() {
+    let board = ChessBoard::try_from(&FEN::start_position()).unwrap();
+    let bytes = *board.to_packed().unwrap().as_bytes();
+
+    //White king on e1 turned into a queen, white queen on d1 turned into a king
+    let mut no_king = bytes;
+    no_king[10] = (no_king[10] & 0xF0) | u8::from(Piece::QUEEN);
+
+    let mut two_kings = bytes;
+    two_kings[9] = (two_kings[9] & 0x0F) | u8::from(Piece::KING) << 4;
+
+    //Queen side rook moved onto the knight square
+    let mut castle_rook = bytes;
+    castle_rook[28] = u8::from(Square::B1);
+
+    for (bytes, error) in [
+        (no_king, BoardError::InvalidKingCount(Side::WHITE, 0)),
+        (two_kings, BoardError::InvalidKingCount(Side::WHITE, 2)),
+        (castle_rook, BoardError::InvalidCastleRights('B')),
+    ] {
+        let packed = PackedBoard::from_bytes(bytes).unwrap();
+        assert_eq!(ChessBoard::from_packed(&packed), Err(error));
+    }
+}
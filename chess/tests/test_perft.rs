@@ -3,7 +3,7 @@ use std::{
     io::{BufRead, BufReader},
 };
 
-use chess::{perft, ChessBoard, FEN};
+use chess::{perft, perft_divide, perft_hashed, perft_parallel, ChessBoard, Epd, PerftTable, FEN};
 
 #[test]
 fn standard() {
@@ -11,16 +11,13 @@ fn standard() {
     let reader = BufReader::new(file);
 
     for line in reader.lines() {
-        let line = line.unwrap();
-        let line = line.split(';').collect::<Vec<&str>>();
-        let fen = FEN::from(line[0]);
-        let target = line[line.len() - 2]
-            .split_whitespace()
-            .collect::<Vec<&str>>();
-        let expected_result = target[1].parse::<u128>().unwrap();
-        let depth = target[0].chars().collect::<Vec<char>>()[1] as u8 - b'0';
-        println!("{fen}");
-        let (result, _) = perft::<true, false, false>(&ChessBoard::from(&fen), Some(depth));
+        let epd = Epd::parse(&line.unwrap()).unwrap();
+        let operations = epd.operations().collect::<Vec<_>>();
+        let (opcode, operands) = operations[operations.len() - 2];
+        let expected_result = operands[0].parse::<u128>().unwrap();
+        let depth = opcode[1..].parse::<u8>().unwrap();
+        println!("{}", epd.fen());
+        let (result, _) = perft::<true, false, false>(&epd.board().unwrap(), Some(depth));
         assert_eq!(result, expected_result);
     }
 }
@@ -31,16 +28,50 @@ fn frc() {
     let reader = BufReader::new(file);
 
     for line in reader.lines() {
-        let line = line.unwrap();
-        let line = line.split(';').collect::<Vec<&str>>();
-        let fen = FEN::from(line[0]);
-        let target = line[line.len() - 3]
-            .split_whitespace()
-            .collect::<Vec<&str>>();
-        let expected_result = target[1].parse::<u128>().unwrap();
-        let depth = target[0].chars().collect::<Vec<char>>()[1] as u8 - b'0';
-        println!("{fen}");
-        let (result, _) = perft::<true, false, true>(&ChessBoard::from(&fen), Some(depth));
+        let epd = Epd::parse(&line.unwrap()).unwrap();
+        let operations = epd.operations().collect::<Vec<_>>();
+        let (opcode, operands) = operations[operations.len() - 3];
+        let expected_result = operands[0].parse::<u128>().unwrap();
+        let depth = opcode[1..].parse::<u8>().unwrap();
+        println!("{}", epd.fen());
+        let (result, _) = perft::<true, false, true>(&epd.board().unwrap(), Some(depth));
         assert_eq!(result, expected_result);
     }
 }
+
+#[test]
+fn hashed_and_threaded() {
+    let table = PerftTable::new(16);
+
+    for path in ["./tests/standard.epd", "./tests/fischer.epd"] {
+        let file = File::open(path).unwrap();
+        for line in BufReader::new(file).lines().take(40) {
+            let epd = Epd::parse(&line.unwrap()).unwrap();
+            let board = epd.board().unwrap();
+            for (opcode, operands) in epd.operations().take(4) {
+                let expected_result = operands[0].parse::<u128>().unwrap();
+                let depth = opcode[1..].parse::<u8>().unwrap();
+
+                assert_eq!(perft_hashed(&board, Some(depth), &table).0, expected_result);
+                assert_eq!(
+                    perft_parallel(&board, Some(depth), 3, None).0,
+                    expected_result
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn divide() {
+    let board = ChessBoard::try_from(&FEN::start_position()).unwrap();
+    let table = PerftTable::new(1);
+    let divide = perft_divide(&board, 3, 2, Some(&table));
+
+    assert_eq!(divide.len(), 20);
+    assert_eq!(divide.iter().map(|&(_, count)| count).sum::<u128>(), 8902);
+    assert!(divide
+        .iter()
+        .any(|&(mv, count)| mv.to_string(false) == "e2e4" && count == 600));
+    assert!(perft_divide(&board, 0, 2, None).is_empty());
+}
@@ -0,0 +1,118 @@
+use chess::{ChessBoard, DrawReason, Game, GameResult, Side, WinReason, FEN};
+
+fn play(game: &mut Game, moves: &[&str]) {
+    for mv in moves {
+        let mv = game.board().parse_move(mv).unwrap();
+        game.make_move(mv);
+    }
+}
+
+#[test]
+fn checkmate_and_stalemate() {
+    let mut game = Game::try_from(&FEN::start_position()).unwrap();
+    play(&mut game, &["f2f3", "e7e5", "g2g4", "d8h4"]);
+
+    let result = game.result();
+    assert_eq!(
+        result,
+        Some(GameResult::Win(Side::BLACK, WinReason::Checkmate))
+    );
+    assert_eq!(result.unwrap().white_score(), 0.0);
+    assert_eq!(result.unwrap().to_string(), "0-1 (checkmate)");
+
+    let game = Game::try_from(&FEN::from("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1")).unwrap();
+    assert_eq!(game.result(), Some(GameResult::Draw(DrawReason::Stalemate)));
+}
+
+#[test]
+fn repetitions() {
+    let mut game = Game::try_from(&FEN::start_position()).unwrap();
+    let shuffle = ["g1f3", "g8f6", "f3g1", "f6g8"];
+
+    play(&mut game, &shuffle);
+    assert_eq!(game.claimable_draw(), None);
+
+    play(&mut game, &shuffle);
+    assert_eq!(game.claimable_draw(), Some(DrawReason::ThreefoldRepetition));
+    assert_eq!(game.result(), None);
+    assert_eq!(
+        game.result_with_claims(),
+        Some(GameResult::Draw(DrawReason::ThreefoldRepetition))
+    );
+
+    play(&mut game, &shuffle);
+    assert_eq!(game.automatic_draw(), None);
+
+    play(&mut game, &shuffle);
+    assert_eq!(
+        game.result(),
+        Some(GameResult::Draw(DrawReason::FivefoldRepetition))
+    );
+
+    let position = game.position();
+    assert_eq!(position.repetitions(), 5);
+    assert_eq!(position.result(), game.result());
+}
+
+#[test]
+fn move_rules() {
+    let game = Game::try_from(&FEN::from("4k3/8/8/8/8/8/8/R3K3 w - - 99 80")).unwrap();
+    assert_eq!(game.claimable_draw(), None);
+
+    let game = Game::try_from(&FEN::from("4k3/8/8/8/8/8/8/R3K3 w - - 100 80")).unwrap();
+    assert_eq!(game.claimable_draw(), Some(DrawReason::FiftyMoveRule));
+    assert_eq!(game.result(), None);
+
+    let game = Game::try_from(&FEN::from("4k3/8/8/8/8/8/8/R3K3 w - - 150 105")).unwrap();
+    assert_eq!(
+        game.result(),
+        Some(GameResult::Draw(DrawReason::SeventyFiveMoveRule))
+    );
+
+    //Mate delivered on the 150th ply still wins
+    let game = Game::try_from(&FEN::from("R3k3/8/4K3/8/8/8/8/8 b - - 150 105")).unwrap();
+    assert_eq!(
+        game.result(),
+        Some(GameResult::Win(Side::WHITE, WinReason::Checkmate))
+    );
+}
+
+#[test]
+fn dead_positions() {
+    for fen in [
+        "8/8/4k3/8/8/3K4/8/8 w - - 0 1",
+        "8/8/4k3/8/8/3KN3/8/8 w - - 0 1",
+        "8/8/3bk3/8/8/3KB3/8/8 w - - 0 1",
+    ] {
+        let game = Game::try_from(&FEN::from(fen)).unwrap();
+        assert_eq!(
+            game.result(),
+            Some(GameResult::Draw(DrawReason::InsufficientMaterial)),
+            "{fen}"
+        );
+    }
+
+    for fen in [
+        "8/8/4k3/p1p1p1p1/P1P1P1P1/8/3K4/8 w - - 0 1",
+        "8/2k5/8/1p1p1p1p/pP1P1P1P/P7/4K3/8 b - - 0 1",
+    ] {
+        let board = ChessBoard::try_from(&FEN::from(fen)).unwrap();
+        assert!(board.is_dead_position(), "{fen}");
+        assert_eq!(
+            Game::from(board).result(),
+            Some(GameResult::Draw(DrawReason::DeadPosition)),
+            "{fen}"
+        );
+    }
+
+    for fen in [
+        "8/8/4k3/8/8/2n1KN2/8/8 w - - 0 1",
+        "8/8/4k3/p1p1p1p1/P1P1P1P1/8/3K4/7R w - - 0 1",
+        "8/8/4k3/p1p1p3/P1P1P1P1/8/3K4/8 w - - 0 1",
+        "8/8/4k3/p1p1p2p/P1P1P1P1/8/3K4/8 w - - 0 1",
+        "8/8/4k3/p1p1p3/P1P1P3/8/3K4/8 w - - 0 1",
+    ] {
+        let board = ChessBoard::try_from(&FEN::from(fen)).unwrap();
+        assert!(!board.is_dead_position(), "{fen}");
+    }
+}
@@ -0,0 +1,110 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use chess::{Bitboard, ChessBoard, Epd, Move, Side, FEN};
+
+fn squares(mask: Bitboard) -> Vec<String> {
+    let mut result = Vec::new();
+    mask.map(|square| result.push(square.to_string()));
+    result
+}
+
+fn find_move(board: &ChessBoard, text: &str) -> Move {
+    board
+        .legal_moves()
+        .iter()
+        .copied()
+        .find(|mv| mv.to_string(false) == text)
+        .unwrap()
+}
+
+#[test]
+fn see_score_matches_threshold() {
+    let file = File::open("./tests/standard.epd").unwrap();
+    for line in BufReader::new(file).lines() {
+        let board = Epd::parse(&line.unwrap()).unwrap().board().unwrap();
+
+        let mut boards = vec![board];
+        board.map_legal_moves(|mv| {
+            let mut board_copy = board;
+            board_copy.make_move_no_mask(mv);
+            boards.push(board_copy);
+        });
+
+        for board in boards {
+            board.map_legal_moves(|mv| {
+                let score = board.see_score(mv);
+                for threshold in [-1300, -651, -450, -108, -1, 0, 1, 100, 450, 1300] {
+                    assert_eq!(
+                        board.see(mv, threshold),
+                        score >= threshold,
+                        "{} {} {threshold}",
+                        FEN::from(&board),
+                        mv.to_string(false)
+                    );
+                }
+            });
+        }
+    }
+}
+
+#[test]
+fn see_score_values() {
+    let board = ChessBoard::try_from(&FEN::from(
+        "1k1r4/1pp4p/p7/4p3/8/P5P1/1PP4P/2K1R3 w - - 0 1",
+    ))
+    .unwrap();
+    assert_eq!(board.see_score(find_move(&board, "e1e5")), 100);
+
+    let board = ChessBoard::try_from(&FEN::from(
+        "1k1r3q/1ppn3p/p4b2/4p3/8/P2N2P1/1PP1R1BP/2K1Q3 w - - 0 1",
+    ))
+    .unwrap();
+    assert_eq!(board.see_score(find_move(&board, "d3e5")), -350);
+
+    //Quiet moves to squares the d6 pawn does not attack lose nothing
+    let board = ChessBoard::try_from(&FEN::from("4k3/8/3p4/8/3N4/8/8/4K3 w - - 0 1")).unwrap();
+    assert_eq!(board.see_score(find_move(&board, "d4c6")), 0);
+    assert_eq!(board.see_score(find_move(&board, "d4e6")), 0);
+    assert_eq!(board.see_score(find_move(&board, "d4b5")), 0);
+
+    //Quiet move into a square the pawn attacks loses the piece
+    let board = ChessBoard::try_from(&FEN::from("4k3/8/3p4/8/8/3N4/8/4K3 w - - 0 1")).unwrap();
+    assert_eq!(board.see_score(find_move(&board, "d3e5")), -450);
+    assert_eq!(board.see_score(find_move(&board, "d3c5")), -450);
+}
+
+#[test]
+fn threat_queries() {
+    //Knight on c6 is attacked by a pawn, rook on a8 is undefended
+    let board = ChessBoard::try_from(&FEN::from("r3k3/8/2n5/3P4/8/8/8/4K3 w - - 0 1")).unwrap();
+    assert_eq!(squares(board.attacked_pieces(Side::BLACK)), vec!["c6"]);
+    assert_eq!(squares(board.hanging_pieces(Side::BLACK)), vec!["c6"]);
+    assert_eq!(
+        squares(board.undefended_pieces(Side::BLACK)),
+        vec!["c6", "a8"]
+    );
+    assert_eq!(squares(board.undefended_pieces(Side::WHITE)), vec!["d5"]);
+
+    //Knight pinned by the bishop, white rook blocked by its own knight
+    let board = ChessBoard::try_from(&FEN::from("4k3/8/2n5/1B6/8/8/4N3/4RK2 w - - 0 1")).unwrap();
+    assert_eq!(squares(board.pinned_pieces(Side::BLACK)), vec!["c6"]);
+    assert_eq!(board.pinned_pieces(Side::WHITE), Bitboard::EMPTY);
+    assert_eq!(
+        squares(board.discovered_attack_candidates(Side::WHITE)),
+        vec!["e2"]
+    );
+    assert_eq!(
+        board.discovered_attack_candidates(Side::BLACK),
+        Bitboard::EMPTY
+    );
+
+    let start = ChessBoard::try_from(&FEN::start_position()).unwrap();
+    assert_eq!(start.hanging_pieces(Side::WHITE), Bitboard::EMPTY);
+    assert_eq!(
+        squares(start.undefended_pieces(Side::WHITE)),
+        vec!["a1", "h1"]
+    );
+}
@@ -0,0 +1,120 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use chess::{Bitboard, ChessBoard, Epd, Move, MoveFlag, Piece, Side, Square, Variant, FEN};
+
+fn round_trip<T>(value: &T) -> T
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap()
+}
+
+#[test]
+fn basic_types() {
+    assert_eq!(
+        serde_json::to_string(&Square::from("e4")).unwrap(),
+        "\"e4\""
+    );
+    assert_eq!(serde_json::to_string(&Piece::KNIGHT).unwrap(), "\"n\"");
+    assert_eq!(serde_json::to_string(&Side::BLACK).unwrap(), "\"black\"");
+    assert_eq!(serde_json::to_string(&Bitboard::RANK_1).unwrap(), "255");
+
+    for square in 0..64u8 {
+        assert_eq!(round_trip(&Square::from(square)), Square::from(square));
+    }
+    assert_eq!(round_trip(&Square::NULL), Square::NULL);
+
+    for piece in 0..6u8 {
+        assert_eq!(round_trip(&Piece::from(piece)), Piece::from(piece));
+    }
+
+    for side in [Side::WHITE, Side::BLACK] {
+        assert_eq!(round_trip(&side), side);
+    }
+
+    assert_eq!(round_trip(&Bitboard::FULL), Bitboard::FULL);
+
+    assert!(serde_json::from_str::<Square>("\"i9\"").is_err());
+    assert!(serde_json::from_str::<Piece>("\"x\"").is_err());
+    assert!(serde_json::from_str::<Side>("\"red\"").is_err());
+}
+
+#[test]
+fn moves() {
+    let board = ChessBoard::try_from(&FEN::kiwipete_position()).unwrap();
+    board.map_legal_moves(|mv| {
+        let json = serde_json::to_string(&mv).unwrap();
+        assert!(json.starts_with(&format!("{{\"uci\":\"{}\",", mv.to_string(true))));
+
+        //Capture, castle and en passant flags survive without the board
+        assert_eq!(round_trip(&mv), mv);
+    });
+
+    let castle = Move::from_squares(Square::E1, Square::H1, MoveFlag::KING_SIDE_CASTLE);
+    assert_eq!(
+        serde_json::to_string(&castle).unwrap(),
+        "{\"uci\":\"e1h1\",\"flag\":\"king_side_castle\"}"
+    );
+
+    let promotion = Move::from_squares(Square::E7, Square::E8, MoveFlag::QUEEN_PROMOTION);
+    assert_eq!(
+        serde_json::to_string(&promotion).unwrap(),
+        "{\"uci\":\"e7e8q\",\"flag\":\"queen_promotion\"}"
+    );
+    assert_eq!(round_trip(&promotion), promotion);
+
+    let drop = Move::from_drop(Piece::KNIGHT, Square::from("f3"));
+    assert_eq!(
+        serde_json::to_string(&drop).unwrap(),
+        "{\"uci\":\"N@f3\",\"flag\":\"drop\"}"
+    );
+    assert_eq!(round_trip(&drop), drop);
+    assert_eq!(round_trip(&Move::NULL), Move::NULL);
+
+    assert!(serde_json::from_str::<Move>("\"e2e4\"").is_err());
+    assert!(serde_json::from_str::<Move>("{\"uci\":\"e7e8q\",\"flag\":\"quiet\"}").is_err());
+    assert!(serde_json::from_str::<Move>("{\"uci\":\"e2e4\",\"flag\":\"sideways\"}").is_err());
+}
+
+#[test]
+fn boards() {
+    let file = File::open("./tests/standard.epd").unwrap();
+    for line in BufReader::new(file).lines() {
+        let board = Epd::parse(&line.unwrap()).unwrap().board().unwrap();
+        let fen = FEN::from(&board);
+
+        assert_eq!(
+            serde_json::to_string(&board).unwrap(),
+            format!("{{\"fen\":\"{fen}\",\"variant\":\"chess\"}}")
+        );
+        assert_eq!(round_trip(&board), board);
+        assert_eq!(round_trip(&fen).to_string(), fen.to_string());
+    }
+
+    let crazyhouse = ChessBoard::try_from(&FEN::from(
+        "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R[Pp] w KQkq - 2 3",
+    ))
+    .unwrap();
+    assert_eq!(round_trip(&crazyhouse), crazyhouse);
+
+    for variant in Variant::ALL {
+        let board = ChessBoard::from_fen_with_variant(&variant.start_position(), variant).unwrap();
+        assert_eq!(round_trip(&variant), variant);
+        assert_eq!(round_trip(&board).variant(), variant);
+        assert_eq!(round_trip(&board), board);
+    }
+
+    assert!(
+        serde_json::from_str::<ChessBoard>("{\"fen\":\"not a fen\",\"variant\":\"chess\"}")
+            .is_err()
+    );
+    assert!(serde_json::from_str::<ChessBoard>(&format!(
+        "{{\"fen\":\"{}\",\"variant\":\"chess960\"}}",
+        FEN::start_position()
+    ))
+    .is_err());
+    assert!(serde_json::from_str::<FEN>("\"8/8/8 w - - 0 1\"").is_err());
+}
@@ -0,0 +1,165 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use chess::{perft_with_mode, ChessBoard, Epd, Move, MoveStage, PerftMode, Variant, FEN};
+
+fn load_epds() -> Vec<Epd> {
+    let file = File::open("./tests/standard.epd").unwrap();
+    BufReader::new(file)
+        .lines()
+        .map(|line| Epd::parse(&line.unwrap()).unwrap())
+        .collect()
+}
+
+fn sorted(mut moves: Vec<Move>) -> Vec<u16> {
+    moves.sort_by_key(|&mv| u16::from(mv));
+    moves.into_iter().map(u16::from).collect()
+}
+
+fn verify_generators(board: &ChessBoard) {
+    let legal = sorted(board.legal_moves().to_vec());
+
+    let filter = board.legality_filter();
+    let mut pseudo_legal = Vec::new();
+    board.map_pseudo_legal_moves(|mv| {
+        if filter.is_legal(board, mv) {
+            pseudo_legal.push(mv)
+        }
+    });
+    assert_eq!(sorted(pseudo_legal), legal, "{}", FEN::from(board));
+
+    let staged = board.staged_moves().collect::<Vec<_>>();
+    assert_eq!(sorted(staged.clone()), legal, "{}", FEN::from(board));
+
+    //Captures always come before quiet moves, and promotions before other quiets
+    let first_quiet = staged
+        .iter()
+        .position(|mv| !mv.is_capture())
+        .unwrap_or(staged.len());
+    assert!(staged[first_quiet..].iter().all(|mv| !mv.is_capture()));
+
+    if !board.is_in_check() {
+        let first_non_promotion = staged[first_quiet..]
+            .iter()
+            .position(|mv| !mv.is_promotion())
+            .map_or(staged.len(), |index| first_quiet + index);
+        assert!(staged[first_non_promotion..]
+            .iter()
+            .all(|mv| !mv.is_promotion()));
+    }
+}
+
+#[test]
+fn pseudo_legal_and_staged_match_legal() {
+    for epd in load_epds() {
+        let board = epd.board().unwrap();
+        verify_generators(&board);
+
+        board.map_legal_moves(|mv| {
+            let mut board_copy = board;
+            board_copy.make_move_no_mask(mv);
+            verify_generators(&board_copy);
+        });
+    }
+
+    for variant in [Variant::Horde, Variant::Crazyhouse] {
+        let board = ChessBoard::from_fen_with_variant(&variant.start_position(), variant).unwrap();
+        verify_generators(&board);
+    }
+
+    let pockets = ChessBoard::try_from(&FEN::from("4k3/8/8/8/8/8/8/r3K3[Nn] w - - 0 1")).unwrap();
+    verify_generators(&pockets);
+}
+
+#[test]
+fn staged_stages() {
+    let board = ChessBoard::try_from(&FEN::from("4k3/1P6/8/3p4/4P3/8/8/4K3 w - - 0 1")).unwrap();
+    let mut moves = board.staged_moves();
+    assert_eq!(moves.stage(), MoveStage::Captures);
+    assert_eq!(
+        moves.next().map(|mv| mv.to_string(false)),
+        Some(String::from("e4d5"))
+    );
+    assert_eq!(moves.next().map(|mv| mv.is_promotion()), Some(true));
+    assert_eq!(moves.stage(), MoveStage::Promotions);
+
+    let mut moves = moves.skip_while(|mv| mv.is_promotion());
+    assert!(moves.next().is_some());
+
+    let evasions = ChessBoard::try_from(&FEN::from("4k3/8/8/8/8/8/3q4/4K3 w - - 0 1")).unwrap();
+    let mut moves = evasions.staged_moves();
+    assert_eq!(moves.stage(), MoveStage::Evasions);
+    assert_eq!(
+        moves.next().map(|mv| mv.to_string(false)),
+        Some(String::from("e1d2"))
+    );
+    assert_eq!(moves.count(), 1);
+}
+
+#[test]
+fn quiet_promotions() {
+    let board = ChessBoard::try_from(&FEN::from("4k3/1P4P1/8/8/8/8/8/4K3 w - - 0 1")).unwrap();
+    let mut promotions = Vec::new();
+    board.map_quiet_promotions(|mv| promotions.push(mv));
+    assert_eq!(promotions.len(), 8);
+    assert!(promotions
+        .iter()
+        .all(|mv| mv.is_promotion() && !mv.is_capture()));
+
+    //A pawn pinned along the rank cannot leave it, a blocked pawn cannot push
+    for fen in [
+        "4k3/KP5r/8/8/8/8/8/8 w - - 0 1",
+        "1n2k3/1P6/8/8/8/8/8/4K3 w - - 0 1",
+    ] {
+        let board = ChessBoard::try_from(&FEN::from(fen)).unwrap();
+        let mut promotions = Vec::new();
+        board.map_quiet_promotions(|mv| promotions.push(mv));
+        assert!(promotions.is_empty(), "{fen}");
+        verify_generators(&board);
+    }
+}
+
+#[test]
+fn quiet_promotions_in_check() {
+    //No block, a block of a single check, and a block of only one of two checkers
+    for (fen, count) in [
+        ("4k3/1P4P1/8/8/8/8/8/r3K3 w - - 0 1", 0),
+        ("K6r/1P4P1/8/8/8/8/8/4k3 w - - 0 1", 8),
+        ("K6r/1P6/8/8/8/8/8/q3k3 w - - 0 1", 0),
+    ] {
+        let board = ChessBoard::try_from(&FEN::from(fen)).unwrap();
+        let mut promotions = Vec::new();
+        board.map_quiet_promotions(|mv| promotions.push(mv));
+
+        let legal = board
+            .legal_moves()
+            .to_vec()
+            .into_iter()
+            .filter(|mv| mv.is_promotion() && !mv.is_capture())
+            .collect::<Vec<_>>();
+
+        assert_eq!(promotions.len(), count, "{fen}");
+        assert_eq!(sorted(promotions), sorted(legal), "{fen}");
+    }
+}
+
+#[test]
+fn perft_modes() {
+    for epd in load_epds().into_iter().take(20) {
+        let expected = epd
+            .operations()
+            .find(|(opcode, _)| *opcode == "D3")
+            .map(|(_, operands)| operands[0].parse::<u128>().unwrap());
+
+        let Some(expected) = expected else {
+            continue;
+        };
+
+        let board = epd.board().unwrap();
+        for mode in [PerftMode::Legal, PerftMode::PseudoLegal, PerftMode::Staged] {
+            assert_eq!(perft_with_mode(&board, Some(3), mode).0, expected);
+        }
+    }
+}
@@ -0,0 +1,306 @@
+use chess::{
+    perft, BoardError, ChessBoard, Game, GameResult, Move, MoveFlag, Piece, Side, Square, Variant,
+    WinReason, FEN,
+};
+
+fn board(fen: &str, variant: Variant) -> ChessBoard {
+    ChessBoard::from_fen_with_variant(&FEN::parse(fen).unwrap(), variant).unwrap()
+}
+
+fn assert_perft(board: &ChessBoard, expected: &[u128]) {
+    for (depth, &nodes) in expected.iter().enumerate() {
+        let depth = depth as u8 + 1;
+        assert_eq!(perft::<true, false, false>(board, Some(depth)).0, nodes);
+        assert_eq!(perft::<false, false, false>(board, Some(depth)).0, nodes);
+    }
+}
+
+#[test]
+fn horde_perft() {
+    let start = ChessBoard::from_fen_with_variant(&Variant::Horde.start_position(), Variant::Horde);
+    assert_perft(&start.unwrap(), &[8, 128, 1274, 23310]);
+
+    let open_flank = board(
+        "4k3/pp4q1/3P2p1/8/P3PP2/PPP2r2/PPP5/PPPP4 b - - 0 1",
+        Variant::Horde,
+    );
+    assert_perft(&open_flank, &[30, 241, 6633, 56539]);
+
+    let en_passant = board(
+        "k7/5p2/4p2P/3p2P1/2p2P2/1p2P2P/p2P2P1/2P2P2 w - - 0 1",
+        Variant::Horde,
+    );
+    assert_perft(&en_passant, &[13, 172, 2205, 33781]);
+}
+
+#[test]
+fn three_check_perft() {
+    let kiwipete = board(
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 1+1 0 1",
+        Variant::ThreeCheck,
+    );
+    assert_perft(&kiwipete, &[48, 2039, 97848]);
+
+    let start = ChessBoard::try_from(&Variant::ThreeCheck.start_position()).unwrap();
+    assert_eq!(start.variant(), Variant::ThreeCheck);
+    assert_perft(&start, &[20, 400, 8902, 197281]);
+}
+
+#[test]
+fn king_of_the_hill_perft() {
+    let kiwipete = board(
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        Variant::KingOfTheHill,
+    );
+    assert_perft(&kiwipete, &[48, 2039, 97862]);
+
+    let near_hill = board("8/8/8/8/2K5/8/8/k7 w - - 0 1", Variant::KingOfTheHill);
+    assert_perft(&near_hill, &[8, 15, 105, 457]);
+}
+
+#[test]
+fn king_of_the_hill_result() {
+    let mut game = Game::from(board(
+        "8/8/8/8/2K5/8/8/k7 w - - 0 1",
+        Variant::KingOfTheHill,
+    ));
+    assert_eq!(game.result(), None);
+
+    game.make_move(Move::from_squares(
+        Square::C4,
+        Square::D4,
+        MoveFlag::QUIET_MOVE,
+    ));
+    assert_eq!(
+        game.result(),
+        Some(GameResult::Win(Side::WHITE, WinReason::KingOfTheHill))
+    );
+    assert_eq!(perft::<true, false, false>(game.board(), Some(1)).0, 0);
+
+    //Bare kings are not a draw when they can still walk to the centre
+    let standard = ChessBoard::try_from(&FEN::from("8/8/8/8/2K5/8/8/k7 w - - 0 1")).unwrap();
+    assert!(standard.is_insufficient_material());
+    assert!(!game.board().is_insufficient_material());
+}
+
+#[test]
+fn three_check_counters() {
+    let fen =
+        FEN::parse("rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 2+3 0 2").unwrap();
+    assert_eq!(fen.remaining_checks(), Some((2, 3)));
+
+    let board = ChessBoard::from_fen_checked(&fen).unwrap();
+    assert_eq!(board.variant(), Variant::ThreeCheck);
+    assert_eq!(board.checks_given(Side::WHITE), 1);
+    assert_eq!(board.checks_given(Side::BLACK), 0);
+    assert_eq!(
+        FEN::from(&board).to_string(),
+        "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w HAha - 2+3 0 1"
+    );
+
+    //Lichess stores checks already given at the end of the fen
+    let lichess = FEN::parse("rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2 +1+0");
+    assert_eq!(
+        ChessBoard::from_fen_checked(&lichess.unwrap()).unwrap(),
+        board
+    );
+
+    assert_eq!(
+        FEN::parse("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 4+3 0 1"),
+        Err(chess::FenError::InvalidCheckCounters(String::from("4+3")))
+    );
+
+    let mut standard = board;
+    standard.set_variant(Variant::Standard);
+    assert_ne!(standard.hash(), board.hash());
+}
+
+#[test]
+fn three_check_result() {
+    let mut game = Game::from(board(
+        "rnbqkbnr/ppp2ppp/8/3pp3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 1+3 0 3",
+        Variant::ThreeCheck,
+    ));
+    let hash = game.board().hash();
+
+    game.make_move(Move::from_squares(
+        Square::F1,
+        Square::B5,
+        MoveFlag::QUIET_MOVE,
+    ));
+    assert_eq!(game.board().checks_given(Side::WHITE), 3);
+    assert_eq!(
+        game.result(),
+        Some(GameResult::Win(Side::WHITE, WinReason::ThreeChecks))
+    );
+    assert_eq!(FEN::from(game.board()).remaining_checks(), Some((0, 3)));
+
+    game.unmake_move();
+    assert_eq!(game.board().checks_given(Side::WHITE), 2);
+    assert_eq!(game.board().hash(), hash);
+}
+
+#[test]
+fn horde_rules() {
+    let start = Variant::Horde.start_position();
+    assert_eq!(
+        ChessBoard::from_fen_checked(&start),
+        Err(BoardError::InvalidKingCount(Side::WHITE, 0))
+    );
+
+    let board = ChessBoard::from_fen_with_variant(&start, Variant::Horde).unwrap();
+    assert!(!board.is_in_check());
+
+    assert!(!board.is_legal(Move::from_squares(
+        Square::A1,
+        Square::A3,
+        MoveFlag::DOUBLE_PUSH
+    )));
+    assert!(board.is_legal(Move::from_squares(
+        Square::B5,
+        Square::B6,
+        MoveFlag::QUIET_MOVE
+    )));
+
+    //First rank double pushes do not create an en passant square
+    let double_push = Move::from_squares(Square::A1, Square::A3, MoveFlag::DOUBLE_PUSH);
+    let mut open_rank = self::board("4k3/8/8/8/8/8/8/P7 w - - 0 1", Variant::Horde);
+    assert!(open_rank.is_legal(double_push));
+    open_rank.make_move_no_mask(double_push);
+    assert_eq!(open_rank.en_passant_square(), Square::NULL);
+
+    let mut game = Game::from(self::board(
+        "4k3/8/8/8/8/8/3q4/4P3 b - - 0 1",
+        Variant::Horde,
+    ));
+    assert_eq!(game.result(), None);
+
+    game.make_move(Move::from_squares(
+        Square::D2,
+        Square::E1,
+        MoveFlag::CAPTURE,
+    ));
+    assert_eq!(
+        game.result(),
+        Some(GameResult::Win(Side::BLACK, WinReason::HordeCaptured))
+    );
+}
+
+#[test]
+fn crazyhouse_perft() {
+    let start = ChessBoard::try_from(&Variant::Crazyhouse.start_position()).unwrap();
+    assert_eq!(start.variant(), Variant::Crazyhouse);
+    assert_perft(&start, &[20, 400, 8902, 197281, 4888832]);
+
+    let full_pockets = board(
+        "2k5/8/8/8/8/8/8/4K3[QRBNPqrbnp] w - - 0 1",
+        Variant::Crazyhouse,
+    );
+    assert_perft(&full_pockets, &[301, 75353]);
+
+    let promoted = board("4k3/1Q~6/8/8/4b3/8/Kpp5/8/ b - - 0 1", Variant::Crazyhouse);
+    assert_perft(&promoted, &[20, 360, 5445, 132758]);
+
+    let middlegame = board(
+        "r1bqk2r/pppp1ppp/2n1p3/4P3/1b1Pn3/2NB1N2/PPP2PPP/R1BQK2R[] b KQkq - 0 1",
+        Variant::Crazyhouse,
+    );
+    assert_perft(&middlegame, &[42, 1347, 58057]);
+}
+
+#[test]
+fn crazyhouse_pockets() {
+    let fen = FEN::parse("4k3/1Q~6/8/8/4b3/8/Kpp5/8[Rn] b - - 0 1").unwrap();
+    assert_eq!(fen.pocket(), Some("Rn"));
+    assert_eq!(fen.to_string(), "4k3/1Q~6/8/8/4b3/8/Kpp5/8[Rn] b - - 0 1");
+
+    let board = ChessBoard::from_fen_checked(&fen).unwrap();
+    assert_eq!(board.variant(), Variant::Crazyhouse);
+    assert_eq!(board.pocket(Side::WHITE, Piece::ROOK), 1);
+    assert_eq!(board.pocket(Side::BLACK, Piece::KNIGHT), 1);
+    assert!(board.promoted_pieces().get_bit(Square::B7));
+    assert_eq!(
+        FEN::from(&board).to_string(),
+        "4k3/1Q~6/8/8/4b3/8/Kpp5/8[Rn] b - - 0 1"
+    );
+
+    //Pockets written as a ninth rank are accepted as well
+    let ninth_rank = FEN::parse("4k3/1Q~6/8/8/4b3/8/Kpp5/8/Rn b - - 0 1").unwrap();
+    assert_eq!(ChessBoard::from_fen_checked(&ninth_rank).unwrap(), board);
+
+    assert_eq!(
+        FEN::parse("4k3/8/8/8/8/8/8/4K3[Kx] w - - 0 1"),
+        Err(chess::FenError::InvalidPocket(String::from("Kx")))
+    );
+    assert!(FEN::parse("4k3/8/8/8/8/8/8/~4K3[] w - - 0 1").is_err());
+}
+
+#[test]
+fn crazyhouse_moves() {
+    let mut game = Game::from(board(
+        "4k3/1Q~6/8/8/4b3/8/Kpp5/8[Rn] b - - 0 1",
+        Variant::Crazyhouse,
+    ));
+    let hash = game.board().hash();
+
+    //Capturing a promoted queen only puts a pawn in the pocket
+    let capture = game.board().parse_move("e4b7").unwrap();
+    game.make_move(capture);
+    assert_eq!(game.board().pocket(Side::BLACK, Piece::PAWN), 1);
+    assert_eq!(game.board().pocket(Side::BLACK, Piece::QUEEN), 0);
+    assert!(game.board().promoted_pieces().is_empty());
+
+    let drop = game.board().parse_move("R@a8").unwrap();
+    assert_eq!(drop, Move::from_drop(Piece::ROOK, Square::A8));
+    assert!(drop.is_drop() && !drop.is_capture());
+    assert_eq!(drop.to_string(false), "R@a8");
+    assert_eq!(game.board().to_san(drop), "R@a8+");
+    game.make_move(drop);
+    assert_eq!(game.board().pocket(Side::WHITE, Piece::ROOK), 0);
+    assert_eq!(game.board().piece_on_square(Square::A8), Piece::ROOK);
+
+    //Promotions are remembered so the piece returns as a pawn once captured
+    let mut promotion = board("4k3/8/8/8/8/8/Kpp5/8[] b - - 0 1", Variant::Crazyhouse);
+    promotion.make_move_no_mask(Move::from_squares(
+        Square::C2,
+        Square::C1,
+        MoveFlag::QUEEN_PROMOTION,
+    ));
+    assert!(promotion.promoted_pieces().get_bit(Square::C1));
+
+    //Pawns cannot be dropped on the back ranks, and nothing can be dropped on an occupied square
+    let pockets = board("4k3/8/8/8/8/8/8/4K3[Pp] w - - 0 1", Variant::Crazyhouse);
+    assert!(!pockets.is_legal(Move::from_drop(Piece::PAWN, Square::A8)));
+    assert!(!pockets.is_legal(Move::from_drop(Piece::PAWN, Square::A1)));
+    assert!(!pockets.is_legal(Move::from_drop(Piece::PAWN, Square::E1)));
+    assert!(!pockets.is_legal(Move::from_drop(Piece::KNIGHT, Square::A4)));
+    assert!(pockets.is_legal(Move::from_drop(Piece::PAWN, Square::A4)));
+
+    game.unmake_move();
+    game.unmake_move();
+    assert_eq!(game.board().hash(), hash);
+    assert_eq!(game.board().pocket(Side::WHITE, Piece::ROOK), 1);
+    assert!(game.board().promoted_pieces().get_bit(Square::B7));
+}
+
+#[test]
+fn crazyhouse_legality() {
+    for fen in [
+        "2k5/8/8/8/8/8/8/4K3[QRBNPqrbnp] w - - 0 1",
+        "4k3/1Q~6/8/8/4b3/8/Kpp5/8[Rn] b - - 0 1",
+        "r1bqk2r/pppp1ppp/2n1p3/4P3/1b1Pn3/2NB1N2/PPP2PPP/R1BQK2R[Pn] b KQkq - 0 1",
+        "4k3/8/8/8/8/8/8/r3K3[Nn] w - - 0 1",
+    ] {
+        let board = board(fen, Variant::Crazyhouse);
+        let legal_moves = board.legal_moves();
+
+        for value in 0..=u16::MAX {
+            let mv = Move::from(value);
+            assert_eq!(
+                board.is_legal(mv),
+                legal_moves.contains(&mv),
+                "{fen} {mv:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use chess::{ChessBoard, Epd, Side, Variant, FEN};
+
+struct Xorshift(u64);
+impl Xorshift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+fn assert_keys_match_recomputation(board: &ChessBoard) {
+    let fen = FEN::from(board);
+    let recomputed = ChessBoard::from_fen_with_variant(&fen, board.variant()).unwrap();
+
+    assert_eq!(board.hash(), recomputed.hash(), "{fen}");
+    assert_eq!(board.pawn_key(), recomputed.pawn_key(), "{fen}");
+    assert_eq!(board.material_key(), recomputed.material_key(), "{fen}");
+    for side in [Side::WHITE, Side::BLACK] {
+        assert_eq!(
+            board.non_pawn_key(side),
+            recomputed.non_pawn_key(side),
+            "{fen}"
+        );
+    }
+}
+
+fn play_random_moves(board: &ChessBoard, random: &mut Xorshift, plies: usize) {
+    let mut board = *board;
+    for _ in 0..plies {
+        let moves = board.legal_moves();
+        if moves.is_empty() || board.variant_result().is_some() {
+            break;
+        }
+
+        let before = board;
+        let mv = moves[random.next() as usize % moves.len()];
+        let undo_info = board.undo_info(mv);
+        board.make_move_no_mask(mv);
+        assert_keys_match_recomputation(&board);
+
+        let mut undone = board;
+        undone.unmake_move(mv, &undo_info);
+        assert_eq!(undone.pawn_key(), before.pawn_key());
+        assert_eq!(undone.material_key(), before.material_key());
+    }
+}
+
+#[test]
+fn incremental_keys_match_recomputation() {
+    let mut random = Xorshift(0x9E3779B97F4A7C15);
+
+    let file = File::open("./tests/standard.epd").unwrap();
+    for line in BufReader::new(file).lines() {
+        let board = Epd::parse(&line.unwrap()).unwrap().board().unwrap();
+        assert_keys_match_recomputation(&board);
+
+        for _ in 0..4 {
+            play_random_moves(&board, &mut random, 60);
+        }
+    }
+
+    for variant in [Variant::Horde, Variant::Crazyhouse] {
+        let board = ChessBoard::from_fen_with_variant(&variant.start_position(), variant).unwrap();
+        for _ in 0..8 {
+            play_random_moves(&board, &mut random, 100);
+        }
+    }
+}
+
+#[test]
+fn partial_keys() {
+    let start = ChessBoard::try_from(&FEN::start_position()).unwrap();
+
+    //Knight moves leave the pawn structure and material untouched
+    let knights = ChessBoard::try_from(&FEN::from(
+        "rnbqkb1r/pppppppp/5n2/8/8/5N2/PPPPPPPP/RNBQKB1R w KQkq - 2 2",
+    ))
+    .unwrap();
+    assert_eq!(knights.pawn_key(), start.pawn_key());
+    assert_eq!(knights.material_key(), start.material_key());
+    assert_ne!(
+        knights.non_pawn_key(Side::WHITE),
+        start.non_pawn_key(Side::WHITE)
+    );
+
+    //Pawn moves leave the pieces untouched
+    let pawns = ChessBoard::try_from(&FEN::from(
+        "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2",
+    ))
+    .unwrap();
+    assert_ne!(pawns.pawn_key(), start.pawn_key());
+    assert_eq!(pawns.material_key(), start.material_key());
+    assert_eq!(
+        pawns.non_pawn_key(Side::WHITE),
+        start.non_pawn_key(Side::WHITE)
+    );
+    assert_eq!(
+        pawns.non_pawn_key(Side::BLACK),
+        start.non_pawn_key(Side::BLACK)
+    );
+
+    //Same material on different squares shares the material key
+    let rook_a = ChessBoard::try_from(&FEN::from("4k3/8/8/8/8/8/8/R3K3 w - - 0 1")).unwrap();
+    let rook_h = ChessBoard::try_from(&FEN::from("4k3/8/8/8/8/8/8/4K2R w - - 0 1")).unwrap();
+    let queen = ChessBoard::try_from(&FEN::from("4k3/8/8/8/8/8/8/Q3K3 w - - 0 1")).unwrap();
+    assert_eq!(rook_a.material_key(), rook_h.material_key());
+    assert_ne!(rook_a.material_key(), queen.material_key());
+    assert_ne!(
+        rook_a.non_pawn_key(Side::WHITE),
+        rook_h.non_pawn_key(Side::WHITE)
+    );
+}
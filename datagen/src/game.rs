@@ -1,11 +1,10 @@
-use chess::{ChessPosition, Move, MoveFlag, Side, Square, FEN};
+use chess::{ChessPosition, Move, MoveFlag, Side, Square};
 use engine::{NoReport, SearchEngine, SearchLimits};
-use montyformat::{
-    chess::{Castling, Position},
-    MontyFormat, SearchData,
-};
+use montyformat::{MontyFormat, SearchData};
 use rand::Rng;
 
+use crate::monty_position::{board_from_monty, board_to_monty};
+
 pub fn play_game(
     engine: &mut SearchEngine,
     position: &mut ChessPosition,
@@ -14,9 +13,11 @@ pub fn play_game(
 ) -> MontyFormat {
     let castle_mask = position.board().castle_rights().get_castle_mask();
 
-    let mut monty_castling = Castling::default();
-    let fen = FEN::from(position.board()).to_string();
-    let monty_position = Position::parse_fen(fen.as_str(), &mut monty_castling);
+    let (monty_position, monty_castling) = board_to_monty(position.board());
+    debug_assert_eq!(
+        board_from_monty(&monty_position, &monty_castling).map(|board| board.hash()),
+        Ok(position.board().hash())
+    );
     let mut game_data = MontyFormat::new(monty_position, monty_castling);
 
     let mut temperature = 0.77;
@@ -95,27 +96,8 @@ pub fn play_game(
         position.make_move(best_move, &castle_mask);
         temperature *= 0.91;
 
-        let mut no_legal_moves = true;
-        position.board().map_legal_moves(|_| no_legal_moves = false);
-
-        if no_legal_moves {
-            game_data.result = if position.board().is_in_check() {
-                if position.board().side() == Side::WHITE {
-                    0.0
-                } else {
-                    1.0
-                }
-            } else {
-                0.5
-            };
-
-            break;
-        }
-
-        if position.board().half_moves() >= 100
-            || position.history().get_repetitions(position.board().hash()) >= 3
-        {
-            game_data.result = 0.5;
+        if let Some(result) = position.result_with_claims() {
+            game_data.result = result.white_score();
             break;
         }
     }
@@ -6,7 +6,7 @@ use std::{
     time::Duration,
 };
 
-use chess::{ChessBoard, ChessPosition, FEN};
+use chess::{ChessBoard, ChessPosition, Epd, FEN};
 use crossbeam::queue::SegQueue;
 use engine::{SearchEngine, SearchLimits};
 use rand::Rng;
@@ -15,12 +15,15 @@ use utils::{clear_terminal_screen, create_loading_bar, WHITE};
 use crate::game::play_game;
 
 mod game;
+mod monty_position;
 
 fn main() {
     let args: Vec<String> = env::args().collect();
     let mut threads = 1usize;
     let mut target_positions = 100_000_000usize;
     let output_path = "./policy_data.bin".to_string();
+    let mut book_path = "./resources/books/DFRC.epd".to_string();
+    let mut explicit_book = false;
 
     for i in 0..args.len() {
         match args[i].as_str() {
@@ -34,6 +37,12 @@ fn main() {
                     target_positions = v.parse().unwrap_or(100) * 1_000_000;
                 }
             }
+            "--book" => {
+                if let Some(v) = args.get(i + 1) {
+                    book_path = v.clone();
+                    explicit_book = true;
+                }
+            }
             _ => {}
         }
     }
@@ -44,12 +53,28 @@ fn main() {
         .open(output_path)
         .expect("Cannot open file");
 
-    let openings = std::io::BufReader::new(
-        File::open("./resources/books/DFRC.epd").expect("Book does not exist!"),
-    )
-    .lines()
-    .map(|line| line.unwrap())
-    .collect::<Vec<String>>();
+    //Without the default book every game starts from a random double fischer random position,
+    //a book passed on the command line has to load
+    let (openings, book_status) = match load_book(&book_path) {
+        Ok(book) if !book.openings.is_empty() => {
+            let status = book.status(&book_path);
+            (book.openings, status)
+        }
+        Ok(book) if explicit_book => {
+            println!(
+                "Book {book_path} has no valid positions, {}",
+                book.skipped_lines()
+            );
+            std::process::exit(1);
+        }
+        Err(err) if explicit_book => {
+            println!("Cannot read book {book_path}: {err}");
+            std::process::exit(1);
+        }
+        _ => (Vec::new(), String::from("random double fischer random")),
+    };
+
+    println!("Book: {book_status}");
 
     let mut limits = SearchLimits::default();
     limits.set_iters(Some(99999));
@@ -70,8 +95,16 @@ fn main() {
                 let mut rng = rand::rng();
 
                 loop {
-                    let fen = FEN::from(openings[rng.random_range(0..openings.len())].clone());
-                    let mut new_position = ChessPosition::from(ChessBoard::from(&fen));
+                    let opening = if openings.is_empty() {
+                        ChessBoard::try_from(&FEN::double_fischer_random(
+                            rng.random_range(0..960),
+                            rng.random_range(0..960),
+                        ))
+                        .expect("Invalid double fischer random position")
+                    } else {
+                        openings[rng.random_range(0..openings.len())]
+                    };
+                    let mut new_position = ChessPosition::from(opening);
 
                     let mut avg_iters = 0u64;
                     let game = play_game(&mut engine, &mut new_position, &limits, &mut avg_iters);
@@ -108,6 +141,7 @@ fn main() {
                 create_loading_bar(40, positions as f32 / target_positions as f32, WHITE, WHITE)
             );
             println!("Threads:    {}", threads);
+            println!("Book:       {book_status}");
             println!("Avg. Nodes: {:.1}", datagen_stats.avg_iterations());
             println!(
                 "Target:     {}\n",
@@ -144,6 +178,64 @@ fn main() {
     });
 }
 
+struct Book {
+    openings: Vec<ChessBoard>,
+    skipped: usize,
+    first_skipped: Option<(usize, String)>,
+}
+
+impl Book {
+    fn status(&self, path: &str) -> String {
+        format!(
+            "{path} ({} openings, {})",
+            self.openings.len(),
+            self.skipped_lines()
+        )
+    }
+
+    fn skipped_lines(&self) -> String {
+        match &self.first_skipped {
+            Some((line, err)) => {
+                format!(
+                    "{} lines skipped, first at line {line}: {err}",
+                    self.skipped
+                )
+            }
+            None => String::from("0 lines skipped"),
+        }
+    }
+}
+
+fn load_book(path: &str) -> std::io::Result<Book> {
+    let mut book = Book {
+        openings: Vec::new(),
+        skipped: 0,
+        first_skipped: None,
+    };
+
+    let reader = std::io::BufReader::new(File::open(path)?);
+    for (line_idx, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let board = Epd::parse(&line)
+            .map_err(|err| err.to_string())
+            .and_then(|epd| epd.board().map_err(|err| err.to_string()));
+
+        match board {
+            Ok(board) => book.openings.push(board),
+            Err(err) => {
+                book.skipped += 1;
+                book.first_skipped.get_or_insert((line_idx + 1, err));
+            }
+        }
+    }
+
+    Ok(book)
+}
+
 struct DatagenStats {
     positions: AtomicU64,
     games: AtomicU64,
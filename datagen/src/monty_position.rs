@@ -0,0 +1,45 @@
+use chess::{BoardError, ChessBoard, Square, FEN};
+use montyformat::chess::{Castling, Position, Right};
+
+//montyformat keeps the castling setup outside of the position, so both are returned
+pub fn board_to_monty(board: &ChessBoard) -> (Position, Castling) {
+    let mut castling = Castling::default();
+    let fen = FEN::from(board).to_string();
+    let position = Position::parse_fen(fen.as_str(), &mut castling);
+    (position, castling)
+}
+
+//as_fen always writes KQkq castling and no en passant square, so both are rebuilt from
+//the rook files and the position
+pub fn board_from_monty(
+    position: &Position,
+    castling: &Castling,
+) -> Result<ChessBoard, BoardError> {
+    let fen = position.as_fen();
+    let mut fields = fen.split_whitespace().collect::<Vec<_>>();
+
+    let rights = castle_rights(position, castling);
+    fields[2] = if rights.is_empty() { "-" } else { &rights };
+
+    let en_passant = Square::from(position.enp_sq()).to_string();
+    if position.enp_sq() > 0 {
+        fields[3] = &en_passant;
+    }
+
+    ChessBoard::try_from(fields.join(" ").as_str())
+}
+
+fn castle_rights(position: &Position, castling: &Castling) -> String {
+    let rook_files = castling.rook_files();
+
+    [
+        (Right::WKS, rook_files[0][1], b'A'),
+        (Right::WQS, rook_files[0][0], b'A'),
+        (Right::BKS, rook_files[1][1], b'a'),
+        (Right::BQS, rook_files[1][0], b'a'),
+    ]
+    .into_iter()
+    .filter(|&(right, _, _)| position.rights() & right != 0)
+    .map(|(_, file, first)| (first + file) as char)
+    .collect()
+}
@@ -0,0 +1,58 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use chess::{ChessBoard, Epd, FEN};
+
+#[path = "../src/monty_position.rs"]
+mod monty_position;
+
+use monty_position::{board_from_monty, board_to_monty};
+
+fn load_boards(path: &str) -> Vec<ChessBoard> {
+    let file = File::open(path).unwrap();
+    BufReader::new(file)
+        .lines()
+        .map(|line| Epd::parse(&line.unwrap()).unwrap().board().unwrap())
+        .collect()
+}
+
+fn assert_round_trip(board: &ChessBoard) {
+    let (position, castling) = board_to_monty(board);
+    let converted = board_from_monty(&position, &castling).unwrap();
+
+    assert_eq!(
+        converted.hash(),
+        board.hash(),
+        "{} became {}",
+        FEN::from(board),
+        FEN::from(&converted)
+    );
+}
+
+#[test]
+fn standard_round_trip() {
+    for board in load_boards("../chess/tests/standard.epd") {
+        assert_round_trip(&board);
+
+        board.map_legal_moves(|mv| {
+            let mut board_copy = board;
+            board_copy.make_move_no_mask(mv);
+            assert_round_trip(&board_copy);
+        });
+    }
+}
+
+#[test]
+fn fischer_round_trip() {
+    for board in load_boards("../chess/tests/fischer.epd") {
+        assert_round_trip(&board);
+
+        board.map_legal_moves(|mv| {
+            let mut board_copy = board;
+            board_copy.make_move_no_mask(mv);
+            assert_round_trip(&board_copy);
+        });
+    }
+}
@@ -1,5 +1,6 @@
 mod networks;
 mod search_engine;
+mod search_observer;
 mod search_report_trait;
 
 pub use networks::BasePolicyNetwork;
@@ -12,14 +13,23 @@ pub use networks::Stage2PolicyNetwork;
 pub use networks::Stage3PolicyNetwork;
 pub use networks::ValueNetwork;
 pub use search_engine::AtomicWDLScore;
+pub use search_engine::BestMoveSelection;
 pub use search_engine::GameState;
+pub use search_engine::MemoryManager;
 pub use search_engine::Node;
 pub use search_engine::NodeIndex;
+pub use search_engine::NumaPolicy;
 pub use search_engine::PvLine;
+pub use search_engine::ScoreBound;
 pub use search_engine::SearchEngine;
+pub use search_engine::SearchError;
+pub use search_engine::SearchHandle;
 pub use search_engine::SearchLimits;
+pub use search_engine::SearchResult;
+pub use search_engine::SearchSnapshot;
 pub use search_engine::SearchStats;
 pub use search_engine::Tree;
 pub use search_engine::WDLScore;
+pub use search_observer::SearchObserver;
 pub use search_report_trait::NoReport;
 pub use search_report_trait::SearchReport;
@@ -152,6 +152,9 @@ fn map_move_to_index(board: &ChessBoard, mv: Move, see: bool, _chess960: bool) -
         let is_ks = usize::from(mv.flag() == MoveFlag::KING_SIDE_CASTLE);
         let is_hm = usize::from(hm == 0);
         OFFSETS[5][64] + PROMOS + (is_ks ^ is_hm)
+    } else if mv.is_drop() {
+        //Drops share the slot of the first move of that piece from the target square
+        OFFSETS[usize::from(mv.drop_piece())][dst]
     } else if mv.flag() == MoveFlag::DOUBLE_PUSH {
         OFFSETS[5][64] + PROMOS + 2 + (src % 8)
     } else {
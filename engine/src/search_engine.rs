@@ -1,39 +1,112 @@
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::{
+    fmt::{Display, Formatter},
+    ops::Deref,
+    sync::atomic::{AtomicBool, Ordering},
+    thread,
+    time::Duration,
+};
 
-use chess::{ChessBoard, ChessPosition, FEN};
+use chess::{ChessBoard, ChessPosition, Game, Variant, FEN};
 
-use crate::{search_engine::engine_options::EngineOptions, search_report_trait::SearchReport};
+use crate::{
+    search_engine::{engine_options::EngineOptions, lockstep::Lockstep},
+    search_observer::{ReportObserver, SearchObserver},
+    search_report_trait::SearchReport,
+};
 
 mod bench;
+mod best_move_selection;
 mod butterfly_history;
+mod capture_history;
+mod continuation_history;
 mod engine_options;
 mod hash_table;
+mod history_table;
+mod large_buffer;
+mod lockstep;
 mod mcts;
+mod memory_manager;
+mod numa;
+mod search_handle;
 mod search_limits;
 mod search_stats;
 mod tree;
 
+pub use best_move_selection::BestMoveSelection;
+pub use memory_manager::MemoryManager;
+pub use numa::NumaPolicy;
+pub use search_handle::{SearchHandle, SearchResult, SearchSnapshot};
 pub use search_limits::SearchLimits;
 pub use search_stats::SearchStats;
-pub use tree::{AtomicWDLScore, GameState, Node, NodeIndex, PvLine, Tree, WDLScore};
+pub use tree::{AtomicWDLScore, GameState, Node, NodeIndex, PvLine, ScoreBound, Tree, WDLScore};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchError {
+    AlreadyRunning,
+}
+
+impl Display for SearchError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SearchError::AlreadyRunning => write!(formatter, "search is already running"),
+        }
+    }
+}
+
+impl std::error::Error for SearchError {}
+
+//Marks the engine as searching for as long as it lives, so a shared engine cannot run a
+//handle and a search at the same time on one tree and one interruption token. Dropping it
+//clears the mark even when the search unwinds
+struct SearchGuard<Engine: Deref<Target = SearchEngine>>(Engine);
+
+impl<Engine: Deref<Target = SearchEngine>> SearchGuard<Engine> {
+    fn new(search_engine: Engine) -> Result<Self, SearchError> {
+        if search_engine.searching.swap(true, Ordering::Acquire) {
+            return Err(SearchError::AlreadyRunning);
+        }
+
+        Ok(Self(search_engine))
+    }
+}
+
+impl<Engine: Deref<Target = SearchEngine>> Deref for SearchGuard<Engine> {
+    type Target = SearchEngine;
+
+    fn deref(&self) -> &SearchEngine {
+        &self.0
+    }
+}
+
+impl<Engine: Deref<Target = SearchEngine>> Drop for SearchGuard<Engine> {
+    fn drop(&mut self) {
+        self.0.searching.store(false, Ordering::Release);
+    }
+}
 
 #[derive(Debug)]
 pub struct SearchEngine {
     position: ChessPosition,
+    game: Game,
     tree: Tree,
     options: EngineOptions,
     interruption_token: AtomicBool,
+    searching: AtomicBool,
     game_ply: u16,
+    lockstep: Lockstep,
 }
 
 impl Clone for SearchEngine {
     fn clone(&self) -> Self {
         Self {
             position: self.position,
+            game: self.game.clone(),
             tree: self.tree.clone(),
             options: self.options.clone(),
             interruption_token: AtomicBool::new(self.interruption_token.load(Ordering::Relaxed)),
+            searching: AtomicBool::new(false),
             game_ply: self.game_ply,
+            lockstep: Lockstep::new(),
         }
     }
 }
@@ -47,13 +120,17 @@ impl Default for SearchEngine {
 impl SearchEngine {
     pub fn new() -> Self {
         let options = EngineOptions::new();
+        let board = ChessBoard::try_from(&FEN::start_position()).expect("Invalid start position");
 
         Self {
-            position: ChessPosition::from(ChessBoard::from(&FEN::start_position())),
+            position: ChessPosition::from(board),
+            game: Game::from(board),
             tree: Tree::from_bytes(options.hash() as usize, &options),
             options,
             interruption_token: AtomicBool::new(false),
+            searching: AtomicBool::new(false),
             game_ply: 0,
+            lockstep: Lockstep::new(),
         }
     }
 
@@ -62,6 +139,11 @@ impl SearchEngine {
         &self.position
     }
 
+    #[inline]
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
     #[inline]
     pub fn tree(&self) -> &Tree {
         &self.tree
@@ -95,12 +177,25 @@ impl SearchEngine {
     #[inline]
     pub fn set_position(&mut self, position: &ChessPosition, game_ply: u16) {
         self.position = *position;
+        self.game = Game::from(*position.board());
         self.game_ply = game_ply;
     }
 
+    #[inline]
+    pub fn set_game(&mut self, game: &Game) {
+        self.position = game.position();
+        self.game = game.clone();
+        self.game_ply = game.ply() as u16;
+    }
+
     #[inline]
     pub fn reset_position(&mut self) {
-        self.position = ChessPosition::from(ChessBoard::from(&FEN::start_position()));
+        let variant = Variant::parse(&self.options.uci_variant()).unwrap_or_default();
+        let board = ChessBoard::from_fen_with_variant(&variant.start_position(), variant)
+            .expect("Invalid start position");
+
+        self.position = ChessPosition::from(board);
+        self.game = Game::from(board);
         self.game_ply = 0;
     }
 
@@ -114,9 +209,34 @@ impl SearchEngine {
         self.interruption_token.load(Ordering::Relaxed)
     }
 
+    //Waits for a search running on a handle to end before starting this one
     pub fn search<Display: SearchReport>(&self, search_limits: &SearchLimits) -> SearchStats {
+        let mut observer = ReportObserver::<Display>::default();
+        loop {
+            if let Ok(result) = self.search_with_observer(search_limits, &mut observer) {
+                return result;
+            }
+
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    pub fn search_with_observer(
+        &self,
+        search_limits: &SearchLimits,
+        observer: &mut dyn SearchObserver,
+    ) -> Result<SearchStats, SearchError> {
+        let search_guard = SearchGuard::new(self)?;
         self.interruption_token.store(false, Ordering::Relaxed);
 
+        Ok(search_guard.run_search(search_limits, observer))
+    }
+
+    fn run_search(
+        &self,
+        search_limits: &SearchLimits,
+        observer: &mut dyn SearchObserver,
+    ) -> SearchStats {
         if self.tree().root_node().children_count() == 0 {
             self.tree().expand_node(
                 self.tree().root_index(),
@@ -127,12 +247,12 @@ impl SearchEngine {
             );
         }
 
-        Display::search_started(search_limits, self);
+        observer.on_search_started(search_limits, self);
 
-        let result = self.mcts::<Display>(search_limits);
+        let result = self.mcts(search_limits, observer);
 
-        Display::search_report(search_limits, &result, self);
-        Display::search_ended(search_limits, &result, self);
+        observer.on_stats(search_limits, &result, self);
+        observer.on_search_ended(search_limits, &result, self);
 
         result
     }
@@ -1,6 +1,6 @@
 use std::time::{Duration, Instant};
 
-use chess::{ChessBoard, ChessPosition, FEN};
+use chess::{ChessBoard, ChessPosition};
 
 use crate::{search_report_trait::NoReport, SearchEngine, SearchLimits};
 
@@ -69,8 +69,7 @@ impl SearchEngine {
         let mut nodes = 0u64;
 
         for fen in BENCH_FENS {
-            let fen = FEN::from(fen);
-            let board = ChessBoard::from(&fen);
+            let board = ChessBoard::try_from(fen).expect("Invalid bench position");
             self.tree().clear();
             self.set_position(&ChessPosition::from(board), 0);
 
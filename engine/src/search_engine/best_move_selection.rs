@@ -0,0 +1,149 @@
+use std::{
+    fmt::{Display, Formatter, Result},
+    str::FromStr,
+};
+
+use crate::{search_engine::engine_options::EngineOptions, GameState, Node, WDLScore};
+
+//How the final move and the MultiPV lines are picked from the children of a node.
+//Visits takes the most searched move, Q the best average score, LCB the best lower
+//confidence bound of the score and aggressive the score blended with the sacrifice,
+//pawn push and castling bonuses
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum BestMoveSelection {
+    Visits,
+    Q,
+    Lcb,
+    #[default]
+    Aggressive,
+}
+
+impl FromStr for BestMoveSelection {
+    type Err = String;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "visits" => Ok(BestMoveSelection::Visits),
+            "q" => Ok(BestMoveSelection::Q),
+            "lcb" => Ok(BestMoveSelection::Lcb),
+            "aggressive" => Ok(BestMoveSelection::Aggressive),
+            _ => Err(format!("Unknown best move selection '{value}'")),
+        }
+    }
+}
+
+impl Display for BestMoveSelection {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        let name = match self {
+            BestMoveSelection::Visits => "visits",
+            BestMoveSelection::Q => "q",
+            BestMoveSelection::Lcb => "lcb",
+            BestMoveSelection::Aggressive => "aggressive",
+        };
+
+        write!(formatter, "{name}")
+    }
+}
+
+impl BestMoveSelection {
+    //Higher is better. Proven results are ordered by mate distance ahead of every
+    //strategy score, which all stay within [-1, 2], and proven draws of the score based
+    //strategies score exactly a draw
+    pub fn child_key(
+        &self,
+        node: &Node,
+        parent: &Node,
+        draw_score: f64,
+        options: &EngineOptions,
+    ) -> f64 {
+        match node.state() {
+            GameState::Loss(x) => return 256.0 - x as f64,
+            GameState::Win(x) => return -256.0 + x as f64,
+            GameState::Draw if matches!(self, BestMoveSelection::Q | BestMoveSelection::Lcb) => {
+                return draw_score
+            }
+            _ => (),
+        }
+
+        match self {
+            BestMoveSelection::Visits => {
+                f64::from(node.visits()) / f64::from(parent.visits().max(1))
+            }
+            BestMoveSelection::Q => node.score().single_with_score(draw_score),
+            BestMoveSelection::Lcb => {
+                if node.visits() == 0 {
+                    return -1.0;
+                }
+
+                node.score().single_with_score(draw_score)
+                    - options.confidence_z() * node.standard_error()
+            }
+            BestMoveSelection::Aggressive => aggressive_score(
+                node.score().single_with_score(draw_score),
+                node,
+                parent.score().reversed(),
+                5,
+                f64::from(node.pawn_push_strength()).sqrt(),
+                options,
+            ),
+        }
+    }
+
+    //Order of the MultiPV lines. The aggressive lines use a softer sacrifice ramp and
+    //linear pawn pushes, with the bonuses added to proven results as well
+    pub fn pv_key(
+        &self,
+        node: &Node,
+        parent: &Node,
+        draw_score: f64,
+        options: &EngineOptions,
+    ) -> f64 {
+        if *self != BestMoveSelection::Aggressive {
+            return self.child_key(node, parent, draw_score, options);
+        }
+
+        let score = match node.state() {
+            GameState::Loss(x) => 256.0 - x as f64,
+            GameState::Win(x) => -256.0 + x as f64,
+            _ => node.score().single_with_score(draw_score),
+        };
+
+        aggressive_score(
+            score,
+            node,
+            parent.score().reversed(),
+            2,
+            f64::from(node.pawn_push_strength()),
+            options,
+        )
+    }
+}
+
+fn aggressive_score(
+    mut score: f64,
+    node: &Node,
+    parent_score: WDLScore,
+    ramp_power: i32,
+    pawn_push: f64,
+    options: &EngineOptions,
+) -> f64 {
+    if node.sac_strength() != 0 && parent_score.single() > 0.4 && parent_score.single() < 0.9 {
+        let below_ramp = (((parent_score.single() - 0.4) / (0.51 - 0.4)).min(1.0)).powi(ramp_power);
+        let sac_multiplier =
+            below_ramp * (1.0 + (parent_score.single() - 0.75).max(0.0) * options.sac_scaling());
+        score +=
+            (options.selection_sac_bonus() + node.sac_strength() as f64 / 20000.0) * sac_multiplier;
+    }
+
+    score += pawn_push * options.selection_pawn_push_bonus();
+
+    if node.is_king_opposite_sides() {
+        score += options.selection_castle_bonus();
+    }
+
+    if node.is_queen_trade() {
+        score -= options.selection_queen_trade_penalty();
+    }
+
+    score
+}
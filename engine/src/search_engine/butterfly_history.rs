@@ -1,22 +1,12 @@
-use std::sync::atomic::{AtomicI16, Ordering};
-
 use chess::{Move, Side};
 
-use crate::{search_engine::engine_options::EngineOptions, WDLScore};
-
-#[derive(Debug)]
-pub struct ButterflyHistory(Vec<AtomicI16>);
+use crate::{
+    search_engine::{engine_options::EngineOptions, history_table::HistoryTable},
+    WDLScore,
+};
 
-impl Clone for ButterflyHistory {
-    fn clone(&self) -> Self {
-        Self(
-            self.0
-                .iter()
-                .map(|x| AtomicI16::new(x.load(Ordering::Relaxed)))
-                .collect(),
-        )
-    }
-}
+#[derive(Debug, Clone)]
+pub struct ButterflyHistory(HistoryTable);
 
 impl Default for ButterflyHistory {
     fn default() -> Self {
@@ -26,49 +16,26 @@ impl Default for ButterflyHistory {
 
 impl ButterflyHistory {
     pub fn new() -> Self {
-        Self((0..8192).map(|_| AtomicI16::new(0)).collect())
+        Self(HistoryTable::new(8192))
     }
 
     pub fn clear(&self) {
-        for entry in &self.0 {
-            entry.store(0, Ordering::Relaxed);
-        }
+        self.0.clear()
     }
 
     pub fn get_bonus(&self, side: Side, mv: Move, options: &EngineOptions) -> f64 {
-        f64::from(self.entry(side, mv).load(Ordering::Relaxed)) / options.butterfly_bonus_scale()
+        f64::from(self.0.get(Self::index(side, mv))) / options.butterfly_bonus_scale()
     }
 
     pub fn update_entry(&self, side: Side, mv: Move, score: WDLScore, options: &EngineOptions) {
-        let score =
-            (-400.0 * ((1.0 / score.single().clamp(0.001, 0.999)) - 1.0).ln()).round() as i32;
-        let entry = self.entry(side, mv);
-
-        let mut current_entry = entry.load(Ordering::Relaxed);
-        loop {
-            let delta = scale_bonus(
-                current_entry,
-                score,
-                options.butterfly_reduction_factor() as i32,
-            );
-            let new = current_entry.saturating_add(delta);
-            match entry.compare_exchange(current_entry, new, Ordering::Relaxed, Ordering::Relaxed) {
-                Ok(_) => break,
-                Err(actual) => current_entry = actual,
-            }
-        }
+        self.0.update(
+            Self::index(side, mv),
+            score,
+            options.butterfly_reduction_factor(),
+        )
     }
 
-    fn entry(&self, side: Side, mv: Move) -> &AtomicI16 {
-        &self.0[usize::from(side) * 4096
-            + usize::from(mv.from_square()) * 64
-            + usize::from(mv.to_square())]
+    fn index(side: Side, mv: Move) -> usize {
+        usize::from(side) * 4096 + usize::from(mv.from_square()) * 64 + usize::from(mv.to_square())
     }
 }
-
-fn scale_bonus(score: i16, bonus: i32, reduction_factor: i32) -> i16 {
-    let bonus = bonus.clamp(i16::MIN as i32, i16::MAX as i32);
-    let reduction = i32::from(score) * bonus.abs() / reduction_factor;
-    let adjusted = bonus - reduction;
-    adjusted.clamp(i16::MIN as i32, i16::MAX as i32) as i16
-}
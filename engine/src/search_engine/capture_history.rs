@@ -0,0 +1,78 @@
+use chess::{ChessBoard, Move, Piece, Side};
+
+use crate::{
+    search_engine::{engine_options::EngineOptions, history_table::HistoryTable},
+    WDLScore,
+};
+
+//History of captures indexed by attacker, destination and victim
+#[derive(Debug, Clone)]
+pub struct CaptureHistory(HistoryTable);
+
+impl Default for CaptureHistory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CaptureHistory {
+    pub fn new() -> Self {
+        Self(HistoryTable::new(2 * 6 * 64 * 6))
+    }
+
+    pub fn clear(&self) {
+        self.0.clear()
+    }
+
+    pub fn get_bonus(
+        &self,
+        side: Side,
+        attacker: Piece,
+        mv: Move,
+        victim: Piece,
+        options: &EngineOptions,
+    ) -> f64 {
+        if !options.capture_history() || victim == Piece::NONE {
+            return 0.0;
+        }
+
+        f64::from(self.0.get(Self::index(side, attacker, mv, victim)))
+            / options.capture_bonus_scale()
+    }
+
+    pub fn update_entry(
+        &self,
+        side: Side,
+        attacker: Piece,
+        mv: Move,
+        victim: Piece,
+        score: WDLScore,
+        options: &EngineOptions,
+    ) {
+        if !options.capture_history() || victim == Piece::NONE {
+            return;
+        }
+
+        self.0.update(
+            Self::index(side, attacker, mv, victim),
+            score,
+            options.capture_reduction_factor(),
+        )
+    }
+
+    //Piece captured by the move, NONE for quiet moves
+    pub fn victim(board: &ChessBoard, mv: Move) -> Piece {
+        if mv.is_en_passant() {
+            Piece::PAWN
+        } else if mv.is_capture() {
+            board.piece_on_square(mv.to_square())
+        } else {
+            Piece::NONE
+        }
+    }
+
+    fn index(side: Side, attacker: Piece, mv: Move, victim: Piece) -> usize {
+        ((usize::from(side) * 6 + usize::from(attacker)) * 64 + usize::from(mv.to_square())) * 6
+            + usize::from(victim)
+    }
+}
@@ -0,0 +1,93 @@
+use chess::{ChessBoard, Move, Piece, Side, Square};
+
+use crate::{
+    search_engine::{engine_options::EngineOptions, history_table::HistoryTable},
+    WDLScore,
+};
+
+const PIECE_SQUARES: usize = 6 * 64;
+
+//Piece-to history of a move indexed by the piece-to of the move that led to the position
+#[derive(Debug, Clone)]
+pub struct ContinuationHistory(HistoryTable);
+
+impl Default for ContinuationHistory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ContinuationHistory {
+    pub fn new() -> Self {
+        Self(HistoryTable::new(2 * PIECE_SQUARES * PIECE_SQUARES))
+    }
+
+    pub fn clear(&self) {
+        self.0.clear()
+    }
+
+    pub fn get_bonus(
+        &self,
+        side: Side,
+        parent: Option<(Piece, Square)>,
+        piece: Piece,
+        mv: Move,
+        options: &EngineOptions,
+    ) -> f64 {
+        if !options.continuation_history() {
+            return 0.0;
+        }
+
+        let Some(parent) = parent else {
+            return 0.0;
+        };
+
+        f64::from(self.0.get(Self::index(side, parent, piece, mv)))
+            / options.continuation_bonus_scale()
+    }
+
+    pub fn update_entry(
+        &self,
+        side: Side,
+        parent: Option<(Piece, Square)>,
+        piece: Piece,
+        mv: Move,
+        score: WDLScore,
+        options: &EngineOptions,
+    ) {
+        if !options.continuation_history() {
+            return;
+        }
+
+        if let Some(parent) = parent {
+            self.0.update(
+                Self::index(side, parent, piece, mv),
+                score,
+                options.continuation_reduction_factor(),
+            )
+        }
+    }
+
+    //Piece and destination of the move that led to the board, None when it is unknown
+    pub fn parent_key(board: &ChessBoard, parent_mv: Move) -> Option<(Piece, Square)> {
+        if parent_mv == Move::NULL {
+            return None;
+        }
+
+        if parent_mv.is_castle() {
+            let square = board.king_square(board.side().flipped());
+            return Some((Piece::KING, square));
+        }
+
+        let piece = board.piece_on_square(parent_mv.to_square());
+        (piece != Piece::NONE).then_some((piece, parent_mv.to_square()))
+    }
+
+    fn index(side: Side, parent: (Piece, Square), piece: Piece, mv: Move) -> usize {
+        let (parent_piece, parent_square) = parent;
+        let parent_idx = usize::from(parent_piece) * 64 + usize::from(parent_square);
+        let child_idx = usize::from(piece) * 64 + usize::from(mv.to_square());
+
+        (usize::from(side) * PIECE_SQUARES + parent_idx) * PIECE_SQUARES + child_idx
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{create_options, WDLScore};
+use crate::{create_options, BestMoveSelection, MemoryManager, NumaPolicy, WDLScore};
 
 mod macros;
 
@@ -8,10 +8,20 @@ create_options! {
             //====== General ======
             ["Hash"]         hash:          i64   =>  32,  1,  524288;
             ["Threads"]      threads:       i64   =>  1,   1,  1024;
+            ["LargePages"]   large_pages:   bool  =>  false;
+            ["NumaPolicy"]   numa_policy:   NumaPolicy  =>  NumaPolicy::None;
+            ["MemoryManager"] memory_manager: MemoryManager  =>  MemoryManager::Halves;
             ["MoveOverhead"] move_overhead: i64   =>  10,  0,  2000;
+            ["ConfidenceStop"] confidence_stop: bool  =>  false;
             ["MultiPV"]      multi_pv:      i64   =>  1,   1,  218;
             ["UCI_Chess960"] chess960:      bool  =>  false;
             ["UCI_ShowWDL"]  show_wdl:      bool  =>  false;
+            ["UCI_Variant"]  uci_variant:   String  =>  String::from("chess");
+            ["BestMoveSelection"] best_move_selection: BestMoveSelection  =>  BestMoveSelection::Aggressive;
+
+            //====== History ======
+            ["ContinuationHistory"] continuation_history: bool  =>  false;
+            ["CaptureHistory"]      capture_history:      bool  =>  false;
 
             //======== EAS ========
             ["UCI_Opponent"]  uci_opponent:   String  =>  String::from("");
@@ -19,8 +29,10 @@ create_options! {
             ["Contempt"]      min_contempt:   i64     =>  300,    -1000,  1000;
 
             //======= Debug =======
-            ["MinimalPrint"] minimal_print:  bool  =>  false;
-            ["ItersAsNodes"] iters_as_nodes: bool  =>  false;
+            ["MinimalPrint"]   minimal_print:   bool  =>  false;
+            ["ItersAsNodes"]   iters_as_nodes:  bool  =>  false;
+            ["ShowConfidence"] show_confidence: bool  =>  false;
+            ["Deterministic"]  deterministic:   bool  =>  false;
         }
         Tunables {
             //PST
@@ -66,6 +78,14 @@ create_options! {
             butterfly_reduction_factor: i64 => 9640,     1,    65536,     1024,    0.002;
             butterfly_bonus_scale:      f64 => 16850.0,  1.0,  131072.0,  2048.0,  0.002;
 
+            //Continuation history
+            continuation_reduction_factor: i64 => 9640,     1,    65536,     1024,    0.002;
+            continuation_bonus_scale:      f64 => 24000.0,  1.0,  131072.0,  2048.0,  0.002;
+
+            //Capture history
+            capture_reduction_factor: i64 => 9640,     1,    65536,     1024,    0.002;
+            capture_bonus_scale:      f64 => 16850.0,  1.0,  131072.0,  2048.0,  0.002;
+
             //Draw Scaling
             power_50mr:          f64  =>  3.0,     1.0,  10.0,  0.3,     0.002;
             cap_50mr:            f64  =>  0.9,     0.0,  1.0,   0.08,    0.002;
@@ -153,6 +173,10 @@ create_options! {
             value_stage_left_ramp:           f64  =  0.1;
             value_stage_right_ramp:          f64  =  0.1;
             value_stage_pmax:                f64  =  0.85;
+            confidence_z:                    f64  =  1.96;
+            confidence_stop_z:               f64  =  3.0;
+            confidence_stop_min_time:        f64  =  0.5;
+            lru_eviction_batch:              f64  =  0.05;
         }
         Variables {
             contempt: i64  =  0;
@@ -169,3 +193,34 @@ impl EngineOptions {
         self.draw_score() + (0.5 - self.draw_score()) * blend
     }
 }
+
+//How an option is declared to the GUI, combo options also list their values
+pub trait UciOptionType {
+    const UCI_TYPE: &'static str = "string";
+    const VARS: &'static [&'static str] = &[];
+}
+
+impl UciOptionType for bool {
+    const UCI_TYPE: &'static str = "check";
+}
+
+impl UciOptionType for i64 {
+    const UCI_TYPE: &'static str = "spin";
+}
+
+impl UciOptionType for String {}
+
+impl UciOptionType for NumaPolicy {
+    const UCI_TYPE: &'static str = "combo";
+    const VARS: &'static [&'static str] = &["none", "spread", "compact"];
+}
+
+impl UciOptionType for MemoryManager {
+    const UCI_TYPE: &'static str = "combo";
+    const VARS: &'static [&'static str] = &["halves", "lru"];
+}
+
+impl UciOptionType for BestMoveSelection {
+    const UCI_TYPE: &'static str = "combo";
+    const VARS: &'static [&'static str] = &["visits", "q", "lcb", "aggressive"];
+}
@@ -171,11 +171,7 @@ macro_rules! create_options {
             pub fn print_options(&self) {
                 $(
                 {
-                    let uci_type = match stringify!($option_ty) {
-                        "bool" => "check",
-                        "i64"  => "spin",
-                        _      => "string",
-                    };
+                    let uci_type = <$option_ty as UciOptionType>::UCI_TYPE;
 
                     let mut default_str = $option_default.to_string();
                     if default_str.is_empty() {
@@ -188,6 +184,9 @@ macro_rules! create_options {
                     );
 
                     $( print!(" min {} max {}", $option_min, $option_max); )?
+                    for var in <$option_ty as UciOptionType>::VARS {
+                        print!(" var {}", var);
+                    }
                     println!();
                 }
                 )+
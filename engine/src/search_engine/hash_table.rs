@@ -2,7 +2,10 @@ use std::sync::atomic::{AtomicU64, Ordering};
 
 use chess::ZobristKey;
 
-use crate::{AtomicWDLScore, WDLScore};
+use crate::{
+    search_engine::{engine_options::EngineOptions, large_buffer::LargeBuffer},
+    AtomicWDLScore, WDLScore,
+};
 
 #[derive(Debug, Default)]
 pub struct TableEntry {
@@ -20,11 +23,18 @@ impl Clone for TableEntry {
 }
 
 #[derive(Debug, Clone)]
-pub struct HashTable(Vec<TableEntry>);
+pub struct HashTable(LargeBuffer<TableEntry>);
 impl HashTable {
-    pub fn new(bytes: usize) -> Self {
+    pub fn new(bytes: usize, options: &EngineOptions) -> Self {
         let size = bytes / std::mem::size_of::<TableEntry>();
-        Self(vec![TableEntry::default(); size])
+        Self(unsafe {
+            LargeBuffer::zeroed(
+                size,
+                options.large_pages(),
+                options.threads() as usize,
+                options.numa_policy(),
+            )
+        })
     }
 
     pub fn clear(&self) {
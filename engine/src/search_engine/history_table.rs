@@ -0,0 +1,58 @@
+use std::sync::atomic::{AtomicI16, Ordering};
+
+use crate::WDLScore;
+
+//Atomic table of gravity scaled history scores shared by all search threads
+#[derive(Debug)]
+pub struct HistoryTable(Vec<AtomicI16>);
+
+impl Clone for HistoryTable {
+    fn clone(&self) -> Self {
+        Self(
+            self.0
+                .iter()
+                .map(|x| AtomicI16::new(x.load(Ordering::Relaxed)))
+                .collect(),
+        )
+    }
+}
+
+impl HistoryTable {
+    pub fn new(size: usize) -> Self {
+        Self((0..size).map(|_| AtomicI16::new(0)).collect())
+    }
+
+    pub fn clear(&self) {
+        for entry in &self.0 {
+            entry.store(0, Ordering::Relaxed);
+        }
+    }
+
+    #[inline]
+    pub fn get(&self, idx: usize) -> i16 {
+        self.0[idx].load(Ordering::Relaxed)
+    }
+
+    pub fn update(&self, idx: usize, score: WDLScore, reduction_factor: i64) {
+        let score =
+            (-400.0 * ((1.0 / score.single().clamp(0.001, 0.999)) - 1.0).ln()).round() as i32;
+        let entry = &self.0[idx];
+
+        let mut current_entry = entry.load(Ordering::Relaxed);
+        loop {
+            let delta = scale_bonus(current_entry, score, reduction_factor as i32);
+            let new = current_entry.saturating_add(delta);
+            match entry.compare_exchange(current_entry, new, Ordering::Relaxed, Ordering::Relaxed) {
+                Ok(_) => break,
+                Err(actual) => current_entry = actual,
+            }
+        }
+    }
+}
+
+fn scale_bonus(score: i16, bonus: i32, reduction_factor: i32) -> i16 {
+    let bonus = bonus.clamp(i16::MIN as i32, i16::MAX as i32);
+    let reduction = i32::from(score) * bonus.abs() / reduction_factor;
+    let adjusted = bonus - reduction;
+    adjusted.clamp(i16::MIN as i32, i16::MAX as i32) as i16
+}
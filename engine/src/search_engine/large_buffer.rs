@@ -0,0 +1,233 @@
+use std::{
+    alloc::Layout,
+    fmt::{Debug, Formatter, Result},
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+    thread,
+};
+
+use crate::search_engine::numa::NumaPolicy;
+
+const HUGE_PAGE_SIZE: usize = 2 * 1024 * 1024;
+
+//Buffers smaller than this are zeroed on the calling thread
+const PARALLEL_ZEROING_THRESHOLD: usize = 16 * 1024 * 1024;
+
+enum Allocation {
+    Global(Layout),
+    #[cfg(target_os = "linux")]
+    HugePages(usize),
+}
+
+//Fixed size array for the big search structures. Memory is aligned to huge pages and
+//zeroed by the search threads themselves, so with first-touch placement every NUMA node
+//holds the part of the buffer written by its threads
+pub struct LargeBuffer<T> {
+    ptr: NonNull<T>,
+    len: usize,
+    allocation: Allocation,
+}
+
+unsafe impl<T: Send> Send for LargeBuffer<T> {}
+unsafe impl<T: Sync> Sync for LargeBuffer<T> {}
+
+impl<T> LargeBuffer<T> {
+    //All zero bytes have to be a valid value of T
+    pub unsafe fn zeroed(
+        len: usize,
+        large_pages: bool,
+        threads: usize,
+        numa_policy: NumaPolicy,
+    ) -> Self {
+        let layout = Layout::array::<T>(len).unwrap();
+        if layout.size() == 0 {
+            return Self {
+                ptr: NonNull::dangling(),
+                len,
+                allocation: Allocation::Global(layout),
+            };
+        }
+
+        let (ptr, allocation) = match allocate_huge_pages(layout.size(), large_pages) {
+            Some(result) => result,
+            None => allocate_global(layout),
+        };
+
+        zero_memory(ptr, layout.size(), threads, numa_policy);
+
+        Self {
+            ptr: ptr.cast(),
+            len,
+            allocation,
+        }
+    }
+}
+
+impl<T> Deref for LargeBuffer<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> DerefMut for LargeBuffer<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LargeBuffer<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Clone> Clone for LargeBuffer<T> {
+    fn clone(&self) -> Self {
+        let layout = Layout::array::<T>(self.len).unwrap();
+        if layout.size() == 0 {
+            return Self {
+                ptr: NonNull::dangling(),
+                len: self.len,
+                allocation: Allocation::Global(layout),
+            };
+        }
+
+        let (ptr, allocation) = allocate_global(layout);
+        let ptr = ptr.cast::<T>();
+        for (idx, value) in self.iter().enumerate() {
+            unsafe { ptr.as_ptr().add(idx).write(value.clone()) };
+        }
+
+        Self {
+            ptr,
+            len: self.len,
+            allocation,
+        }
+    }
+}
+
+impl<T: Debug> Debug for LargeBuffer<T> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        Debug::fmt(&**self, formatter)
+    }
+}
+
+impl<T> Drop for LargeBuffer<T> {
+    fn drop(&mut self) {
+        unsafe {
+            std::ptr::drop_in_place(&mut **self as *mut [T]);
+
+            match self.allocation {
+                Allocation::Global(layout) => {
+                    if layout.size() != 0 {
+                        std::alloc::dealloc(self.ptr.as_ptr().cast(), layout)
+                    }
+                }
+                #[cfg(target_os = "linux")]
+                Allocation::HugePages(size) => {
+                    libc::munmap(self.ptr.as_ptr().cast(), size);
+                }
+            }
+        }
+    }
+}
+
+fn allocate_global(layout: Layout) -> (NonNull<u8>, Allocation) {
+    //Aligning big buffers to the huge page size lets transparent huge pages back all of it
+    let layout = if layout.size() >= HUGE_PAGE_SIZE {
+        layout.align_to(HUGE_PAGE_SIZE).unwrap()
+    } else {
+        layout
+    };
+
+    let ptr = unsafe { std::alloc::alloc(layout) };
+    let Some(ptr) = NonNull::new(ptr) else {
+        std::alloc::handle_alloc_error(layout);
+    };
+
+    advise_huge_pages(ptr, layout.size());
+    (ptr, Allocation::Global(layout))
+}
+
+#[cfg(target_os = "linux")]
+fn advise_huge_pages(ptr: NonNull<u8>, size: usize) {
+    if size >= HUGE_PAGE_SIZE {
+        unsafe { libc::madvise(ptr.as_ptr().cast(), size, libc::MADV_HUGEPAGE) };
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn advise_huge_pages(_ptr: NonNull<u8>, _size: usize) {}
+
+//Explicit huge pages have to be reserved by the system, when none are available
+//the buffer falls back to the global allocator
+#[cfg(target_os = "linux")]
+fn allocate_huge_pages(size: usize, large_pages: bool) -> Option<(NonNull<u8>, Allocation)> {
+    if !large_pages {
+        return None;
+    }
+
+    let size = size.div_ceil(HUGE_PAGE_SIZE) * HUGE_PAGE_SIZE;
+    let ptr = unsafe {
+        libc::mmap(
+            std::ptr::null_mut(),
+            size,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_PRIVATE | libc::MAP_ANONYMOUS | libc::MAP_HUGETLB,
+            -1,
+            0,
+        )
+    };
+
+    if ptr == libc::MAP_FAILED {
+        return None;
+    }
+
+    NonNull::new(ptr.cast()).map(|ptr| (ptr, Allocation::HugePages(size)))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn allocate_huge_pages(_size: usize, _large_pages: bool) -> Option<(NonNull<u8>, Allocation)> {
+    None
+}
+
+//Every thread zeroes its own chunk after binding itself the same way the search
+//thread with that index will be bound
+fn zero_memory(ptr: NonNull<u8>, size: usize, threads: usize, numa_policy: NumaPolicy) {
+    let threads = if size < PARALLEL_ZEROING_THRESHOLD {
+        1
+    } else {
+        threads.max(1)
+    };
+
+    if threads == 1 {
+        unsafe { std::ptr::write_bytes(ptr.as_ptr(), 0, size) };
+        return;
+    }
+
+    let chunk_size = size.div_ceil(threads).div_ceil(HUGE_PAGE_SIZE) * HUGE_PAGE_SIZE;
+    let address = ptr.as_ptr() as usize;
+
+    thread::scope(|s| {
+        for thread_idx in 0..threads {
+            let start = (thread_idx * chunk_size).min(size);
+            let end = (start + chunk_size).min(size);
+            if start == end {
+                break;
+            }
+
+            s.spawn(move || {
+                numa_policy.bind_thread(thread_idx);
+                unsafe { std::ptr::write_bytes((address + start) as *mut u8, 0, end - start) };
+            });
+        }
+    });
+}
@@ -0,0 +1,181 @@
+use std::sync::{Condvar, Mutex, MutexGuard};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Phase {
+    Descent,
+    Evaluation,
+}
+
+#[derive(Debug)]
+struct LockstepState {
+    threads: usize,
+    phase: Phase,
+    next_descent: usize,
+    participants: usize,
+    evaluated: usize,
+    next_backprop: usize,
+    started_iterations: u64,
+    stopped: bool,
+}
+
+//Schedules the search threads in rounds for the deterministic mode. Every round the
+//threads descend the tree one after another in thread order, leaving their virtual loss
+//behind, then evaluate their leaves in parallel and backpropagate in the same order, so
+//the tree only depends on the thread count and the number of rounds
+#[derive(Debug)]
+pub struct Lockstep {
+    state: Mutex<LockstepState>,
+    condvar: Condvar,
+}
+
+impl Default for Lockstep {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Lockstep {
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(LockstepState {
+                threads: 1,
+                phase: Phase::Descent,
+                next_descent: 0,
+                participants: 0,
+                evaluated: 0,
+                next_backprop: 0,
+                started_iterations: 0,
+                stopped: false,
+            }),
+            condvar: Condvar::new(),
+        }
+    }
+
+    pub fn reset(&self, threads: usize) {
+        let mut state = self.lock();
+        *state = LockstepState {
+            threads: threads.max(1),
+            phase: Phase::Descent,
+            next_descent: 0,
+            participants: 0,
+            evaluated: 0,
+            next_backprop: 0,
+            started_iterations: 0,
+            stopped: false,
+        };
+    }
+
+    //Starts a new round after the tree was swapped, the iteration count carries over so
+    //the limit still covers the whole search
+    pub fn resume(&self) {
+        let mut state = self.lock();
+        state.phase = Phase::Descent;
+        state.next_descent = 0;
+        state.participants = 0;
+        state.evaluated = 0;
+        state.next_backprop = 0;
+        state.stopped = false;
+    }
+
+    //Iterations that have left the descent phase. Read by the main thread between its own
+    //iterations, when every descent of the current round is done, so it is reproducible
+    pub fn started_iterations(&self) -> u64 {
+        self.lock().started_iterations
+    }
+
+    //Waits for the descent turn of the thread. Only the main thread opens a round, so it
+    //alone decides whether an interruption ends the search before the next round
+    pub fn begin_descent(&self, thread_idx: usize, interrupted: bool) -> bool {
+        let mut state = self.lock();
+
+        if thread_idx == 0 && interrupted {
+            state.stopped = true;
+            self.condvar.notify_all();
+        }
+
+        loop {
+            if state.stopped {
+                return false;
+            }
+
+            if state.phase == Phase::Descent && state.next_descent == thread_idx {
+                return true;
+            }
+
+            state = self.condvar.wait(state).unwrap();
+        }
+    }
+
+    //Hands the descent turn to the next thread and returns the backpropagation slot
+    pub fn end_descent(&self) -> usize {
+        let mut state = self.lock();
+        let slot = state.next_descent;
+
+        state.next_descent += 1;
+        state.started_iterations += 1;
+
+        if state.next_descent == state.threads {
+            state.participants = state.threads;
+            state.phase = Phase::Evaluation;
+        }
+
+        self.condvar.notify_all();
+        slot
+    }
+
+    //The descent failed, threads that already descended finish the round and the
+    //remaining ones stop
+    pub fn abort_descent(&self) {
+        let mut state = self.lock();
+
+        state.stopped = true;
+        state.participants = state.next_descent;
+        state.phase = Phase::Evaluation;
+
+        self.condvar.notify_all();
+    }
+
+    pub fn wait_evaluation(&self) {
+        let mut state = self.lock();
+        while state.phase != Phase::Evaluation {
+            state = self.condvar.wait(state).unwrap();
+        }
+    }
+
+    //Waits until every leaf of the round is evaluated and all earlier slots backpropagated
+    pub fn begin_backprop(&self, slot: usize) {
+        let mut state = self.lock();
+
+        state.evaluated += 1;
+        self.condvar.notify_all();
+
+        while state.evaluated < state.participants || state.next_backprop != slot {
+            state = self.condvar.wait(state).unwrap();
+        }
+    }
+
+    pub fn end_backprop(&self) {
+        let mut state = self.lock();
+
+        state.next_backprop += 1;
+
+        if state.next_backprop == state.participants {
+            state.phase = Phase::Descent;
+            state.next_descent = 0;
+            state.participants = 0;
+            state.evaluated = 0;
+            state.next_backprop = 0;
+        }
+
+        self.condvar.notify_all();
+    }
+
+    pub fn stop(&self) {
+        self.lock().stopped = true;
+        self.condvar.notify_all();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, LockstepState> {
+        self.state.lock().unwrap()
+    }
+}
@@ -6,17 +6,30 @@ use crate::{
     search_engine::{
         search_limits::TimeManager,
         search_stats::{SearchStatsAccumulator, ThreadSearchStats},
+        tree::{take_contended_reads, take_contended_writes},
         SearchLimits, SearchStats,
     },
-    SearchEngine, SearchReport, WDLScore,
+    GameState, SearchEngine, SearchObserver, WDLScore,
 };
 
 mod iteration;
 
 const BATCH_SIZE: u64 = 256;
+const PV_CHECK_INTERVAL: u64 = 1024;
+
+//Last PV and proven mate given to the observer
+#[derive(Default)]
+struct PvTracker {
+    moves: Vec<Move>,
+    mate: Option<GameState>,
+}
 
 impl SearchEngine {
-    pub(super) fn mcts<Display: SearchReport>(&self, search_limits: &SearchLimits) -> SearchStats {
+    pub(super) fn mcts(
+        &self,
+        search_limits: &SearchLimits,
+        observer: &mut dyn SearchObserver,
+    ) -> SearchStats {
         let castle_mask = self
             .root_position()
             .board()
@@ -30,13 +43,18 @@ impl SearchEngine {
 
         let mut last_best_move = None;
         let mut best_move_changes = 0;
+        let mut pv_tracker = PvTracker::default();
+
+        self.lockstep.reset(self.options().threads() as usize);
 
         loop {
             let mut time_manager = search_limits.time_manager();
 
             thread::scope(|s| {
                 s.spawn(|| {
-                    self.main_loop::<Display>(
+                    self.options().numa_policy().bind_thread(0);
+                    self.main_loop(
+                        observer,
                         &search_stats,
                         search_limits,
                         &mut time_manager,
@@ -45,14 +63,19 @@ impl SearchEngine {
                         &mut max_avg_depth,
                         &mut last_best_move,
                         &mut best_move_changes,
+                        &mut pv_tracker,
                     );
+                    self.lockstep.stop();
                 });
 
                 for i in 0..(self.options().threads() - 1) {
                     let thread_stats = search_stats.thread_stats((i + 1) as usize);
                     let castle_mask = &castle_mask;
 
-                    s.spawn(move || self.worker_loop(thread_stats, castle_mask));
+                    s.spawn(move || {
+                        self.options().numa_policy().bind_thread(i as usize + 1);
+                        self.worker_loop(thread_stats, castle_mask, i as usize + 1)
+                    });
                 }
             });
 
@@ -60,15 +83,18 @@ impl SearchEngine {
                 break;
             }
 
-            self.tree().swap_half();
+            self.tree().reclaim_memory(self.options());
+            self.lockstep.resume();
+            observer.on_half_swap(self);
         }
 
         search_stats
     }
 
     #[allow(clippy::too_many_arguments)]
-    fn main_loop<Display: SearchReport>(
+    fn main_loop(
         &self,
+        observer: &mut dyn SearchObserver,
         search_stats: &SearchStats,
         search_limits: &SearchLimits,
         time_manager: &mut TimeManager,
@@ -77,15 +103,17 @@ impl SearchEngine {
         max_avg_depth: &mut u64,
         last_best_move: &mut Option<Move>,
         best_move_changes: &mut usize,
+        pv_tracker: &mut PvTracker,
     ) -> Option<()> {
         #[allow(unused)]
         let mut latest_kld_distribution: Vec<u32> = Vec::new();
         let thread_stats = search_stats.thread_stats(0);
         let accumulator = &mut SearchStatsAccumulator::default();
         let mut stored_iterations = search_stats.aggregate().iterations();
+        let mut main_iterations = 0u64;
 
         while !self.is_search_interrupted() {
-            if self.search_step(accumulator, castle_mask).is_none() {
+            if self.search_step(accumulator, castle_mask, 0).is_none() {
                 if accumulator.iterations() > 0 {
                     thread_stats.add_batch(accumulator);
                 }
@@ -95,10 +123,9 @@ impl SearchEngine {
 
             let avg_depth = thread_stats.avg_depth();
             if avg_depth > *max_avg_depth
-                || search_report_timer.elapsed().as_secs_f64()
-                    > (1.0 / Display::refresh_rate_per_second())
+                || search_report_timer.elapsed().as_secs_f64() > (1.0 / observer.stats_per_second())
             {
-                Display::search_report(search_limits, search_stats, self);
+                observer.on_stats(search_limits, search_stats, self);
                 *search_report_timer = Instant::now();
                 *max_avg_depth = avg_depth.max(*max_avg_depth);
             }
@@ -117,6 +144,14 @@ impl SearchEngine {
                 }
             }
 
+            main_iterations += 1;
+            if *last_best_move != Some(best_move) {
+                observer.on_best_move_changed(best_move, self);
+                self.notify_pv(observer, pv_tracker);
+            } else if main_iterations.is_multiple_of(PV_CHECK_INTERVAL) {
+                self.notify_pv(observer, pv_tracker);
+            }
+
             *last_best_move = Some(best_move);
 
             #[cfg(feature = "datagen")]
@@ -133,7 +168,11 @@ impl SearchEngine {
                 *accumulator = SearchStatsAccumulator::default();
             }
 
-            let iterations = stored_iterations + accumulator.iterations();
+            let iterations = if self.options().deterministic() {
+                self.lockstep.started_iterations()
+            } else {
+                stored_iterations + accumulator.iterations()
+            };
             let elapsed_ms = search_stats.elapsed_ms();
             if search_limits.is_limit_reached(thread_stats, iterations, elapsed_ms) {
                 self.interrupt_search();
@@ -174,11 +213,40 @@ impl SearchEngine {
         Some(())
     }
 
-    fn worker_loop(&self, thread_stats: &ThreadSearchStats, castle_mask: &[u8; 64]) -> Option<()> {
+    fn notify_pv(&self, observer: &mut dyn SearchObserver, pv_tracker: &mut PvTracker) {
+        let pv = self.tree().get_best_pv(0, self.options());
+        if pv.is_empty() || pv.first_move() == Move::NULL {
+            return;
+        }
+
+        if !pv.moves().eq(pv_tracker.moves.iter().copied()) {
+            pv_tracker.moves = pv.moves().collect();
+            observer.on_pv_changed(&pv, self);
+        }
+
+        let mate = match pv.first_node().state() {
+            state @ (GameState::Win(_) | GameState::Loss(_)) => Some(state),
+            _ => None,
+        };
+
+        if mate.is_some() && mate != pv_tracker.mate {
+            observer.on_mate_proven(&pv, self);
+        }
+
+        pv_tracker.mate = mate;
+    }
+
+    fn worker_loop(
+        &self,
+        thread_stats: &ThreadSearchStats,
+        castle_mask: &[u8; 64],
+        thread_idx: usize,
+    ) -> Option<()> {
         let accumulator = &mut SearchStatsAccumulator::default();
 
-        while !self.is_search_interrupted() {
-            self.search_step(accumulator, castle_mask)?;
+        //In the deterministic mode the lockstep rounds decide when the search ends
+        while self.options().deterministic() || !self.is_search_interrupted() {
+            self.search_step(accumulator, castle_mask, thread_idx)?;
 
             if accumulator.iterations() > BATCH_SIZE {
                 thread_stats.add_batch(accumulator);
@@ -197,17 +265,39 @@ impl SearchEngine {
         &self,
         accumulator: &mut SearchStatsAccumulator,
         castle_mask: &[u8; 64],
+        thread_idx: usize,
     ) -> Option<()> {
+        let deterministic = self.options().deterministic();
+        if deterministic
+            && !self
+                .lockstep
+                .begin_descent(thread_idx, self.is_search_interrupted())
+        {
+            return None;
+        }
+
         let mut depth = 0.0;
         let mut position = *self.root_position();
 
-        self.perform_iteration::<true>(
+        let result = self.perform_iteration::<true>(
             self.tree().root_index(),
             &mut position,
             &mut depth,
             WDLScore::DRAW,
             castle_mask,
-        )?;
+        );
+
+        accumulator.add_contention(take_contended_reads(), take_contended_writes());
+
+        //Iterations only fail while descending, before handing over the descent turn
+        if deterministic {
+            match result {
+                Some(_) => self.lockstep.end_backprop(),
+                None => self.lockstep.abort_descent(),
+            }
+        }
+
+        result?;
 
         accumulator.add_iteration(depth as u64);
 
@@ -1,6 +1,11 @@
 use chess::ChessPosition;
 
-use crate::{search_engine::tree::NodeIndex, SearchEngine, WDLScore};
+use crate::{
+    search_engine::{
+        capture_history::CaptureHistory, continuation_history::ContinuationHistory, tree::NodeIndex,
+    },
+    SearchEngine, WDLScore,
+};
 
 mod backpropagate;
 mod select;
@@ -17,49 +22,60 @@ impl SearchEngine {
     ) -> Option<WDLScore> {
         let hash = position.board().hash();
         let node = &self.tree()[node_idx];
+        self.tree().touch(node_idx, self.options());
 
         let mut selected_child_idx = None;
 
         let score = if !ROOT && (node.is_terminal() || node.visits() == 0) {
-            self.simulate(node_idx, position, *depth, parent_score)
+            if self.options().deterministic() {
+                let slot = self.lockstep.end_descent();
+                self.lockstep.wait_evaluation();
+                let score = self.simulate(node_idx, position, *depth, parent_score);
+                self.lockstep.begin_backprop(slot);
+                score
+            } else {
+                self.simulate(node_idx, position, *depth, parent_score)
+            }
         } else {
             *depth += 1.0;
 
-            if node.children_count() == 0 {
-                self.tree().expand_node(
-                    node_idx,
-                    position.board(),
-                    self.options(),
-                    *depth as i32,
-                    parent_score,
-                )?
-            }
-
-            self.tree().update_node(node_idx)?;
+            //LRU eviction can take the children between the expansion check and the selection.
+            //The thread count of the node keeps them from being evicted again once expanded
+            let new_idx = loop {
+                if node.children_count() == 0 {
+                    self.tree().expand_node(
+                        node_idx,
+                        position.board(),
+                        self.options(),
+                        *depth as i32,
+                        parent_score,
+                    )?
+                }
+
+                self.tree().update_node(node_idx, self.options())?;
+
+                if ROOT || !node.children_index().is_null() {
+                    break self.select::<ROOT>(node_idx, *depth)?;
+                }
+            };
 
             let score = node.score();
-            let new_idx = self.select::<ROOT>(node_idx, *depth)?;
 
             selected_child_idx = Some(new_idx);
 
             let old_side = position.board().side();
             let mv = self.tree()[new_idx].mv();
+            let piece = position.board().moved_piece(mv);
+            let victim = CaptureHistory::victim(position.board(), mv);
+            let parent = ContinuationHistory::parent_key(position.board(), node.mv());
             position.make_move(mv, castle_mask);
 
-            self.tree().inc_threads(new_idx, 1);
-
-            let lock = if self.tree()[new_idx].visits() == 0 {
-                Some(node.children_index_mut())
-            } else {
-                None
-            };
+            self.tree().inc_threads(new_idx, 1, self.options());
 
             let score =
                 self.perform_iteration::<false>(new_idx, position, depth, score, castle_mask);
 
-            drop(lock);
-
-            self.tree().dec_threads(new_idx, 1);
+            self.tree().dec_threads(new_idx, 1, self.options());
 
             let score = score?;
 
@@ -67,6 +83,22 @@ impl SearchEngine {
                 self.tree()
                     .butterfly_history()
                     .update_entry(old_side, mv, score, self.options());
+                self.tree().continuation_history().update_entry(
+                    old_side,
+                    parent,
+                    piece,
+                    mv,
+                    score,
+                    self.options(),
+                );
+                self.tree().capture_history().update_entry(
+                    old_side,
+                    piece,
+                    mv,
+                    victim,
+                    score,
+                    self.options(),
+                );
             }
 
             score
@@ -2,7 +2,7 @@ use chess::ZobristKey;
 
 use crate::{
     search_engine::tree::{NodeIndex, Tree},
-    GameState, SearchEngine, WDLScore,
+    GameState, Node, ScoreBound, SearchEngine, WDLScore,
 };
 
 impl SearchEngine {
@@ -19,31 +19,62 @@ impl SearchEngine {
     }
 }
 
+//MCTS-solver. Every node has a proven range of results for its side to move, which is
+//the best of the negated ranges of its children. Nodes whose range collapses become
+//terminal, the ones that only rule out a win or a loss keep the bound
 fn backprop_state(tree: &Tree, node_idx: NodeIndex, child_idx: Option<NodeIndex>) -> Option<()> {
     let child_idx = child_idx?;
 
     match tree[child_idx].state() {
         GameState::Loss(len) => {
             tree.set_state(node_idx, GameState::Win(len + 1));
+            return Some(());
         }
-        GameState::Win(len) => {
-            let mut proven_loss = true;
-            let mut proven_loss_length = len;
-
-            tree[node_idx].map_children(|child_idx| {
-                if let GameState::Win(x) = tree[child_idx].state() {
-                    proven_loss_length = x.max(proven_loss_length);
-                } else {
-                    proven_loss = false;
-                }
-            });
-
-            if proven_loss {
-                tree.set_state(node_idx, GameState::Loss(proven_loss_length + 1));
-            }
+        GameState::Ongoing if tree[child_idx].bound() == ScoreBound::None => return Some(()),
+        _ => (),
+    }
+
+    let mut lower = -1;
+    let mut upper = -1;
+    let mut win_length = u8::MAX;
+    let mut loss_length = 0;
+
+    tree[node_idx].map_children(|child_idx| {
+        let child_node = &tree[child_idx];
+        let (child_lower, child_upper) = result_range(child_node);
+
+        lower = lower.max(-child_upper);
+        upper = upper.max(-child_lower);
+
+        match child_node.state() {
+            GameState::Loss(x) => win_length = win_length.min(x),
+            GameState::Win(x) => loss_length = loss_length.max(x),
+            _ => (),
         }
+    });
+
+    match (lower, upper) {
+        (1, _) => tree.set_state(node_idx, GameState::Win(win_length.saturating_add(1))),
+        (_, -1) => tree.set_state(node_idx, GameState::Loss(loss_length.saturating_add(1))),
+        (0, 0) => tree.set_state(node_idx, GameState::Draw),
+        (0, _) => tree.set_bound(node_idx, ScoreBound::AtLeastDraw),
+        (_, 0) => tree.set_bound(node_idx, ScoreBound::AtMostDraw),
         _ => (),
     }
 
     Some(())
 }
+
+//Lowest and highest possible result for the side to move, -1 is a loss and 1 a win
+fn result_range(node: &Node) -> (i8, i8) {
+    match node.state() {
+        GameState::Win(_) => (1, 1),
+        GameState::Loss(_) => (-1, -1),
+        GameState::Draw => (0, 0),
+        GameState::Ongoing => match node.bound() {
+            ScoreBound::None => (-1, 1),
+            ScoreBound::AtLeastDraw => (0, 1),
+            ScoreBound::AtMostDraw => (-1, 0),
+        },
+    }
+}
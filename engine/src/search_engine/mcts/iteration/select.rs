@@ -15,6 +15,7 @@ impl SearchEngine {
         let cpuct = get_cpuct(self.options(), parent_node, depth);
 
         let start_idx = parent_node.children_index();
+
         let mut total_policy = 0.0;
         let mut k = 0;
         while k < parent_node.children_count() && total_policy < self.options().policy_percentage()
@@ -30,6 +31,17 @@ impl SearchEngine {
             .floor() as usize;
         }
 
+        //Solved root moves are never searched, so they do not take widening slots
+        if ROOT {
+            let mut idx = 0;
+            while idx < limit.min(parent_node.children_count()) {
+                if self.tree[start_idx + idx].is_terminal() {
+                    limit += 1;
+                }
+                idx += 1;
+            }
+        }
+
         #[allow(unused_assignments)]
         {
             limit = limit.min(parent_node.children_count());
@@ -142,8 +154,7 @@ fn get_cpuct(options: &EngineOptions, parent_node: &Node, depth: f64) -> f64 {
     cpuct *= 1.0 + ((f64::from(parent_node.visits()) + visit_scale) / visit_scale).ln();
 
     if parent_node.visits() > 1 {
-        let var = (parent_node.squared_score() - parent_node.score().single().powi(2)).max(0.0);
-        let mut variance = var.sqrt() / options.cpuct_variance_scale();
+        let mut variance = parent_node.score_variance().sqrt() / options.cpuct_variance_scale();
         variance +=
             (1.0 - variance) / (1.0 + options.cpuct_var_warmup() * parent_node.visits() as f64);
         cpuct *= 1.0 + options.cpuct_variance_weight() * (variance - 1.0);
@@ -44,6 +44,14 @@ impl SearchEngine {
     }
 
     fn get_node_state(&self, position: &ChessPosition) -> GameState {
+        if let Some(result) = position.board().variant_result() {
+            return if result.winner() == Some(position.board().side()) {
+                GameState::Win(0)
+            } else {
+                GameState::Loss(0)
+            };
+        }
+
         let mut possible_moves = 0;
         position.board().map_legal_moves(|_| possible_moves += 1);
 
@@ -61,7 +69,7 @@ impl SearchEngine {
     }
 
     fn is_draw(&self, position: &ChessPosition) -> bool {
-        if position.board().half_moves() >= 100 || position.board().is_insufficient_material() {
+        if position.board().is_fifty_move_rule() || position.board().is_dead_position() {
             return true;
         }
 
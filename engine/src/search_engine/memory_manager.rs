@@ -0,0 +1,37 @@
+use std::{
+    fmt::{Display, Formatter, Result},
+    str::FromStr,
+};
+
+//How the tree makes room when it runs out of nodes. Halves stops the search, flips to the
+//other half and lazily copies nodes back, LRU keeps searching and evicts the subtrees a
+//clock sweep found unused into free lists a batch at a time
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum MemoryManager {
+    #[default]
+    Halves,
+    Lru,
+}
+
+impl FromStr for MemoryManager {
+    type Err = String;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "halves" => Ok(MemoryManager::Halves),
+            "lru" => Ok(MemoryManager::Lru),
+            _ => Err(format!("Unknown memory manager '{value}'")),
+        }
+    }
+}
+
+impl Display for MemoryManager {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        let name = match self {
+            MemoryManager::Halves => "halves",
+            MemoryManager::Lru => "lru",
+        };
+
+        write!(formatter, "{name}")
+    }
+}
@@ -0,0 +1,132 @@
+use std::{
+    fmt::{Display, Formatter, Result},
+    str::FromStr,
+    sync::OnceLock,
+};
+
+//How search threads are bound to NUMA nodes. Spread assigns threads to nodes round robin,
+//compact fills all cpus of a node before moving to the next one
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum NumaPolicy {
+    #[default]
+    None,
+    Spread,
+    Compact,
+}
+
+impl FromStr for NumaPolicy {
+    type Err = String;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "none" => Ok(NumaPolicy::None),
+            "spread" => Ok(NumaPolicy::Spread),
+            "compact" => Ok(NumaPolicy::Compact),
+            _ => Err(format!("Unknown NUMA policy '{value}'")),
+        }
+    }
+}
+
+impl Display for NumaPolicy {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        let name = match self {
+            NumaPolicy::None => "none",
+            NumaPolicy::Spread => "spread",
+            NumaPolicy::Compact => "compact",
+        };
+
+        write!(formatter, "{name}")
+    }
+}
+
+impl NumaPolicy {
+    //Cpus the given search thread should run on, None leaves the choice to the OS
+    pub fn thread_cpus(&self, thread_idx: usize) -> Option<&'static [usize]> {
+        let nodes = numa_nodes();
+        if nodes.is_empty() {
+            return None;
+        }
+
+        let node = match self {
+            NumaPolicy::None => return None,
+            NumaPolicy::Spread => thread_idx % nodes.len(),
+            NumaPolicy::Compact => {
+                let cpus_per_node = nodes[0].len().max(1);
+                (thread_idx / cpus_per_node) % nodes.len()
+            }
+        };
+
+        Some(&nodes[node])
+    }
+
+    //Binds the calling thread according to the policy. Failures are ignored, as pinning
+    //is only a performance hint
+    pub fn bind_thread(&self, thread_idx: usize) {
+        if let Some(cpus) = self.thread_cpus(thread_idx) {
+            set_thread_affinity(cpus);
+        }
+    }
+}
+
+fn numa_nodes() -> &'static [Vec<usize>] {
+    static NODES: OnceLock<Vec<Vec<usize>>> = OnceLock::new();
+    NODES.get_or_init(read_numa_nodes)
+}
+
+#[cfg(target_os = "linux")]
+fn read_numa_nodes() -> Vec<Vec<usize>> {
+    let mut result = Vec::new();
+    for node in 0.. {
+        let path = format!("/sys/devices/system/node/node{node}/cpulist");
+        let Ok(cpu_list) = std::fs::read_to_string(path) else {
+            break;
+        };
+
+        let cpus = parse_cpu_list(&cpu_list);
+        if !cpus.is_empty() {
+            result.push(cpus);
+        }
+    }
+
+    result
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_numa_nodes() -> Vec<Vec<usize>> {
+    Vec::new()
+}
+
+//Parses kernel cpu lists in the "0-3,8,10-11" format
+#[cfg(target_os = "linux")]
+fn parse_cpu_list(cpu_list: &str) -> Vec<usize> {
+    let mut result = Vec::new();
+    for range in cpu_list.trim().split(',').filter(|range| !range.is_empty()) {
+        let bounds = match range.split_once('-') {
+            Some((start, end)) => start.parse::<usize>().ok().zip(end.parse::<usize>().ok()),
+            None => range.parse::<usize>().ok().map(|cpu| (cpu, cpu)),
+        };
+
+        if let Some((start, end)) = bounds {
+            result.extend(start..=end);
+        }
+    }
+
+    result
+}
+
+#[cfg(target_os = "linux")]
+fn set_thread_affinity(cpus: &[usize]) {
+    unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        for &cpu in cpus {
+            if cpu < libc::CPU_SETSIZE as usize {
+                libc::CPU_SET(cpu, &mut set);
+            }
+        }
+
+        libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set);
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn set_thread_affinity(_cpus: &[usize]) {}
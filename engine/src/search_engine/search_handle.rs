@@ -0,0 +1,158 @@
+use std::{
+    sync::{atomic::Ordering, Arc},
+    thread::{self, JoinHandle},
+};
+
+use chess::Move;
+
+use crate::{
+    search_engine::{SearchError, SearchGuard},
+    search_observer::ReportObserver,
+    NoReport, PvLine, SearchEngine, SearchLimits, SearchStats, WDLScore,
+};
+
+//Best lines of the root at one point of the search, MultiPV of them, best first
+#[derive(Debug, Clone)]
+pub struct SearchSnapshot {
+    pvs: Vec<PvLine>,
+    visits: u32,
+}
+
+impl SearchSnapshot {
+    pub fn new(search_engine: &SearchEngine) -> Self {
+        let tree = search_engine.tree();
+        let pv_count = tree
+            .root_node()
+            .children_count()
+            .min(search_engine.options().multi_pv() as usize);
+
+        let pvs = (0..pv_count)
+            .map(|pv_idx| tree.get_best_pv(pv_idx, search_engine.options()))
+            .filter(|pv| !pv.is_empty() && pv.first_move() != Move::NULL)
+            .collect();
+
+        Self {
+            pvs,
+            visits: tree.root_node().visits(),
+        }
+    }
+
+    #[inline]
+    pub fn pvs(&self) -> &[PvLine] {
+        &self.pvs
+    }
+
+    //Null when the search has not finished an iteration yet
+    #[inline]
+    pub fn best_move(&self) -> Move {
+        self.pvs.first().map_or(Move::NULL, |pv| pv.first_move())
+    }
+
+    //From the perspective of the side to move at the root
+    #[inline]
+    pub fn score(&self) -> Option<WDLScore> {
+        self.pvs.first().map(|pv| pv.score())
+    }
+
+    #[inline]
+    pub fn visits(&self) -> u32 {
+        self.visits
+    }
+}
+
+#[derive(Debug)]
+pub struct SearchResult {
+    snapshot: SearchSnapshot,
+    stats: SearchStats,
+}
+
+impl SearchResult {
+    #[inline]
+    pub fn best_move(&self) -> Move {
+        self.snapshot.best_move()
+    }
+
+    #[inline]
+    pub fn snapshot(&self) -> &SearchSnapshot {
+        &self.snapshot
+    }
+
+    #[inline]
+    pub fn stats(&self) -> &SearchStats {
+        &self.stats
+    }
+}
+
+//Search running on its own thread. Dropping the handle stops the search and waits for it
+#[derive(Debug)]
+pub struct SearchHandle {
+    search_engine: Arc<SearchEngine>,
+    thread: Option<JoinHandle<SearchStats>>,
+}
+
+impl Drop for SearchHandle {
+    fn drop(&mut self) {
+        if let Some(thread) = self.thread.take() {
+            self.search_engine.interrupt_search();
+            let _ = thread.join();
+        }
+    }
+}
+
+impl SearchHandle {
+    #[inline]
+    pub fn stop(&self) {
+        self.search_engine.interrupt_search();
+    }
+
+    #[inline]
+    pub fn is_running(&self) -> bool {
+        self.thread
+            .as_ref()
+            .is_some_and(|thread| !thread.is_finished())
+    }
+
+    #[inline]
+    pub fn snapshot(&self) -> SearchSnapshot {
+        SearchSnapshot::new(&self.search_engine)
+    }
+
+    //Blocks until the limits are reached or the search is stopped
+    pub fn wait(mut self) -> SearchResult {
+        let stats = self
+            .thread
+            .take()
+            .unwrap()
+            .join()
+            .expect("Search thread panicked");
+
+        SearchResult {
+            snapshot: SearchSnapshot::new(&self.search_engine),
+            stats,
+        }
+    }
+}
+
+impl SearchEngine {
+    //The engine is shared with the search thread, so it can only be changed again through
+    //Arc::get_mut once the handle is gone
+    pub fn start_search(
+        self: &Arc<Self>,
+        search_limits: &SearchLimits,
+    ) -> Result<SearchHandle, SearchError> {
+        //Both are set before the thread starts, so a stop right after this call is not lost
+        //and a second search cannot start before this one does
+        let search_guard = SearchGuard::new(Arc::clone(self))?;
+        self.interruption_token.store(false, Ordering::Relaxed);
+
+        let search_limits = search_limits.clone();
+        let thread = thread::spawn(move || {
+            search_guard.run_search(&search_limits, &mut ReportObserver::<NoReport>::default())
+        });
+
+        Ok(SearchHandle {
+            search_engine: Arc::clone(self),
+            thread: Some(thread),
+        })
+    }
+}
@@ -4,7 +4,7 @@ mod time_manager;
 
 pub use time_manager::TimeManager;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct SearchLimits {
     depth: Option<u64>,
     iters: Option<u64>,
@@ -132,10 +132,56 @@ impl TimeManager {
 
         soft_limit_multiplier *= self.sacrifice_extension(best_idx, second_best_idx, tree, options);
 
-        elapsed_ms
-            >= ((self.soft_limit.unwrap() as f64 * soft_limit_multiplier) as u128)
-                .saturating_sub(move_overhead)
-                .max(1)
+        let soft_limit = self.soft_limit.unwrap() as f64 * soft_limit_multiplier;
+
+        if options.confidence_stop()
+            && self.confidence_stop(draw_score, elapsed_ms, soft_limit, tree, options)
+        {
+            return true;
+        }
+
+        elapsed_ms >= (soft_limit as u128).saturating_sub(move_overhead).max(1)
+    }
+
+    //Stops once the best move is statistically separated from the runner-up, which
+    //further search is unlikely to change. Both are ranked the way the best move is chosen
+    fn confidence_stop(
+        &self,
+        draw_score: f64,
+        elapsed_ms: u128,
+        soft_limit: f64,
+        tree: &Tree,
+        options: &EngineOptions,
+    ) -> bool {
+        if (elapsed_ms as f64) < soft_limit * options.confidence_stop_min_time() {
+            return false;
+        }
+
+        let root = tree.root_node();
+        let selection = options.best_move_selection();
+
+        let mut best = None;
+        let mut second_best = None;
+        root.map_children(|child_idx| {
+            let key = selection.child_key(&tree[child_idx], root, draw_score, options);
+            if second_best.is_none_or(|(_, second_key)| key > second_key) {
+                second_best = Some((child_idx, key));
+
+                if best.is_none_or(|(_, best_key)| key > best_key) {
+                    (best, second_best) = (second_best, best);
+                }
+            }
+        });
+
+        let (Some((best_idx, _)), Some((second_best_idx, _))) = (best, second_best) else {
+            return false;
+        };
+
+        let z = options.confidence_stop_z();
+        let best_lcb = tree[best_idx].score_bound(-z).single();
+        let second_ucb = tree[second_best_idx].score_bound(z).single();
+
+        tree[best_idx].visits() > tree[second_best_idx].visits() && best_lcb > second_ucb
     }
 
     fn visits_distribution(
@@ -3,16 +3,20 @@ use std::{
     time::Instant,
 };
 
+#[derive(Debug)]
 pub struct SearchStats {
     threads: Vec<ThreadSearchStats>,
     timer: Instant,
 }
 
+#[derive(Debug)]
 #[repr(align(64))]
 pub struct ThreadSearchStats {
     iterations: AtomicU64,
     cumulative_depth: AtomicU64,
     max_depth: AtomicU64,
+    contended_reads: AtomicU64,
+    contended_writes: AtomicU64,
 }
 
 impl Default for ThreadSearchStats {
@@ -27,6 +31,8 @@ impl ThreadSearchStats {
             iterations: AtomicU64::new(0),
             cumulative_depth: AtomicU64::new(0),
             max_depth: AtomicU64::new(0),
+            contended_reads: AtomicU64::new(0),
+            contended_writes: AtomicU64::new(0),
         }
     }
 
@@ -50,6 +56,16 @@ impl ThreadSearchStats {
         self.cumulative_depth.load(Ordering::Relaxed)
     }
 
+    #[inline(always)]
+    pub fn contended_reads(&self) -> u64 {
+        self.contended_reads.load(Ordering::Relaxed)
+    }
+
+    #[inline(always)]
+    pub fn contended_writes(&self) -> u64 {
+        self.contended_writes.load(Ordering::Relaxed)
+    }
+
     #[inline(always)]
     pub fn add_batch(&self, accumulator: &SearchStatsAccumulator) {
         self.iterations
@@ -58,6 +74,10 @@ impl ThreadSearchStats {
             .fetch_add(accumulator.cumulative_depth(), Ordering::Relaxed);
         self.max_depth
             .fetch_max(accumulator.max_depth(), Ordering::Relaxed);
+        self.contended_reads
+            .fetch_add(accumulator.contended_reads(), Ordering::Relaxed);
+        self.contended_writes
+            .fetch_add(accumulator.contended_writes(), Ordering::Relaxed);
     }
 }
 
@@ -66,6 +86,8 @@ pub struct SearchStatsAccumulator {
     iterations: u64,
     cumulative_depth: u64,
     max_depth: u64,
+    contended_reads: u64,
+    contended_writes: u64,
 }
 
 impl SearchStatsAccumulator {
@@ -89,12 +111,29 @@ impl SearchStatsAccumulator {
         self.max_depth
     }
 
+    //Accesses of a children index that waited for another thread to finish expanding
+    #[inline(always)]
+    pub fn contended_reads(&self) -> u64 {
+        self.contended_reads
+    }
+
+    #[inline(always)]
+    pub fn contended_writes(&self) -> u64 {
+        self.contended_writes
+    }
+
     #[inline(always)]
     pub fn add_iteration(&mut self, depth: u64) {
         self.iterations += 1;
         self.cumulative_depth += depth;
         self.max_depth = self.max_depth.max(depth);
     }
+
+    #[inline(always)]
+    pub fn add_contention(&mut self, reads: u64, writes: u64) {
+        self.contended_reads += reads;
+        self.contended_writes += writes;
+    }
 }
 
 impl SearchStats {
@@ -121,6 +160,8 @@ impl SearchStats {
                 .max_depth
                 .load(Ordering::Relaxed)
                 .max(result.max_depth);
+            result.contended_reads += thread.contended_reads.load(Ordering::Relaxed);
+            result.contended_writes += thread.contended_writes.load(Ordering::Relaxed);
         }
 
         result
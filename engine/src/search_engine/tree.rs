@@ -1,6 +1,6 @@
 use std::{
     ops::{Index, IndexMut},
-    sync::atomic::{AtomicU64, Ordering},
+    sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
 };
 
 use chess::Move;
@@ -16,12 +16,18 @@ mod tree_utils;
 
 use half::TreeHalf;
 
-pub use node::{AtomicWDLScore, GameState, Node, NodeIndex, WDLScore};
+pub use node::{
+    take_contended_reads, take_contended_writes, AtomicWDLScore, GameState, Node, NodeIndex,
+    ScoreBound, WDLScore,
+};
 pub use pv_line::PvLine;
 
 use crate::search_engine::{
-    butterfly_history::ButterflyHistory, engine_options::EngineOptions, hash_table::HashTable,
+    butterfly_history::ButterflyHistory, capture_history::CaptureHistory,
+    continuation_history::ContinuationHistory, engine_options::EngineOptions,
+    hash_table::HashTable,
 };
+use crate::MemoryManager;
 
 #[derive(Debug)]
 pub struct Tree {
@@ -29,6 +35,12 @@ pub struct Tree {
     current_half: AtomicU64,
     hash_table: HashTable,
     butterfly_history: ButterflyHistory,
+    continuation_history: ContinuationHistory,
+    capture_history: CaptureHistory,
+    clock_hand: AtomicUsize,
+    collecting: AtomicBool,
+    evicted_nodes: AtomicU64,
+    stalls: AtomicU64,
 }
 
 impl Clone for Tree {
@@ -38,6 +50,12 @@ impl Clone for Tree {
             current_half: AtomicU64::from(self.current_half.load(Ordering::Relaxed)),
             hash_table: self.hash_table.clone(),
             butterfly_history: self.butterfly_history.clone(),
+            continuation_history: self.continuation_history.clone(),
+            capture_history: self.capture_history.clone(),
+            clock_hand: AtomicUsize::new(self.clock_hand.load(Ordering::Relaxed)),
+            collecting: AtomicBool::new(false),
+            evicted_nodes: AtomicU64::new(self.evicted_nodes.load(Ordering::Relaxed)),
+            stalls: AtomicU64::new(self.stalls.load(Ordering::Relaxed)),
         }
     }
 }
@@ -63,8 +81,8 @@ impl Tree {
         let tree_size = Self::bytes_to_size(bytes - hash_bytes);
 
         let halves = [
-            TreeHalf::new(0, tree_size / 2),
-            TreeHalf::new(1, tree_size / 2),
+            TreeHalf::new(0, tree_size / 2, options),
+            TreeHalf::new(1, tree_size / 2, options),
         ];
 
         halves[0].reserve_nodes(1);
@@ -72,19 +90,32 @@ impl Tree {
         Self {
             halves,
             current_half: AtomicU64::new(0),
-            hash_table: HashTable::new(hash_bytes),
+            hash_table: HashTable::new(hash_bytes, options),
             butterfly_history: ButterflyHistory::new(),
+            continuation_history: ContinuationHistory::new(),
+            capture_history: CaptureHistory::new(),
+            clock_hand: AtomicUsize::new(0),
+            collecting: AtomicBool::new(false),
+            evicted_nodes: AtomicU64::new(0),
+            stalls: AtomicU64::new(0),
         }
     }
 
     #[inline]
     pub fn clear(&self) {
+        self.halves[0].clear_links();
+        self.halves[1].clear_links();
         self.halves[0].clear();
         self.halves[1].clear();
         self.hash_table.clear();
         self.butterfly_history.clear();
+        self.continuation_history.clear();
+        self.capture_history.clear();
 
         self.current_half.store(0, Ordering::Relaxed);
+        self.clock_hand.store(0, Ordering::Relaxed);
+        self.evicted_nodes.store(0, Ordering::Relaxed);
+        self.stalls.store(0, Ordering::Relaxed);
 
         self.halves[0].reserve_nodes(1);
         self[self.root_index()].clear(Move::NULL);
@@ -120,6 +151,18 @@ impl Tree {
         self.halves[0].current_size() + self.halves[1].current_size()
     }
 
+    //Nodes dropped to make room since the tree was cleared
+    #[inline]
+    pub fn evicted_nodes(&self) -> u64 {
+        self.evicted_nodes.load(Ordering::Relaxed)
+    }
+
+    //Times every search thread had to stop until memory was reclaimed
+    #[inline]
+    pub fn stalls(&self) -> u64 {
+        self.stalls.load(Ordering::Relaxed)
+    }
+
     #[inline]
     pub fn hash_table(&self) -> &HashTable {
         &self.hash_table
@@ -130,6 +173,16 @@ impl Tree {
         &self.butterfly_history
     }
 
+    #[inline]
+    pub fn continuation_history(&self) -> &ContinuationHistory {
+        &self.continuation_history
+    }
+
+    #[inline]
+    pub fn capture_history(&self) -> &CaptureHistory {
+        &self.capture_history
+    }
+
     #[inline]
     pub fn add_visit(&self, node_idx: NodeIndex, score: WDLScore) {
         self[node_idx].add_visit(score)
@@ -141,12 +194,38 @@ impl Tree {
     }
 
     #[inline]
-    pub fn inc_threads(&self, node_idx: NodeIndex, value: u8) -> u8 {
-        self[node_idx].inc_threads(value)
+    pub fn set_bound(&self, node_idx: NodeIndex, bound: ScoreBound) {
+        self[node_idx].set_bound(bound)
+    }
+
+    //Only the LRU tree tracks which nodes are in use, the halves tree stops the search to
+    //reclaim memory and needs neither the reference bit nor the ordered thread counts
+    #[inline]
+    pub fn touch(&self, node_idx: NodeIndex, options: &EngineOptions) {
+        let node = &self[node_idx];
+        if options.memory_manager() == MemoryManager::Lru && !node.is_referenced() {
+            node.set_referenced(true)
+        }
     }
 
+    //Ordered against the LRU eviction of the children, see Tree::evict_children
     #[inline]
-    pub fn dec_threads(&self, node_idx: NodeIndex, value: u8) -> u8 {
-        self[node_idx].dec_threads(value)
+    pub fn inc_threads(&self, node_idx: NodeIndex, value: u8, options: &EngineOptions) -> u8 {
+        let ordering = match options.memory_manager() {
+            MemoryManager::Halves => Ordering::Relaxed,
+            MemoryManager::Lru => Ordering::SeqCst,
+        };
+
+        self[node_idx].inc_threads(value, ordering)
+    }
+
+    #[inline]
+    pub fn dec_threads(&self, node_idx: NodeIndex, value: u8, options: &EngineOptions) -> u8 {
+        let ordering = match options.memory_manager() {
+            MemoryManager::Halves => Ordering::Relaxed,
+            MemoryManager::Lru => Ordering::Release,
+        };
+
+        self[node_idx].dec_threads(value, ordering)
     }
 }
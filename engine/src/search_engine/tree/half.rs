@@ -1,15 +1,25 @@
 use std::{
     ops::{Index, IndexMut},
-    sync::atomic::{AtomicUsize, Ordering},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
 };
 
-use crate::{Node, NodeIndex};
+use chess::MAX_MOVES;
+
+use crate::{
+    search_engine::{engine_options::EngineOptions, large_buffer::LargeBuffer},
+    Node, NodeIndex,
+};
 
 #[derive(Debug)]
 pub struct TreeHalf {
-    nodes: Vec<Node>,
+    nodes: LargeBuffer<Node>,
     idx: AtomicUsize,
     half_index: u64,
+    free_lists: Vec<Mutex<Vec<u32>>>,
+    free_nodes: AtomicUsize,
 }
 
 impl Clone for TreeHalf {
@@ -18,6 +28,12 @@ impl Clone for TreeHalf {
             nodes: self.nodes.clone(),
             idx: AtomicUsize::new(self.idx.load(Ordering::Relaxed)),
             half_index: self.half_index,
+            free_lists: self
+                .free_lists
+                .iter()
+                .map(|list| Mutex::new(list.lock().unwrap().clone()))
+                .collect(),
+            free_nodes: AtomicUsize::new(self.free_nodes.load(Ordering::Relaxed)),
         }
     }
 }
@@ -37,21 +53,22 @@ impl IndexMut<NodeIndex> for TreeHalf {
 }
 
 impl TreeHalf {
-    pub fn new(index: u64, size: usize) -> Self {
+    pub fn new(index: u64, size: usize, options: &EngineOptions) -> Self {
         let nodes = unsafe {
-            let layout = std::alloc::Layout::array::<Node>(size).unwrap();
-            let ptr = std::alloc::alloc_zeroed(layout);
-            if ptr.is_null() {
-                std::alloc::handle_alloc_error(layout);
-            }
-
-            Vec::from_raw_parts(ptr.cast::<Node>(), size, size)
+            LargeBuffer::zeroed(
+                size,
+                options.large_pages(),
+                options.threads() as usize,
+                options.numa_policy(),
+            )
         };
 
         Self {
             nodes,
             idx: AtomicUsize::new(0),
             half_index: index,
+            free_lists: (0..=MAX_MOVES).map(|_| Mutex::new(Vec::new())).collect(),
+            free_nodes: AtomicUsize::new(0),
         }
     }
 
@@ -72,7 +89,9 @@ impl TreeHalf {
 
     #[inline]
     pub fn current_size(&self) -> usize {
-        self.idx.load(Ordering::Relaxed)
+        self.idx
+            .load(Ordering::Relaxed)
+            .saturating_sub(self.free_nodes.load(Ordering::Relaxed))
     }
 
     #[inline]
@@ -80,8 +99,18 @@ impl TreeHalf {
         self.nodes.len()
     }
 
+    //Slots handed out by the bump pointer, including released ones
+    #[inline]
+    pub fn reserved_size(&self) -> usize {
+        self.idx.load(Ordering::Relaxed).min(self.nodes.len())
+    }
+
     #[inline]
     pub fn reserve_nodes(&self, count: usize) -> Option<NodeIndex> {
+        if self.idx.load(Ordering::Relaxed) + count >= self.nodes.len() {
+            return None;
+        }
+
         let current_idx = self.idx.fetch_add(count, Ordering::Relaxed);
 
         if current_idx + count >= self.nodes.len() {
@@ -91,8 +120,56 @@ impl TreeHalf {
         Some(NodeIndex::new(self.half_index, current_idx as u64))
     }
 
+    //Free lists are indexed by the size of the block, a bigger block is split when no block
+    //of the exact size is free
+    pub fn reserve_free_nodes(&self, count: usize) -> Option<NodeIndex> {
+        if self.free_nodes.load(Ordering::Relaxed) < count {
+            return None;
+        }
+
+        for size in count..self.free_lists.len() {
+            let Some(idx) = self.free_lists[size].lock().unwrap().pop() else {
+                continue;
+            };
+
+            if size > count {
+                self.free_lists[size - count]
+                    .lock()
+                    .unwrap()
+                    .push(idx + count as u32);
+            }
+
+            self.free_nodes.fetch_sub(count, Ordering::Relaxed);
+            return Some(NodeIndex::new(self.half_index, u64::from(idx)));
+        }
+
+        None
+    }
+
+    pub fn release_nodes(&self, node_idx: NodeIndex, count: usize) {
+        self.free_lists[count]
+            .lock()
+            .unwrap()
+            .push(node_idx.idx() as u32);
+        self.free_nodes.fetch_add(count, Ordering::Relaxed);
+    }
+
+    //Reserved nodes keep their links after a clear, the LRU clock sweeps slots instead of
+    //walking the tree and must not find children in nodes that are not reserved again yet
+    pub fn clear_links(&self) {
+        for node in &self.nodes[..self.reserved_size()] {
+            node.clear_children();
+        }
+    }
+
     pub fn clear(&self) {
         self.idx.store(0, Ordering::Relaxed);
+
+        for list in &self.free_lists {
+            list.lock().unwrap().clear();
+        }
+
+        self.free_nodes.store(0, Ordering::Relaxed);
     }
 
     #[inline]
@@ -1,4 +1,4 @@
-use std::sync::atomic::{AtomicU16, AtomicU32, AtomicU64, AtomicU8, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU16, AtomicU32, AtomicU64, AtomicU8, Ordering};
 
 use chess::Move;
 
@@ -13,7 +13,8 @@ mod lock;
 mod node_index;
 mod wdl_score;
 
-pub use game_state::GameState;
+pub use game_state::{GameState, ScoreBound};
+pub use lock::{take_contended_reads, take_contended_writes};
 pub use node_index::NodeIndex;
 pub use wdl_score::{AtomicWDLScore, WDLScore};
 
@@ -24,13 +25,14 @@ pub struct Node {
     cumulative_score: AtomicWDLScore,
     squared_score: AtomicU64,
     children_start_index: IndexLock,
-    children_count: AtomicU8,
+    children_count: AtomicU16,
     policy: AtomicU16,
     state: AtomicGameState,
     threads: AtomicU8,
     gini_impurity: AtomicU16,
     sac_strength: AtomicU8,
     move_traits: AtomicU8,
+    referenced: AtomicBool,
 }
 
 impl Clone for Node {
@@ -41,13 +43,14 @@ impl Clone for Node {
             cumulative_score: self.cumulative_score.clone(),
             squared_score: AtomicU64::new(self.squared_score.load(Ordering::Relaxed)),
             children_start_index: self.children_start_index.clone(),
-            children_count: AtomicU8::new(self.children_count.load(Ordering::Relaxed)),
+            children_count: AtomicU16::new(self.children_count.load(Ordering::Relaxed)),
             state: self.state.clone(),
             policy: AtomicU16::new(self.policy.load(Ordering::Relaxed)),
             threads: AtomicU8::new(self.threads.load(Ordering::Relaxed)),
             gini_impurity: AtomicU16::new(self.gini_impurity.load(Ordering::Relaxed)),
             sac_strength: AtomicU8::new(self.sac_strength.load(Ordering::Relaxed)),
             move_traits: AtomicU8::new(self.move_traits.load(Ordering::Relaxed)),
+            referenced: AtomicBool::new(self.is_referenced()),
         }
     }
 }
@@ -66,13 +69,14 @@ impl Node {
             cumulative_score: AtomicWDLScore::default(),
             squared_score: AtomicU64::new(0),
             children_start_index: IndexLock::new(NodeIndex::NULL),
-            children_count: AtomicU8::new(0),
+            children_count: AtomicU16::new(0),
             state: AtomicGameState::new(GameState::Ongoing),
             policy: AtomicU16::new(0),
             threads: AtomicU8::new(0),
             gini_impurity: AtomicU16::new(0),
             sac_strength: AtomicU8::new(0),
             move_traits: AtomicU8::new(0),
+            referenced: AtomicBool::new(false),
         }
     }
 
@@ -98,6 +102,8 @@ impl Node {
             .store(node.sac_strength(), Ordering::Relaxed);
         self.move_traits
             .store(node.move_traits.load(Ordering::Relaxed), Ordering::Relaxed);
+        self.referenced
+            .store(node.is_referenced(), Ordering::Relaxed);
     }
 
     #[inline]
@@ -112,12 +118,16 @@ impl Node {
         self.gini_impurity.store(0, Ordering::Relaxed);
         self.sac_strength.store(0, Ordering::Relaxed);
         self.move_traits.store(0, Ordering::Relaxed);
+        self.referenced.store(false, Ordering::Relaxed);
         self.clear_children();
     }
 
+    //Count goes first, a reader that still sees the old index then walks at most the old
+    //block, which is not reused before the next expansion
     pub fn clear_children(&self) {
-        self.children_index_mut().store(NodeIndex::NULL);
+        let children_idx = self.children_index_mut();
         self.children_count.store(0, Ordering::Relaxed);
+        children_idx.store(NodeIndex::NULL);
     }
 
     #[inline]
@@ -141,6 +151,26 @@ impl Node {
             / f64::from(self.visits())
     }
 
+    #[inline]
+    pub fn score_variance(&self) -> f64 {
+        if self.visits() == 0 {
+            return 0.0;
+        }
+
+        (self.squared_score() - self.score().single().powi(2)).max(0.0)
+    }
+
+    #[inline]
+    pub fn standard_error(&self) -> f64 {
+        (self.score_variance() / f64::from(self.visits().max(1))).sqrt()
+    }
+
+    //Score moved by z standard errors, negative z gives the lower confidence bound
+    #[inline]
+    pub fn score_bound(&self, z: f64) -> WDLScore {
+        self.score().shifted(z * self.standard_error())
+    }
+
     #[inline]
     pub fn children_index(&self) -> NodeIndex {
         self.children_start_index.read()
@@ -151,6 +181,16 @@ impl Node {
         self.children_start_index.write()
     }
 
+    #[inline]
+    pub fn try_children_index(&self) -> Option<NodeIndex> {
+        self.children_start_index.try_read()
+    }
+
+    #[inline]
+    pub fn try_children_index_mut(&self) -> Option<IndexLockGuard<'_>> {
+        self.children_start_index.try_write()
+    }
+
     #[inline]
     pub fn children_count(&self) -> usize {
         self.children_count.load(Ordering::Relaxed) as usize
@@ -161,6 +201,11 @@ impl Node {
         self.state.get()
     }
 
+    #[inline]
+    pub fn bound(&self) -> ScoreBound {
+        self.state.bound()
+    }
+
     #[inline]
     pub fn policy(&self) -> f64 {
         self.policy.load(Ordering::Relaxed) as f64 / f64::from(u16::MAX)
@@ -206,6 +251,11 @@ impl Node {
         self.state.set(state)
     }
 
+    #[inline]
+    pub fn set_bound(&self, bound: ScoreBound) {
+        self.state.set_bound(bound)
+    }
+
     #[inline]
     pub fn set_policy(&self, policy: f64) {
         self.policy
@@ -213,13 +263,24 @@ impl Node {
     }
 
     #[inline]
-    pub fn inc_threads(&self, value: u8) -> u8 {
-        self.threads.fetch_add(value, Ordering::Relaxed)
+    pub fn inc_threads(&self, value: u8, ordering: Ordering) -> u8 {
+        self.threads.fetch_add(value, ordering)
     }
 
     #[inline]
-    pub fn dec_threads(&self, value: u8) -> u8 {
-        self.threads.fetch_sub(value, Ordering::Relaxed)
+    pub fn dec_threads(&self, value: u8, ordering: Ordering) -> u8 {
+        self.threads.fetch_sub(value, ordering)
+    }
+
+    //Set by iterations through the node and cleared by the LRU clock when it passes by
+    #[inline]
+    pub fn is_referenced(&self) -> bool {
+        self.referenced.load(Ordering::Relaxed)
+    }
+
+    #[inline]
+    pub fn set_referenced(&self, referenced: bool) {
+        self.referenced.store(referenced, Ordering::Relaxed);
     }
 
     #[inline]
@@ -263,7 +324,7 @@ impl Node {
     #[inline]
     pub fn set_children_count(&self, chilren_count: usize) {
         self.children_count
-            .store(chilren_count as u8, Ordering::Relaxed);
+            .store(chilren_count as u16, Ordering::Relaxed);
     }
 
     pub fn map_children<F: FnMut(NodeIndex)>(&self, func: F) {
@@ -271,7 +332,11 @@ impl Node {
     }
 
     pub fn map_children_with_limit<F: FnMut(NodeIndex)>(&self, limit: usize, mut func: F) {
+        //Read without the lock, the children can be cleared by another thread in between
         let children_idx = self.children_index();
+        if children_idx.is_null() {
+            return;
+        }
 
         for child_idx in 0..limit.min(self.children_count()) {
             func(children_idx + child_idx)
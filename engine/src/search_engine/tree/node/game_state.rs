@@ -1,6 +1,7 @@
 use std::sync::atomic::{AtomicU8, Ordering};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GameState {
     Ongoing,
     Draw,
@@ -8,6 +9,21 @@ pub enum GameState {
     Loss(u8),
 }
 
+//Proven range of the result for the side to move of a node that is not solved yet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScoreBound {
+    #[default]
+    None,
+    AtLeastDraw,
+    AtMostDraw,
+}
+
+//Bounds are stored in the upper bits of the state byte, so setting a state clears them
+const STATE_MASK: u8 = 0b111;
+const AT_LEAST_DRAW: u8 = 0b01_000;
+const AT_MOST_DRAW: u8 = 0b10_000;
+
 #[derive(Debug, Default)]
 pub struct AtomicGameState {
     state: AtomicU8,
@@ -49,7 +65,7 @@ impl AtomicGameState {
         let state = self.state.load(Ordering::Relaxed);
         let payload = self.payload.load(Ordering::Relaxed);
 
-        match state {
+        match state & STATE_MASK {
             0 => GameState::Ongoing,
             1 => GameState::Draw,
             2 => GameState::Win(payload),
@@ -57,4 +73,31 @@ impl AtomicGameState {
             _ => unreachable!(),
         }
     }
+
+    pub fn bound(&self) -> ScoreBound {
+        let state = self.state.load(Ordering::Relaxed);
+
+        if state & AT_LEAST_DRAW != 0 {
+            ScoreBound::AtLeastDraw
+        } else if state & AT_MOST_DRAW != 0 {
+            ScoreBound::AtMostDraw
+        } else {
+            ScoreBound::None
+        }
+    }
+
+    //Bounds only apply to ongoing nodes, a concurrently proven state takes precedence
+    pub fn set_bound(&self, bound: ScoreBound) {
+        let bits = match bound {
+            ScoreBound::None => 0,
+            ScoreBound::AtLeastDraw => AT_LEAST_DRAW,
+            ScoreBound::AtMostDraw => AT_MOST_DRAW,
+        };
+
+        let _ = self
+            .state
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |state| {
+                (state & STATE_MASK == 0).then_some(bits)
+            });
+    }
 }
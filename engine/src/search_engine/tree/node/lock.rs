@@ -1,37 +1,88 @@
-use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::{
+    cell::Cell,
+    sync::atomic::{AtomicU32, Ordering},
+};
 
 use crate::NodeIndex;
 
+//Stored in place of the children index while a thread expands or moves the children
+const EXPANDING: u32 = u32::MAX - 1;
+
+thread_local! {
+    static CONTENDED_READS: Cell<u64> = const { Cell::new(0) };
+    static CONTENDED_WRITES: Cell<u64> = const { Cell::new(0) };
+}
+
+//Returns and resets the number of accesses of this thread that had to wait for another
+//thread to finish expanding
+pub fn take_contended_reads() -> u64 {
+    CONTENDED_READS.with(|counter| counter.replace(0))
+}
+
+pub fn take_contended_writes() -> u64 {
+    CONTENDED_WRITES.with(|counter| counter.replace(0))
+}
+
+struct Backoff(u32);
+
+impl Backoff {
+    const SPIN_LIMIT: u32 = 6;
+
+    fn new() -> Self {
+        Self(0)
+    }
+
+    //Spins for exponentially longer, then gives the core away to the expanding thread
+    fn wait(&mut self) {
+        if self.0 <= Self::SPIN_LIMIT {
+            for _ in 0..(1 << self.0) {
+                std::hint::spin_loop();
+            }
+
+            self.0 += 1;
+        } else {
+            std::thread::yield_now();
+        }
+    }
+
+    fn waited(&self) -> bool {
+        self.0 > 0
+    }
+}
+
 #[derive(Debug)]
-pub struct IndexLockGuard<'a>(&'a IndexLock);
+pub struct IndexLockGuard<'a> {
+    lock: &'a IndexLock,
+    value: Cell<NodeIndex>,
+}
 
 impl Drop for IndexLockGuard<'_> {
     fn drop(&mut self) {
-        self.0.lock.store(false, Ordering::Release);
+        self.lock
+            .value
+            .store(u32::from(self.value.get()), Ordering::Release);
     }
 }
 
 impl IndexLockGuard<'_> {
     pub fn value(&self) -> NodeIndex {
-        NodeIndex::from(self.0.value.load(Ordering::Acquire))
+        self.value.get()
     }
 
     pub fn store(&self, index: NodeIndex) {
-        self.0.value.store(u32::from(index), Ordering::Relaxed);
+        self.value.set(index);
     }
 }
 
 #[derive(Debug)]
 pub struct IndexLock {
     value: AtomicU32,
-    lock: AtomicBool,
 }
 
 impl Clone for IndexLock {
     fn clone(&self) -> Self {
         Self {
             value: AtomicU32::new(self.value.load(Ordering::Relaxed)),
-            lock: AtomicBool::new(self.lock.load(Ordering::Relaxed)),
         }
     }
 }
@@ -40,27 +91,70 @@ impl IndexLock {
     pub fn new(index: NodeIndex) -> Self {
         Self {
             value: AtomicU32::new(u32::from(index)),
-            lock: AtomicBool::new(false),
         }
     }
 
     pub fn read(&self) -> NodeIndex {
-        while self.lock.load(Ordering::Acquire) {
-            std::hint::spin_loop();
+        let mut backoff = Backoff::new();
+
+        loop {
+            let value = self.value.load(Ordering::Acquire);
+            if value != EXPANDING {
+                if backoff.waited() {
+                    CONTENDED_READS.with(|counter| counter.set(counter.get() + 1));
+                }
+
+                return NodeIndex::from(value);
+            }
+
+            backoff.wait();
         }
+    }
 
-        NodeIndex::from(self.value.load(Ordering::Acquire))
+    //Returns None instead of waiting while another thread expands
+    pub fn try_read(&self) -> Option<NodeIndex> {
+        let value = self.value.load(Ordering::Acquire);
+        (value != EXPANDING).then(|| NodeIndex::from(value))
     }
 
-    pub fn write(&self) -> IndexLockGuard<'_> {
-        while self
-            .lock
-            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
-            .is_err()
-        {
-            std::hint::spin_loop();
+    pub fn try_write(&self) -> Option<IndexLockGuard<'_>> {
+        let value = self.value.load(Ordering::Relaxed);
+        if value == EXPANDING {
+            return None;
         }
 
-        IndexLockGuard(self)
+        self.value
+            .compare_exchange(value, EXPANDING, Ordering::SeqCst, Ordering::Relaxed)
+            .ok()
+            .map(|_| IndexLockGuard {
+                lock: self,
+                value: Cell::new(NodeIndex::from(value)),
+            })
+    }
+
+    //Swaps the sentinel in, the guard publishes the new index when dropped
+    pub fn write(&self) -> IndexLockGuard<'_> {
+        let mut backoff = Backoff::new();
+
+        loop {
+            let value = self.value.load(Ordering::Relaxed);
+            if value != EXPANDING
+                && self
+                    .value
+                    .compare_exchange(value, EXPANDING, Ordering::Acquire, Ordering::Relaxed)
+                    .is_ok()
+            {
+                if backoff.waited() {
+                    CONTENDED_WRITES.with(|counter| counter.set(counter.get() + 1));
+                }
+
+                return IndexLockGuard {
+                    lock: self,
+                    value: Cell::new(NodeIndex::from(value)),
+                };
+            }
+
+            backoff.wait();
+        }
     }
 }
@@ -72,6 +72,7 @@ impl AtomicWDLScore {
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WDLScore(f64, f64);
 impl WDLScore {
     pub const WIN: Self = Self(1.0, 0.0);
@@ -103,6 +104,13 @@ impl WDLScore {
         Self(self.lose_chance(), self.draw_chance())
     }
 
+    //Moves the single score by delta, trading wins for losses and keeping draws
+    #[inline]
+    pub fn shifted(&self, delta: f64) -> Self {
+        let delta = delta.clamp(-self.win_chance(), self.lose_chance().max(0.0));
+        Self(self.win_chance() + delta, self.draw_chance())
+    }
+
     #[inline]
     pub const fn single(&self) -> f64 {
         self.win_chance() + self.draw_chance() * 0.5
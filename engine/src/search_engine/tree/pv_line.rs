@@ -30,6 +30,11 @@ impl PvLine {
         self.0[0].mv()
     }
 
+    #[inline]
+    pub fn moves(&self) -> impl Iterator<Item = Move> + '_ {
+        self.0.iter().map(|node| node.mv())
+    }
+
     #[inline]
     pub fn first_node(&self) -> Node {
         self.0[0].clone()
@@ -249,6 +249,15 @@ impl Tree {
             1.0,
         );
 
+        //Half width of the confidence interval of the score, in pawns
+        let z = search_engine.options().confidence_z();
+        let interval = (node.score_bound(z).cp() - node.score_bound(-z).cp()).abs();
+        let interval = match node.state() {
+            GameState::Ongoing => format!("{:.2}", interval as f32 / 200.0),
+            _ => String::new(),
+        }
+        .align_to_right(5);
+
         let visits = format!("{}", node.visits()).align_to_right(9);
 
         let policy = heat_color(
@@ -270,7 +279,8 @@ impl Tree {
         println!(
             "{}",
             format!(
-                "{prefix}  {score} score  {} visits  {} policy  {}  sac  {}",
+                "{prefix}  {score} score  {} ci  {} visits  {} policy  {}  sac  {}",
+                interval.white(),
                 visits.to_string().white(),
                 policy,
                 sac_str.white(),
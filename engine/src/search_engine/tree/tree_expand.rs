@@ -1,8 +1,12 @@
-use chess::{ChessBoard, Move, Piece, Side};
+use chess::{ChessBoard, Move, Piece, Side, Square, MAX_MOVES};
 
 use crate::{
-    search_engine::engine_options::EngineOptions, BasePolicyNetwork, NodeIndex,
-    Stage1PolicyNetwork, Stage2PolicyNetwork, Stage3PolicyNetwork, Tree, WDLScore,
+    search_engine::{
+        capture_history::CaptureHistory, continuation_history::ContinuationHistory,
+        engine_options::EngineOptions,
+    },
+    BasePolicyNetwork, NodeIndex, Stage1PolicyNetwork, Stage2PolicyNetwork, Stage3PolicyNetwork,
+    Tree, WDLScore,
 };
 
 impl Tree {
@@ -48,13 +52,16 @@ impl Tree {
             engine_options.base_pst()
         };
 
-        let mut policy = [(Move::NULL, 0f64, 0u8, false, false, 0u8); 256];
+        let parent = ContinuationHistory::parent_key(board, self[node_idx].mv());
+
+        let mut policy = [(Move::NULL, 0f64, 0u8, false, false, 0u8); MAX_MOVES];
         let mut policy_len = 0usize;
         let mut max = f64::NEG_INFINITY;
         let mut total = 0f64;
 
         board.map_legal_moves(|mv| {
-            let see_108 = board.see(mv, -108);
+            let see_score = board.see_score(mv);
+            let see_108 = see_score >= -108;
             let mut p =
                 network.forward(board, &policy_base, mv, see_108, engine_options.chess960()) as f64;
 
@@ -63,15 +70,13 @@ impl Tree {
             let is_sacrifice = if !see_108 {
                 true
             } else if mva > 0.0 && mva <= 108.0 {
-                !board.see(mv, 0)
+                see_score < 0
             } else {
                 false
             };
 
             let policy_bonus = usize::from(!see_108) as f64 * mva * engine_options.policy_sac();
-            let history_bonus =
-                self.butterfly_history()
-                    .get_bonus(board.side(), mv, engine_options);
+            let history_bonus = self.history_bonus(board, parent, mv, engine_options);
             let sac_strength = if is_sacrifice {
                 (mva / 5.0).round() as u8
             } else {
@@ -96,7 +101,7 @@ impl Tree {
 
         let policy = &mut policy[..policy_len];
 
-        let start_index = self.current_half().reserve_nodes(policy.len())?;
+        let start_index = self.reserve_children(policy.len(), engine_options)?;
 
         for (_, p, _, _, _, _) in policy.iter_mut() {
             *p = ((*p - max) / pst).exp();
@@ -178,7 +183,9 @@ impl Tree {
             engine_options.base_pst()
         };
 
-        let mut policy = [(0f64, 0u8, false, false, 0u8); 256];
+        let parent = ContinuationHistory::parent_key(board, self[node_idx].mv());
+
+        let mut policy = [(0f64, 0u8, false, false, 0u8); MAX_MOVES];
         let mut policy_len = 0usize;
         let mut max = f64::NEG_INFINITY;
         let mut total = 0f64;
@@ -186,7 +193,8 @@ impl Tree {
         self[node_idx].map_children(|child_idx| {
             let mv = self[child_idx].mv();
 
-            let see_108 = board.see(mv, -108);
+            let see_score = board.see_score(mv);
+            let see_108 = see_score >= -108;
             let mut p =
                 network.forward(board, &policy_base, mv, see_108, engine_options.chess960()) as f64;
 
@@ -195,15 +203,13 @@ impl Tree {
             let is_sacrifice = if !see_108 {
                 true
             } else if mva > 0.0 && mva <= 108.0 {
-                !board.see(mv, 0)
+                see_score < 0
             } else {
                 false
             };
 
             let policy_bonus = usize::from(!see_108) as f64 * mva * engine_options.policy_sac();
-            let history_bonus =
-                self.butterfly_history()
-                    .get_bonus(board.side(), mv, engine_options);
+            let history_bonus = self.history_bonus(board, parent, mv, engine_options);
             let sac_strength = if is_sacrifice {
                 (mva / 5.0).round() as u8
             } else {
@@ -252,10 +258,30 @@ impl Tree {
         let gini_impurity = (1.0 - squares).clamp(0.0, 1.0);
         self[node_idx].set_gini_impurity(gini_impurity);
     }
+
+    fn history_bonus(
+        &self,
+        board: &ChessBoard,
+        parent: Option<(Piece, Square)>,
+        mv: Move,
+        engine_options: &EngineOptions,
+    ) -> f64 {
+        let side = board.side();
+        let piece = board.moved_piece(mv);
+        let victim = CaptureHistory::victim(board, mv);
+
+        self.butterfly_history().get_bonus(side, mv, engine_options)
+            + self
+                .continuation_history()
+                .get_bonus(side, parent, piece, mv, engine_options)
+            + self
+                .capture_history()
+                .get_bonus(side, piece, mv, victim, engine_options)
+    }
 }
 
 fn move_traits(mv: Move, board: &ChessBoard) -> (bool, bool, u8) {
-    let attacker = board.piece_on_square(mv.from_square());
+    let attacker = board.moved_piece(mv);
     let victim = board.piece_on_square(mv.to_square());
 
     let own_king_square = if attacker == Piece::KING {
@@ -283,7 +309,7 @@ fn move_traits(mv: Move, board: &ChessBoard) -> (bool, bool, u8) {
 }
 
 fn mva_lvv(mv: Move, board: &ChessBoard, options: &EngineOptions) -> f64 {
-    let attacker = board.piece_on_square(mv.from_square());
+    let attacker = board.moved_piece(mv);
     let victim = board.piece_on_square(mv.to_square());
 
     if attacker == Piece::KING {
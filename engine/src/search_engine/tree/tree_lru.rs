@@ -1,8 +1,27 @@
+use std::sync::atomic::{fence, Ordering};
+
 use chess::Move;
 
-use crate::{NodeIndex, Tree};
+use crate::{search_engine::engine_options::EngineOptions, MemoryManager, NodeIndex, Tree};
 
 impl Tree {
+    //Called when an iteration ran out of nodes and every search thread has stopped
+    pub fn reclaim_memory(&self, options: &EngineOptions) {
+        self.stalls.fetch_add(1, Ordering::Relaxed);
+
+        match options.memory_manager() {
+            MemoryManager::Halves => {
+                let dropped = self.halves[self.current_half_index() as usize ^ 1].current_size();
+                self.evicted_nodes
+                    .fetch_add(dropped as u64, Ordering::Relaxed);
+                self.swap_half();
+            }
+            MemoryManager::Lru => {
+                self.evict_lru(self.eviction_batch(options));
+            }
+        }
+    }
+
     pub fn swap_half(&self) {
         let old_root = self.root_index();
         let old_half = self
@@ -18,7 +37,12 @@ impl Tree {
         self.copy_across(old_root, 1, new_root);
     }
 
-    pub fn update_node(&self, node_idx: NodeIndex) -> Option<()> {
+    pub fn update_node(&self, node_idx: NodeIndex, options: &EngineOptions) -> Option<()> {
+        //Children of the LRU tree live in either half and are never moved
+        if options.memory_manager() == MemoryManager::Lru {
+            return Some(());
+        }
+
         if self[node_idx].children_index().half() == self.current_half_index() {
             return Some(());
         }
@@ -57,3 +81,169 @@ impl Tree {
         }
     }
 }
+
+impl Tree {
+    pub fn reserve_children(&self, count: usize, options: &EngineOptions) -> Option<NodeIndex> {
+        if options.memory_manager() == MemoryManager::Halves {
+            return self.current_half().reserve_nodes(count);
+        }
+
+        loop {
+            if let Some(node_idx) = self.reserve_lru(count) {
+                return Some(node_idx);
+            }
+
+            //One thread evicts a batch while the others wait for it. When nothing could
+            //be evicted the iteration fails and the search stops to reclaim memory
+            if self
+                .collecting
+                .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+            {
+                let evicted = self.evict_lru(self.eviction_batch(options));
+                self.collecting.store(false, Ordering::Release);
+
+                if evicted == 0 {
+                    return self.reserve_lru(count);
+                }
+            } else {
+                while self.collecting.load(Ordering::Acquire) {
+                    std::thread::yield_now();
+                }
+            }
+        }
+    }
+
+    fn reserve_lru(&self, count: usize) -> Option<NodeIndex> {
+        let current = self.current_half_index() as usize;
+
+        self.halves[current]
+            .reserve_nodes(count)
+            .or_else(|| self.halves[current ^ 1].reserve_nodes(count))
+            .or_else(|| self.halves[current].reserve_free_nodes(count))
+            .or_else(|| self.halves[current ^ 1].reserve_free_nodes(count))
+    }
+
+    fn eviction_batch(&self, options: &EngineOptions) -> usize {
+        ((self.max_size() as f64 * options.lru_eviction_batch()) as usize).max(1)
+    }
+
+    //Second chance clock over the slots of both halves. Nodes used since the hand last passed
+    //lose their reference bit and are kept, the children of the others are evicted until
+    //the batch is reached or the hand went around twice. Returns the number of evicted nodes
+    pub fn evict_lru(&self, batch: usize) -> usize {
+        let half_size = self.halves[0].max_size();
+        let slots = half_size * 2;
+        let root_idx = self.root_index();
+
+        //Released blocks only return to the free lists after the pass, so nodes in an
+        //already evicted subtree still read as childless instead of as reused nodes
+        let mut released = Vec::new();
+        let mut evicted = 0;
+        let mut hand = self.clock_hand.load(Ordering::Relaxed) % slots;
+
+        for _ in 0..slots * 2 {
+            if evicted >= batch {
+                break;
+            }
+
+            let half = hand / half_size;
+            let idx = hand % half_size;
+            hand = (hand + 1) % slots;
+
+            if idx >= self.halves[half].reserved_size() {
+                continue;
+            }
+
+            let node_idx = NodeIndex::new(half as u64, idx as u64);
+            let node = &self[node_idx];
+            if node_idx == root_idx || node.children_count() == 0 {
+                continue;
+            }
+
+            if node.is_referenced() {
+                node.set_referenced(false);
+                continue;
+            }
+
+            evicted += self.evict_children(node_idx, &mut released);
+        }
+
+        self.clock_hand.store(hand, Ordering::Relaxed);
+
+        for (node_idx, count) in released {
+            self.halves[node_idx.half() as usize].release_nodes(node_idx, count);
+        }
+
+        self.evicted_nodes
+            .fetch_add(evicted as u64, Ordering::Relaxed);
+
+        evicted
+    }
+
+    fn evict_children(&self, node_idx: NodeIndex, released: &mut Vec<(NodeIndex, usize)>) -> usize {
+        let node = &self[node_idx];
+        let Some(children_idx) = node.try_children_index_mut() else {
+            return 0;
+        };
+
+        let count = node.children_count();
+        if children_idx.value().is_null() || count == 0 {
+            return 0;
+        }
+
+        //A thread adds its virtual loss before reading the children index, with the
+        //sentinel already in place one of the two sides sees the other. Threads inside
+        //the subtree keep the count above zero until they are done with it
+        fence(Ordering::SeqCst);
+        if node.threads() > 0 {
+            return 0;
+        }
+        fence(Ordering::Acquire);
+
+        let evicted = self.release_children(children_idx.value(), count, NodeIndex::NULL, released);
+
+        node.set_children_count(0);
+        children_idx.store(NodeIndex::NULL);
+
+        evicted
+    }
+
+    //Detaches the whole subtree below the block, except the children of keep, and collects
+    //the released blocks. Returns the number of released nodes
+    pub(super) fn release_children(
+        &self,
+        children_idx: NodeIndex,
+        count: usize,
+        keep: NodeIndex,
+        released: &mut Vec<(NodeIndex, usize)>,
+    ) -> usize {
+        let mut evicted = count;
+
+        for child_idx in 0..count {
+            let child_idx = children_idx + child_idx;
+            if child_idx == keep {
+                continue;
+            }
+
+            let child = &self[child_idx];
+            let grandchildren_idx = child.children_index_mut();
+            let grandchildren_count = child.children_count();
+
+            if !grandchildren_idx.value().is_null() && grandchildren_count > 0 {
+                evicted += self.release_children(
+                    grandchildren_idx.value(),
+                    grandchildren_count,
+                    keep,
+                    released,
+                );
+            }
+
+            child.set_children_count(0);
+            grandchildren_idx.store(NodeIndex::NULL);
+        }
+
+        released.push((children_idx, count));
+        evicted
+    }
+}
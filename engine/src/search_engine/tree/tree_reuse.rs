@@ -1,6 +1,6 @@
-use chess::ChessPosition;
+use chess::{ChessBoard, ChessPosition, Game};
 
-use crate::{search_engine::engine_options::EngineOptions, NodeIndex, Tree};
+use crate::{search_engine::engine_options::EngineOptions, MemoryManager, NodeIndex, Tree};
 
 impl Tree {
     pub fn try_reuse(
@@ -19,9 +19,54 @@ impl Tree {
             return None;
         }
 
-        let result = result.unwrap();
+        self.promote_to_root(result.unwrap(), target.board(), options)
+    }
+
+    pub fn try_reuse_game(
+        &self,
+        game: &Game,
+        target: &Game,
+        options: &EngineOptions,
+    ) -> Option<()> {
+        let common_prefix = game.common_prefix(target);
+
+        //Target does not continue the current game, so the best we can do is look for a transposition
+        if common_prefix != Some(game.ply()) {
+            return self.try_reuse(&game.position(), &target.position(), options);
+        }
 
-        let new_root = &self[result];
+        if target.ply() == game.ply() {
+            return Some(());
+        }
+
+        let mut node_idx = self.root_index();
+        for &mv in &target.moves()[game.ply()..] {
+            let mut next_idx = None;
+            self[node_idx].map_children(|child_idx| {
+                if self[child_idx].mv() == mv {
+                    next_idx = Some(child_idx);
+                }
+            });
+
+            match next_idx {
+                Some(child_idx) => node_idx = child_idx,
+                None => {
+                    self.clear();
+                    return None;
+                }
+            }
+        }
+
+        self.promote_to_root(node_idx, target.board(), options)
+    }
+
+    fn promote_to_root(
+        &self,
+        node_idx: NodeIndex,
+        target: &ChessBoard,
+        options: &EngineOptions,
+    ) -> Option<()> {
+        let new_root = &self[node_idx];
         let children_idx = new_root.children_index();
         let count = new_root.children_count();
 
@@ -32,12 +77,57 @@ impl Tree {
 
         let old_root_children_idx = self.root_node().children_index();
 
+        //The LRU tree points the root at the children in place and frees everything else
+        if options.memory_manager() == MemoryManager::Lru {
+            let mut released = Vec::new();
+            self.release_children(
+                old_root_children_idx,
+                self.root_node().children_count(),
+                node_idx,
+                &mut released,
+            );
+
+            self[self.root_index()].set_to(new_root);
+            self[self.root_index()].set_children_count(count);
+            self.root_node().children_index_mut().store(children_idx);
+
+            //The old slot of the new root is released with its siblings, the clock would
+            //otherwise find the children of the root through it
+            new_root.clear_children();
+
+            for (released_idx, released_count) in released {
+                self.halves[released_idx.half() as usize]
+                    .release_nodes(released_idx, released_count);
+            }
+
+            self.relabel_root(target, options);
+
+            return Some(());
+        }
+
+        //A position deeper in the game can have more moves than the old root, and copying them
+        //into the old block would overwrite the nodes that follow it
+        let root_children_idx = if count <= self.root_node().children_count() {
+            old_root_children_idx
+        } else {
+            match self.current_half().reserve_nodes(count) {
+                Some(children_idx) => children_idx,
+                None => {
+                    self.clear();
+                    return None;
+                }
+            }
+        };
+
         self[self.root_index()].set_to(new_root);
         self[self.root_index()].set_children_count(count);
 
-        self.copy_across(children_idx, count, old_root_children_idx);
+        self.copy_across(children_idx, count, root_children_idx);
+        self.root_node()
+            .children_index_mut()
+            .store(root_children_idx);
 
-        self.relabel_root(target.board(), options);
+        self.relabel_root(target, options);
 
         Some(())
     }
@@ -50,37 +50,10 @@ impl Tree {
         options: &EngineOptions,
     ) -> Option<NodeIndex> {
         let parent_node = &self[parent_idx];
-        let parent_score = parent_node.score().reversed();
-
-        self.select_child_by_key(parent_idx, |node| match node.state() {
-            GameState::Loss(x) => 256.0 - x as f64,
-            GameState::Win(x) => -256.0 + x as f64,
-            _ => {
-                let mut score = node.score().single_with_score(draw_score);
-
-                if node.sac_strength() != 0
-                    && parent_score.single() > 0.4
-                    && parent_score.single() < 0.9
-                {
-                    let below_ramp = (((parent_score.single() - 0.4) / (0.51 - 0.4)).min(1.0)).powi(5);
-                    let sac_multiplier = below_ramp
-                        * (1.0 + (parent_score.single() - 0.75).max(0.0) * options.sac_scaling());
-                    score += (options.selection_sac_bonus() + node.sac_strength() as f64 / 20000.0)
-                        * sac_multiplier;
-                }
-
-                score += f64::from(node.pawn_push_strength()).sqrt() * options.selection_pawn_push_bonus();
-
-                if node.is_king_opposite_sides() {
-                    score += options.selection_castle_bonus();
-                }
-
-                if node.is_queen_trade() {
-                    score -= options.selection_queen_trade_penalty();
-                }
-
-                score
-            }
+        let selection = options.best_move_selection();
+
+        self.select_child_by_key(parent_idx, |node| {
+            selection.child_key(node, parent_node, draw_score, options)
         })
     }
 
@@ -121,7 +94,7 @@ impl Tree {
         let root = self.root_node();
 
         let draw_score = options.get_draw_score_blend(root.score());
-        let parent_score = root.score().reversed();
+        let selection = options.best_move_selection();
 
         root.map_children(|child_idx| {
             let node = &self[child_idx];
@@ -130,33 +103,7 @@ impl Tree {
                 return;
             }
 
-            let mut child_score = match node.state() {
-                GameState::Loss(x) => 256.0 - x as f64,
-                GameState::Win(x) => -256.0 + x as f64,
-                _ => node.score().single_with_score(draw_score),
-            };
-
-            if node.sac_strength() != 0
-                && parent_score.single() > 0.4
-                && parent_score.single() < 0.9
-            {
-                let below_ramp = (((parent_score.single() - 0.4) / (0.51 - 0.4)).min(1.0)).powi(2);
-                let sac_multiplier = below_ramp
-                    * (1.0 + (parent_score.single() - 0.75).max(0.0) * options.sac_scaling());
-                child_score += (options.selection_sac_bonus()
-                    + node.sac_strength() as f64 / 20000.0)
-                    * sac_multiplier;
-            }
-
-            child_score += f64::from(node.pawn_push_strength()) * options.selection_pawn_push_bonus();
-
-            if node.is_king_opposite_sides() {
-                child_score += options.selection_castle_bonus();
-            }
-
-            if node.is_queen_trade() {
-                child_score -= options.selection_queen_trade_penalty();
-            }
+            let child_score = selection.pv_key(node, root, draw_score, options);
 
             chilren_nodes.push((child_idx, child_score))
         });
@@ -165,6 +112,16 @@ impl Tree {
             return PvLine::new(&Node::new());
         }
 
+        //Proven losing moves are only reported when nothing else is left
+        let is_proven_loss =
+            |child_idx: NodeIndex| matches!(self[child_idx].state(), GameState::Win(_));
+        if chilren_nodes
+            .iter()
+            .any(|&(child_idx, _)| !is_proven_loss(child_idx))
+        {
+            chilren_nodes.retain(|&(child_idx, _)| !is_proven_loss(child_idx));
+        }
+
         chilren_nodes.sort_by(|(_, a), (_, b)| b.total_cmp(a));
 
         let (pv_node_idx, _) = chilren_nodes[index.min(chilren_nodes.len() - 1)];
@@ -0,0 +1,87 @@
+use std::marker::PhantomData;
+
+use chess::Move;
+
+use crate::{search_engine::SearchStats, PvLine, SearchEngine, SearchLimits, SearchReport};
+
+//Stateful search hooks, passed to SearchEngine::search_with_observer as a trait object. The
+//start, the end, the half swaps and the final stats run on the thread that called the search,
+//every other callback runs on the main search thread while the search is going
+pub trait SearchObserver: Send {
+    //How often on_stats is called during the search
+    fn stats_per_second(&self) -> f64 {
+        1.0
+    }
+
+    #[allow(unused)]
+    fn on_search_started(&mut self, search_limits: &SearchLimits, search_engine: &SearchEngine) {}
+
+    #[allow(unused)]
+    fn on_stats(
+        &mut self,
+        search_limits: &SearchLimits,
+        search_stats: &SearchStats,
+        search_engine: &SearchEngine,
+    ) {
+    }
+
+    #[allow(unused)]
+    fn on_best_move_changed(&mut self, best_move: Move, search_engine: &SearchEngine) {}
+
+    #[allow(unused)]
+    fn on_pv_changed(&mut self, pv: &PvLine, search_engine: &SearchEngine) {}
+
+    //The first node of the PV holds the proven state, called again when the mate gets shorter
+    #[allow(unused)]
+    fn on_mate_proven(&mut self, pv: &PvLine, search_engine: &SearchEngine) {}
+
+    //The search stopped every thread to reclaim tree memory
+    #[allow(unused)]
+    fn on_half_swap(&mut self, search_engine: &SearchEngine) {}
+
+    #[allow(unused)]
+    fn on_search_ended(
+        &mut self,
+        search_limits: &SearchLimits,
+        search_stats: &SearchStats,
+        search_engine: &SearchEngine,
+    ) {
+    }
+}
+
+//Lets search::<Display> drive the same search as an observer that only prints stats
+pub(crate) struct ReportObserver<Display>(PhantomData<fn() -> Display>);
+
+impl<Display> Default for ReportObserver<Display> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Display: SearchReport> SearchObserver for ReportObserver<Display> {
+    fn stats_per_second(&self) -> f64 {
+        Display::refresh_rate_per_second()
+    }
+
+    fn on_search_started(&mut self, search_limits: &SearchLimits, search_engine: &SearchEngine) {
+        Display::search_started(search_limits, search_engine)
+    }
+
+    fn on_stats(
+        &mut self,
+        search_limits: &SearchLimits,
+        search_stats: &SearchStats,
+        search_engine: &SearchEngine,
+    ) {
+        Display::search_report(search_limits, search_stats, search_engine)
+    }
+
+    fn on_search_ended(
+        &mut self,
+        search_limits: &SearchLimits,
+        search_stats: &SearchStats,
+        search_engine: &SearchEngine,
+    ) {
+        Display::search_ended(search_limits, search_stats, search_engine)
+    }
+}
@@ -0,0 +1,61 @@
+use engine::{BestMoveSelection, NoReport, SearchEngine, SearchLimits};
+
+#[test]
+fn best_move_selection_option() {
+    for (name, selection) in [
+        ("visits", BestMoveSelection::Visits),
+        ("Q", BestMoveSelection::Q),
+        ("lcb", BestMoveSelection::Lcb),
+        ("aggressive", BestMoveSelection::Aggressive),
+    ] {
+        assert_eq!(name.parse::<BestMoveSelection>(), Ok(selection));
+        assert_eq!(selection.to_string(), name.to_ascii_lowercase());
+    }
+
+    assert!("policy".parse::<BestMoveSelection>().is_err());
+
+    let mut search_engine = SearchEngine::new();
+    assert_eq!(
+        search_engine.options().best_move_selection(),
+        BestMoveSelection::Aggressive
+    );
+    assert!(search_engine.set_option("BestMoveSelection", "lcb").is_ok());
+    assert!(search_engine
+        .set_option("BestMoveSelection", "robust")
+        .is_err());
+    assert_eq!(
+        search_engine.options().best_move_selection(),
+        BestMoveSelection::Lcb
+    );
+}
+
+#[test]
+fn best_move_matches_pv() {
+    //Aggressive keeps its own weights for the MultiPV order, the other strategies rank the
+    //lines the same way they pick the move
+    for selection in ["visits", "q", "lcb"] {
+        let mut search_engine = SearchEngine::new();
+        let _ = search_engine.set_option("BestMoveSelection", selection);
+
+        let mut limits = SearchLimits::default();
+        limits.set_iters(Some(3000));
+        search_engine.search::<NoReport>(&limits);
+
+        let tree = search_engine.tree();
+        let draw_score = search_engine
+            .options()
+            .get_draw_score_blend(tree.root_node().score());
+        let best_idx = tree
+            .select_best_child(tree.root_index(), draw_score, search_engine.options())
+            .unwrap();
+        let pv = tree.get_best_pv(0, search_engine.options());
+        assert_eq!(pv.first_move(), tree[best_idx].mv());
+
+        if selection == "visits" {
+            let mut max_visits = 0;
+            tree.root_node()
+                .map_children(|child_idx| max_visits = max_visits.max(tree[child_idx].visits()));
+            assert_eq!(tree[best_idx].visits(), max_visits);
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use chess::{ChessBoard, ChessPosition, FEN};
+use engine::{NoReport, SearchEngine, SearchLimits, WDLScore};
+
+#[test]
+fn shifted_score() {
+    let score = WDLScore::new(0.4, 0.3);
+
+    let upper = score.shifted(0.1);
+    assert!((upper.win_chance() - 0.5).abs() < 1e-9);
+    assert!((upper.draw_chance() - 0.3).abs() < 1e-9);
+    assert!((upper.single() - score.single() - 0.1).abs() < 1e-9);
+
+    assert!(score.shifted(1.0).lose_chance().abs() < 1e-9);
+    assert_eq!(score.shifted(-1.0).win_chance(), 0.0);
+}
+
+#[test]
+fn node_confidence() {
+    let mut search_engine = SearchEngine::new();
+
+    let position = ChessPosition::from(
+        ChessBoard::try_from(&FEN::from(
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        ))
+        .unwrap(),
+    );
+    search_engine.set_position(&position, 0);
+
+    let mut limits = SearchLimits::default();
+    limits.set_iters(Some(3000));
+    search_engine.search::<NoReport>(&limits);
+
+    let tree = search_engine.tree();
+    let root = tree.root_node();
+    assert!(root.score_variance() > 0.0);
+    assert!(root.standard_error() > 0.0);
+    assert!(root.standard_error() < root.score_variance().sqrt());
+
+    tree.root_node().map_children(|child_idx| {
+        let node = &tree[child_idx];
+        let lower = node.score_bound(-1.96).single();
+        let upper = node.score_bound(1.96).single();
+
+        assert!(lower <= node.score().single() && node.score().single() <= upper);
+        if node.visits() == 0 {
+            assert_eq!(node.standard_error(), 0.0);
+        }
+    });
+}
@@ -0,0 +1,60 @@
+use std::thread;
+
+use engine::{NoReport, Node, NodeIndex, SearchEngine, SearchLimits};
+
+#[test]
+fn exclusive_children_index() {
+    let node = Node::new();
+    node.children_index_mut().store(NodeIndex::from(0));
+
+    thread::scope(|s| {
+        for _ in 0..16 {
+            s.spawn(|| {
+                for _ in 0..5000 {
+                    let children_idx = node.children_index_mut();
+                    let value = u32::from(children_idx.value());
+                    children_idx.store(NodeIndex::from(value + 1));
+                }
+            });
+        }
+
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..5000 {
+                    assert!(u32::from(node.children_index()) <= 80000);
+                }
+            });
+        }
+    });
+
+    assert_eq!(node.children_index(), NodeIndex::from(80000));
+}
+
+#[test]
+fn many_threads_tiny_tree() {
+    let mut search_engine = SearchEngine::new();
+    search_engine.set_option("Threads", "32").unwrap();
+    let _ = search_engine.set_option("Hash", "1");
+    search_engine.resize_tree();
+
+    let mut limits = SearchLimits::default();
+    limits.set_iters(Some(2500));
+    let search_stats = search_engine.search::<NoReport>(&limits);
+
+    let aggregate = search_stats.aggregate();
+    assert!(aggregate.iterations() >= 2500);
+
+    let tree = search_engine.tree();
+    let best_move = tree.get_best_pv(0, search_engine.options()).first_move();
+
+    let mut children_visits = 0;
+    let mut found = false;
+    tree.root_node().map_children(|child_idx| {
+        children_visits += tree[child_idx].visits();
+        found |= tree[child_idx].mv() == best_move;
+    });
+
+    assert!(found);
+    assert!(children_visits > 0);
+    assert!(children_visits <= tree.root_node().visits());
+}
@@ -0,0 +1,36 @@
+use engine::{NoReport, SearchEngine, SearchLimits};
+
+fn deterministic_search(threads: usize) -> (String, Vec<u32>) {
+    let mut search_engine = SearchEngine::new();
+    let _ = search_engine.set_option("Threads", &threads.to_string());
+    search_engine.set_option("Deterministic", "true").unwrap();
+    search_engine.resize_tree();
+
+    let mut limits = SearchLimits::default();
+    limits.set_iters(Some(6000));
+    search_engine.search::<NoReport>(&limits);
+
+    let tree = search_engine.tree();
+    let pv = tree
+        .get_best_pv(0, search_engine.options())
+        .to_string(false);
+
+    let mut visits = Vec::new();
+    tree.root_node()
+        .map_children(|child_idx| visits.push(tree[child_idx].visits()));
+
+    (pv, visits)
+}
+
+#[test]
+fn identical_multithreaded_searches() {
+    for threads in [1, 4] {
+        let (pv, visits) = deterministic_search(threads);
+
+        for _ in 0..3 {
+            let (other_pv, other_visits) = deterministic_search(threads);
+            assert_eq!(pv, other_pv);
+            assert_eq!(visits, other_visits);
+        }
+    }
+}
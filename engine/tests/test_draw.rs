@@ -1,13 +1,13 @@
 use chess::{ChessBoard, ChessPosition, Move, MoveFlag, Square, FEN};
-use engine::{NoReport, SearchEngine, SearchLimits};
+use engine::{GameState, NoReport, ScoreBound, SearchEngine, SearchLimits};
 
 #[test]
 fn three_fold() {
     let mut search_engine = SearchEngine::new();
 
-    let mut position = ChessPosition::from(ChessBoard::from(&FEN::from(
-        "7k/5pp1/rp1p2p1/q1p5/8/2Q5/6PP/7K w - - 0 1",
-    )));
+    let mut position = ChessPosition::from(
+        ChessBoard::try_from(&FEN::from("7k/5pp1/rp1p2p1/q1p5/8/2Q5/6PP/7K w - - 0 1")).unwrap(),
+    );
     let mask = position.board().castle_rights().get_castle_mask();
     position.make_move(
         Move::from_squares(Square::C3, Square::H3, MoveFlag::QUIET_MOVE),
@@ -47,9 +47,12 @@ fn three_fold() {
 fn fifty_mr() {
     let mut search_engine = SearchEngine::new();
 
-    let position = ChessPosition::from(ChessBoard::from(&FEN::from(
-        "1r5k/2q1q3/3q1q2/4q1q1/5q2/8/1P6/KR6 w - - 98 100",
-    )));
+    let position = ChessPosition::from(
+        ChessBoard::try_from(&FEN::from(
+            "1r5k/2q1q3/3q1q2/4q1q1/5q2/8/1P6/KR6 w - - 98 100",
+        ))
+        .unwrap(),
+    );
 
     search_engine.set_position(&position, 0);
 
@@ -71,9 +74,12 @@ fn fifty_mr() {
 fn fifty_mr_mate() {
     let mut search_engine = SearchEngine::new();
 
-    let position = ChessPosition::from(ChessBoard::from(&FEN::from(
-        "1r5k/2q1q3/3q1q2/4q1q1/5q2/8/1P6/KR6 b - - 99 100",
-    )));
+    let position = ChessPosition::from(
+        ChessBoard::try_from(&FEN::from(
+            "1r5k/2q1q3/3q1q2/4q1q1/5q2/8/1P6/KR6 b - - 99 100",
+        ))
+        .unwrap(),
+    );
 
     search_engine.set_position(&position, 0);
 
@@ -86,3 +92,48 @@ fn fifty_mr_mate() {
 
     assert!(pv.score().single() > 0.95)
 }
+
+#[test]
+fn proven_draw() {
+    let mut search_engine = SearchEngine::new();
+
+    //Every move runs into the fifty move rule and none of them mates
+    let position = ChessPosition::from(
+        ChessBoard::try_from(&FEN::from("8/8/8/4k3/8/8/8/R3K3 w - - 99 100")).unwrap(),
+    );
+
+    search_engine.set_position(&position, 0);
+
+    let mut limits = SearchLimits::default();
+    limits.set_iters(Some(20000));
+
+    let stats = search_engine.search::<NoReport>(&limits);
+
+    let tree = search_engine.tree();
+    assert_eq!(tree.root_node().state(), GameState::Draw);
+    assert!(stats.aggregate().iterations() < 20000);
+
+    let pv = tree.get_best_pv(0, search_engine.options());
+    assert_eq!(pv.first_node().state(), GameState::Draw);
+}
+
+#[test]
+fn at_least_draw() {
+    let mut search_engine = SearchEngine::new();
+
+    //King moves are fifty move rule draws, pawn moves reset the counter
+    let position = ChessPosition::from(
+        ChessBoard::try_from(&FEN::from("8/8/8/4k3/8/8/P7/4K3 w - - 99 100")).unwrap(),
+    );
+
+    search_engine.set_position(&position, 0);
+
+    let mut limits = SearchLimits::default();
+    limits.set_iters(Some(5000));
+
+    search_engine.search::<NoReport>(&limits);
+
+    let root = search_engine.tree().root_node();
+    assert_eq!(root.state(), GameState::Ongoing);
+    assert_eq!(root.bound(), ScoreBound::AtLeastDraw);
+}
@@ -0,0 +1,130 @@
+use chess::{ChessBoard, Move, MoveFlag, Piece, Side, Square};
+use engine::{NoReport, SearchEngine, SearchLimits, WDLScore};
+
+#[test]
+fn capture_history() {
+    let mut search_engine = SearchEngine::new();
+    search_engine.set_option("CaptureHistory", "true").unwrap();
+    let board = ChessBoard::try_from("4k3/8/8/3p4/4N3/8/8/4K3 w - - 0 1").unwrap();
+    let mv = Move::from_squares(Square::E4, Square::D5, MoveFlag::CAPTURE);
+    let quiet = Move::from_squares(Square::E4, Square::F6, MoveFlag::QUIET_MOVE);
+
+    let history = search_engine.tree().capture_history();
+    let victim = board.piece_on_square(mv.to_square());
+
+    let options = search_engine.options();
+    history.update_entry(
+        Side::WHITE,
+        Piece::KNIGHT,
+        mv,
+        victim,
+        WDLScore::new(0.9, 0.05),
+        options,
+    );
+    assert!(history.get_bonus(Side::WHITE, Piece::KNIGHT, mv, victim, options) > 0.0);
+    assert_eq!(
+        history.get_bonus(Side::BLACK, Piece::KNIGHT, mv, victim, options),
+        0.0
+    );
+    assert_eq!(
+        history.get_bonus(Side::WHITE, Piece::KNIGHT, quiet, Piece::NONE, options),
+        0.0
+    );
+
+    search_engine.set_option("CaptureHistory", "false").unwrap();
+    let history = search_engine.tree().capture_history();
+    assert_eq!(
+        history.get_bonus(
+            Side::WHITE,
+            Piece::KNIGHT,
+            mv,
+            victim,
+            search_engine.options()
+        ),
+        0.0
+    );
+
+    search_engine.tree().clear();
+    search_engine.set_option("CaptureHistory", "true").unwrap();
+    let history = search_engine.tree().capture_history();
+    assert_eq!(
+        history.get_bonus(
+            Side::WHITE,
+            Piece::KNIGHT,
+            mv,
+            victim,
+            search_engine.options()
+        ),
+        0.0
+    );
+}
+
+#[test]
+fn continuation_history() {
+    let mut search_engine = SearchEngine::new();
+    search_engine
+        .set_option("ContinuationHistory", "true")
+        .unwrap();
+    let board = ChessBoard::try_from("4k3/8/8/3p4/4N3/8/8/4K3 w - - 0 1").unwrap();
+    let parent_mv = Move::from_squares(Square::D6, Square::D5, MoveFlag::QUIET_MOVE);
+    let mv = Move::from_squares(Square::E4, Square::F6, MoveFlag::QUIET_MOVE);
+
+    let parent = Some((
+        board.piece_on_square(parent_mv.to_square()),
+        parent_mv.to_square(),
+    ));
+
+    let history = search_engine.tree().continuation_history();
+    let options = search_engine.options();
+    history.update_entry(
+        Side::WHITE,
+        parent,
+        Piece::KNIGHT,
+        mv,
+        WDLScore::new(0.1, 0.05),
+        options,
+    );
+    assert!(history.get_bonus(Side::WHITE, parent, Piece::KNIGHT, mv, options) < 0.0);
+    assert_eq!(
+        history.get_bonus(Side::WHITE, None, Piece::KNIGHT, mv, options),
+        0.0
+    );
+
+    let other_parent = Some((Piece::KNIGHT, Square::D5));
+    assert_eq!(
+        history.get_bonus(Side::WHITE, other_parent, Piece::KNIGHT, mv, options),
+        0.0
+    );
+
+    search_engine
+        .set_option("ContinuationHistory", "false")
+        .unwrap();
+    let history = search_engine.tree().continuation_history();
+    assert_eq!(
+        history.get_bonus(
+            Side::WHITE,
+            parent,
+            Piece::KNIGHT,
+            mv,
+            search_engine.options()
+        ),
+        0.0
+    );
+}
+
+#[test]
+fn search_with_histories() {
+    let mut search_engine = SearchEngine::new();
+    assert!(!search_engine.options().continuation_history());
+    assert!(!search_engine.options().capture_history());
+
+    search_engine
+        .set_option("ContinuationHistory", "true")
+        .unwrap();
+    search_engine.set_option("CaptureHistory", "true").unwrap();
+
+    let mut limits = SearchLimits::default();
+    limits.set_iters(Some(2000));
+    let stats = search_engine.search::<NoReport>(&limits);
+    assert!(stats.aggregate().iterations() >= 2000);
+}
@@ -2,9 +2,12 @@ use chess::{ChessBoard, ChessPosition, Move, MoveFlag, Square, FEN};
 
 #[test]
 fn validate_hash() {
-    let mut position = ChessPosition::from(ChessBoard::from(&FEN::from(
-        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
-    )));
+    let mut position = ChessPosition::from(
+        ChessBoard::try_from(&FEN::from(
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        ))
+        .unwrap(),
+    );
     let mask = position.board().castle_rights().get_castle_mask();
     position.make_move(
         Move::from_squares(Square::G1, Square::F3, MoveFlag::QUIET_MOVE),
@@ -31,9 +34,12 @@ fn validate_hash() {
 
 #[test]
 fn reversable_transposition() {
-    let mut position = ChessPosition::from(ChessBoard::from(&FEN::from(
-        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
-    )));
+    let mut position = ChessPosition::from(
+        ChessBoard::try_from(&FEN::from(
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        ))
+        .unwrap(),
+    );
     let mask = position.board().castle_rights().get_castle_mask();
     position.make_move(
         Move::from_squares(Square::G1, Square::F3, MoveFlag::QUIET_MOVE),
@@ -54,9 +60,12 @@ fn reversable_transposition() {
 
     let hash_a = position.history().hash();
 
-    let mut position = ChessPosition::from(ChessBoard::from(&FEN::from(
-        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
-    )));
+    let mut position = ChessPosition::from(
+        ChessBoard::try_from(&FEN::from(
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        ))
+        .unwrap(),
+    );
     let mask = position.board().castle_rights().get_castle_mask();
     position.make_move(
         Move::from_squares(Square::B1, Square::C3, MoveFlag::QUIET_MOVE),
@@ -79,9 +88,12 @@ fn reversable_transposition() {
 
     assert_ne!(hash_a, hash_b);
 
-    let mut position = ChessPosition::from(ChessBoard::from(&FEN::from(
-        "rnbqkbnr/pppp1ppp/4p3/8/2P5/3P4/PP2PPPP/RNBQKBNR w KQkq - 0 1",
-    )));
+    let mut position = ChessPosition::from(
+        ChessBoard::try_from(&FEN::from(
+            "rnbqkbnr/pppp1ppp/4p3/8/2P5/3P4/PP2PPPP/RNBQKBNR w KQkq - 0 1",
+        ))
+        .unwrap(),
+    );
     let mask = position.board().castle_rights().get_castle_mask();
     position.make_move(
         Move::from_squares(Square::C1, Square::E3, MoveFlag::QUIET_MOVE),
@@ -98,9 +110,12 @@ fn reversable_transposition() {
 
     let hash_a = position.history().hash();
 
-    let mut position = ChessPosition::from(ChessBoard::from(&FEN::from(
-        "rnbqkbnr/pppp1ppp/4p3/8/2P5/3P4/PP2PPPP/RNBQKBNR w KQkq - 0 1",
-    )));
+    let mut position = ChessPosition::from(
+        ChessBoard::try_from(&FEN::from(
+            "rnbqkbnr/pppp1ppp/4p3/8/2P5/3P4/PP2PPPP/RNBQKBNR w KQkq - 0 1",
+        ))
+        .unwrap(),
+    );
     let mask = position.board().castle_rights().get_castle_mask();
     position.make_move(
         Move::from_squares(Square::D1, Square::A4, MoveFlag::QUIET_MOVE),
@@ -122,9 +137,12 @@ fn reversable_transposition() {
 
 #[test]
 fn irreversable_transposition() {
-    let mut position = ChessPosition::from(ChessBoard::from(&FEN::from(
-        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
-    )));
+    let mut position = ChessPosition::from(
+        ChessBoard::try_from(&FEN::from(
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        ))
+        .unwrap(),
+    );
     let mask = position.board().castle_rights().get_castle_mask();
     position.make_move(
         Move::from_squares(Square::G1, Square::F3, MoveFlag::QUIET_MOVE),
@@ -153,9 +171,12 @@ fn irreversable_transposition() {
 
     let hash_a = position.history().hash();
 
-    let mut position = ChessPosition::from(ChessBoard::from(&FEN::from(
-        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
-    )));
+    let mut position = ChessPosition::from(
+        ChessBoard::try_from(&FEN::from(
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        ))
+        .unwrap(),
+    );
     let mask = position.board().castle_rights().get_castle_mask();
     position.make_move(
         Move::from_squares(Square::G1, Square::F3, MoveFlag::QUIET_MOVE),
@@ -186,9 +207,12 @@ fn irreversable_transposition() {
 
     assert_eq!(hash_a, hash_b);
 
-    let mut position = ChessPosition::from(ChessBoard::from(&FEN::from(
-        "rnbqkbnr/ppp1pppp/8/8/3p4/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
-    )));
+    let mut position = ChessPosition::from(
+        ChessBoard::try_from(&FEN::from(
+            "rnbqkbnr/ppp1pppp/8/8/3p4/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        ))
+        .unwrap(),
+    );
     let mask = position.board().castle_rights().get_castle_mask();
     position.make_move(
         Move::from_squares(Square::E2, Square::E4, MoveFlag::DOUBLE_PUSH),
@@ -201,9 +225,12 @@ fn irreversable_transposition() {
 
     let hash_a = position.history().hash();
 
-    let mut position = ChessPosition::from(ChessBoard::from(&FEN::from(
-        "rnbqkbnr/ppp1pppp/8/8/3p4/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
-    )));
+    let mut position = ChessPosition::from(
+        ChessBoard::try_from(&FEN::from(
+            "rnbqkbnr/ppp1pppp/8/8/3p4/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        ))
+        .unwrap(),
+    );
     let mask = position.board().castle_rights().get_castle_mask();
     position.make_move(
         Move::from_squares(Square::E2, Square::E3, MoveFlag::QUIET_MOVE),
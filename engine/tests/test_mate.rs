@@ -1,13 +1,13 @@
 use chess::{ChessBoard, ChessPosition, Move, MoveFlag, Square, FEN};
-use engine::{NoReport, SearchEngine, SearchLimits};
+use engine::{GameState, NoReport, SearchEngine, SearchLimits};
 
 #[test]
 fn mate_in_1() {
     let mut search_engine = SearchEngine::new();
 
-    let position = ChessPosition::from(ChessBoard::from(&FEN::from(
-        "1r5k/8/8/8/8/8/1P6/KR6 b - - 0 1",
-    )));
+    let position = ChessPosition::from(
+        ChessBoard::try_from(&FEN::from("1r5k/8/8/8/8/8/1P6/KR6 b - - 0 1")).unwrap(),
+    );
 
     search_engine.set_position(&position, 0);
 
@@ -30,9 +30,12 @@ fn mate_in_1() {
 fn mate_in_2() {
     let mut search_engine = SearchEngine::new();
 
-    let position = ChessPosition::from(ChessBoard::from(&FEN::from(
-        "r1b2k1r/ppp1bppp/8/1B1Q4/5q2/2P5/PPP2PPP/R3R1K1 w - - 1 1",
-    )));
+    let position = ChessPosition::from(
+        ChessBoard::try_from(&FEN::from(
+            "r1b2k1r/ppp1bppp/8/1B1Q4/5q2/2P5/PPP2PPP/R3R1K1 w - - 1 1",
+        ))
+        .unwrap(),
+    );
 
     search_engine.set_position(&position, 0);
 
@@ -50,3 +53,35 @@ fn mate_in_2() {
         Move::from_squares(Square::D5, Square::D8, MoveFlag::QUIET_MOVE)
     )
 }
+
+#[test]
+fn proven_losses_filtered() {
+    let mut search_engine = SearchEngine::new();
+    search_engine.set_option("MultiPV", "8").unwrap();
+
+    //Most black moves allow Rb8 mate
+    let position = ChessPosition::from(
+        ChessBoard::try_from(&FEN::from("6k1/5ppp/8/8/8/8/r4PPP/1R4K1 b - - 0 1")).unwrap(),
+    );
+
+    search_engine.set_position(&position, 0);
+
+    let mut limits = SearchLimits::default();
+    limits.set_iters(Some(20000));
+
+    search_engine.search::<NoReport>(&limits);
+
+    let tree = search_engine.tree();
+    let mut proven_losses = 0;
+    tree.root_node().map_children(|child_idx| {
+        if let GameState::Win(_) = tree[child_idx].state() {
+            proven_losses += 1;
+        }
+    });
+    assert!(proven_losses > 0);
+
+    for pv_idx in 0..8 {
+        let pv = tree.get_best_pv(pv_idx, search_engine.options());
+        assert!(!matches!(pv.first_node().state(), GameState::Win(_)));
+    }
+}
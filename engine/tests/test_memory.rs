@@ -0,0 +1,143 @@
+use chess::{Game, FEN};
+use engine::{MemoryManager, NoReport, NumaPolicy, SearchEngine, SearchLimits};
+
+#[test]
+fn numa_policy_option() {
+    for (name, policy) in [
+        ("none", NumaPolicy::None),
+        ("spread", NumaPolicy::Spread),
+        ("Compact", NumaPolicy::Compact),
+    ] {
+        assert_eq!(name.parse::<NumaPolicy>(), Ok(policy));
+        assert_eq!(policy.to_string(), name.to_ascii_lowercase());
+    }
+
+    assert!("interleave".parse::<NumaPolicy>().is_err());
+    assert_eq!(NumaPolicy::None.thread_cpus(0), None);
+
+    let mut search_engine = SearchEngine::new();
+    assert_eq!(search_engine.options().numa_policy(), NumaPolicy::None);
+    assert!(search_engine.set_option("NumaPolicy", "spread").is_ok());
+    assert!(search_engine.set_option("NumaPolicy", "fastest").is_err());
+    assert_eq!(search_engine.options().numa_policy(), NumaPolicy::Spread);
+}
+
+#[test]
+fn large_pages_search() {
+    let mut search_engine = SearchEngine::new();
+    search_engine.set_option("Threads", "3").unwrap();
+    search_engine.set_option("LargePages", "true").unwrap();
+    search_engine.set_option("NumaPolicy", "compact").unwrap();
+    search_engine.set_option("Hash", "64").unwrap();
+
+    //Falls back to regular pages when the system has no huge pages reserved
+    search_engine.resize_tree();
+    assert_eq!(search_engine.tree().current_size(), 1);
+    assert_eq!(search_engine.tree().root_node().children_count(), 0);
+
+    let mut limits = SearchLimits::default();
+    limits.set_iters(Some(5000));
+    let stats = search_engine.search::<NoReport>(&limits);
+    assert!(stats.aggregate().iterations() >= 5000);
+
+    let clone = search_engine.clone();
+    assert_eq!(
+        clone.tree().current_size(),
+        search_engine.tree().current_size()
+    );
+}
+
+#[test]
+fn memory_manager_option() {
+    for (name, manager) in [
+        ("halves", MemoryManager::Halves),
+        ("LRU", MemoryManager::Lru),
+    ] {
+        assert_eq!(name.parse::<MemoryManager>(), Ok(manager));
+        assert_eq!(manager.to_string(), name.to_ascii_lowercase());
+    }
+
+    assert!("generational".parse::<MemoryManager>().is_err());
+
+    let mut search_engine = SearchEngine::new();
+    assert_eq!(
+        search_engine.options().memory_manager(),
+        MemoryManager::Halves
+    );
+    assert!(search_engine.set_option("MemoryManager", "lru").is_ok());
+    assert_eq!(search_engine.options().memory_manager(), MemoryManager::Lru);
+}
+
+fn small_hash_search(manager: &str, threads: &str) -> (u64, u64, u64, u32) {
+    let mut search_engine = SearchEngine::new();
+    let _ = search_engine.set_option("Hash", "1");
+    let _ = search_engine.set_option("Threads", threads);
+    let _ = search_engine.set_option("MemoryManager", manager);
+    search_engine.resize_tree();
+
+    let mut limits = SearchLimits::default();
+    limits.set_iters(Some(20000));
+    let stats = search_engine.search::<NoReport>(&limits);
+
+    let tree = search_engine.tree();
+    assert!(tree.current_size() <= tree.max_size());
+
+    (
+        stats.aggregate().iterations(),
+        tree.stalls(),
+        tree.evicted_nodes(),
+        tree.root_node().visits(),
+    )
+}
+
+#[test]
+fn lru_against_halves() {
+    let (halves_iterations, halves_stalls, halves_evicted, _) = small_hash_search("halves", "1");
+    let (lru_iterations, lru_stalls, lru_evicted, lru_root_visits) = small_hash_search("lru", "1");
+
+    assert!(halves_iterations >= 20000);
+    assert!(lru_iterations >= 20000);
+    assert!(halves_stalls > 0);
+    assert!(halves_evicted > 0);
+
+    //The LRU tree evicts in small batches without stopping the search
+    assert!(lru_evicted > 0);
+    assert!(lru_stalls < halves_stalls);
+    assert!(u64::from(lru_root_visits) >= lru_iterations);
+
+    let (iterations, _, evicted, _) = small_hash_search("lru", "4");
+    assert!(iterations >= 20000);
+    assert!(evicted > 0);
+}
+
+#[test]
+fn lru_reuse_releases_siblings() {
+    let mut search_engine = SearchEngine::new();
+    search_engine.set_option("MemoryManager", "lru").unwrap();
+
+    let mut limits = SearchLimits::default();
+    limits.set_iters(Some(3000));
+    search_engine.search::<NoReport>(&limits);
+
+    let before = search_engine.tree().current_size();
+    let first_move = search_engine
+        .tree()
+        .get_best_pv(0, search_engine.options())
+        .first_move();
+
+    let mut game = Game::try_from(&FEN::start_position()).unwrap();
+    game.make_move(first_move);
+
+    let reused =
+        search_engine
+            .tree()
+            .try_reuse_game(search_engine.game(), &game, search_engine.options());
+    search_engine.set_game(&game);
+
+    assert!(reused.is_some());
+    assert!(search_engine.tree().current_size() < before);
+    assert_eq!(search_engine.tree().root_node().mv(), first_move);
+
+    search_engine.search::<NoReport>(&limits);
+    assert!(search_engine.tree().root_node().visits() > 3000);
+}
@@ -1,6 +1,30 @@
-use engine::Node;
+use chess::{ChessBoard, ChessPosition};
+use engine::{NoReport, Node, SearchEngine, SearchLimits};
 
 #[test]
 fn node_size() {
     assert_eq!(std::mem::size_of::<Node>(), 48);
 }
+
+#[test]
+fn wide_expansion() {
+    //Every piece of the pocket can be dropped on any empty square
+    let board = ChessBoard::try_from("k7/8/8/8/8/8/8/K7[QRBNPqrbnp] w - - 0 1").unwrap();
+    let legal_moves = board.legal_moves().len();
+    assert!(legal_moves > u8::MAX as usize);
+
+    let mut search_engine = SearchEngine::new();
+    search_engine.set_position(&ChessPosition::from(board), 0);
+
+    let mut limits = SearchLimits::default();
+    limits.set_iters(Some(100));
+    search_engine.search::<NoReport>(&limits);
+
+    let tree = search_engine.tree();
+    assert_eq!(tree.root_node().children_count(), legal_moves);
+
+    let mut policy = 0.0;
+    tree.root_node()
+        .map_children(|child_idx| policy += tree[child_idx].policy());
+    assert!((policy - 1.0).abs() < 0.01);
+}
@@ -0,0 +1,106 @@
+use chess::{ChessBoard, ChessPosition, Move, MoveFlag, Square, FEN};
+use engine::{
+    GameState, NoReport, PvLine, SearchEngine, SearchLimits, SearchObserver, SearchStats,
+};
+
+#[derive(Default)]
+struct RecordingObserver {
+    started: usize,
+    stats: usize,
+    ended: usize,
+    best_moves: Vec<Move>,
+    pvs: Vec<String>,
+    mates: Vec<GameState>,
+    half_swaps: usize,
+}
+
+impl SearchObserver for RecordingObserver {
+    fn stats_per_second(&self) -> f64 {
+        100.0
+    }
+
+    fn on_search_started(&mut self, _: &SearchLimits, _: &SearchEngine) {
+        self.started += 1;
+    }
+
+    fn on_stats(&mut self, _: &SearchLimits, _: &SearchStats, _: &SearchEngine) {
+        self.stats += 1;
+    }
+
+    fn on_best_move_changed(&mut self, best_move: Move, _: &SearchEngine) {
+        self.best_moves.push(best_move);
+    }
+
+    fn on_pv_changed(&mut self, pv: &PvLine, _: &SearchEngine) {
+        self.pvs.push(pv.to_string(false));
+    }
+
+    fn on_mate_proven(&mut self, pv: &PvLine, _: &SearchEngine) {
+        self.mates.push(pv.first_node().state());
+    }
+
+    fn on_half_swap(&mut self, _: &SearchEngine) {
+        self.half_swaps += 1;
+    }
+
+    fn on_search_ended(&mut self, _: &SearchLimits, _: &SearchStats, _: &SearchEngine) {
+        self.ended += 1;
+    }
+}
+
+#[test]
+fn observer_events() {
+    let mut search_engine = SearchEngine::new();
+
+    let position = ChessPosition::from(
+        ChessBoard::try_from(&FEN::from("1r5k/8/8/8/8/8/1P6/KR6 b - - 0 1")).unwrap(),
+    );
+    search_engine.set_position(&position, 0);
+
+    let mut limits = SearchLimits::default();
+    limits.set_iters(Some(2000));
+
+    let mut observer: Box<dyn SearchObserver> = Box::new(RecordingObserver::default());
+    search_engine
+        .search_with_observer(&limits, observer.as_mut())
+        .unwrap();
+
+    let mut observer = RecordingObserver::default();
+    search_engine.tree().clear();
+    search_engine
+        .search_with_observer(&limits, &mut observer)
+        .unwrap();
+
+    assert_eq!(observer.started, 1);
+    assert_eq!(observer.ended, 1);
+    assert!(observer.stats >= 1);
+    assert!(!observer.best_moves.is_empty());
+    assert!(!observer.pvs.is_empty());
+    assert_eq!(observer.half_swaps, 0);
+
+    let mate = Move::from_squares(Square::B8, Square::A8, MoveFlag::QUIET_MOVE);
+    assert_eq!(observer.best_moves.last(), Some(&mate));
+    assert!(observer.pvs.last().unwrap().starts_with("b8a8"));
+    assert_eq!(observer.mates, vec![GameState::Loss(0)]);
+}
+
+#[test]
+fn observer_half_swaps() {
+    let mut search_engine = SearchEngine::new();
+    let _ = search_engine.set_option("Hash", "1");
+    search_engine.resize_tree();
+
+    let mut limits = SearchLimits::default();
+    limits.set_iters(Some(5000));
+
+    let mut observer = RecordingObserver::default();
+    search_engine
+        .search_with_observer(&limits, &mut observer)
+        .unwrap();
+
+    assert!(observer.half_swaps > 0);
+    assert_eq!(observer.half_swaps as u64, search_engine.tree().stalls());
+
+    //The static displays run the same search through the generic entry point
+    search_engine.search::<NoReport>(&limits);
+}
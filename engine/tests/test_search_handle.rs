@@ -0,0 +1,136 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::Arc,
+    thread,
+    time::Duration,
+};
+
+use chess::Move;
+use engine::{SearchEngine, SearchError, SearchLimits, SearchObserver};
+
+#[test]
+fn stop_infinite_search() {
+    let search_engine = Arc::new(SearchEngine::new());
+
+    let mut limits = SearchLimits::default();
+    limits.set_infinite(true);
+    let handle = search_engine.start_search(&limits).unwrap();
+
+    while handle.snapshot().visits() < 500 {
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    assert!(handle.is_running());
+
+    let snapshot = handle.snapshot();
+    assert_eq!(snapshot.pvs().len(), 1);
+    assert_ne!(snapshot.best_move(), Move::NULL);
+    assert!(snapshot.score().is_some());
+
+    handle.stop();
+    let result = handle.wait();
+
+    assert_ne!(result.best_move(), Move::NULL);
+    assert!(result.snapshot().visits() >= snapshot.visits());
+    assert!(result.stats().aggregate().iterations() > 0);
+}
+
+#[test]
+fn wait_for_limits() {
+    let mut search_engine = SearchEngine::new();
+    search_engine.set_option("MultiPV", "3").unwrap();
+    let mut search_engine = Arc::new(search_engine);
+
+    let mut limits = SearchLimits::default();
+    limits.set_iters(Some(2000));
+
+    let result = search_engine.start_search(&limits).unwrap().wait();
+    assert!(result.stats().aggregate().iterations() >= 2000);
+    assert_eq!(result.snapshot().pvs().len(), 3);
+    assert_eq!(
+        result.best_move(),
+        search_engine
+            .tree()
+            .get_best_pv(0, search_engine.options())
+            .first_move()
+    );
+
+    //The engine can be changed again once the handle is gone
+    let search_engine = Arc::get_mut(&mut search_engine).unwrap();
+    search_engine.set_option("MultiPV", "1").unwrap();
+    assert_eq!(search_engine.options().multi_pv(), 1);
+}
+
+#[test]
+fn stop_before_first_iteration() {
+    let search_engine = Arc::new(SearchEngine::new());
+
+    let mut limits = SearchLimits::default();
+    limits.set_infinite(true);
+    let handle = search_engine.start_search(&limits).unwrap();
+    handle.stop();
+
+    //Would never return if the stop was lost to the search start
+    handle.wait();
+
+    //Dropping a running handle stops the search
+    drop(search_engine.start_search(&limits).unwrap());
+    assert_eq!(Arc::strong_count(&search_engine), 1);
+}
+
+#[test]
+fn search_while_searching() {
+    let search_engine = Arc::new(SearchEngine::new());
+
+    let mut limits = SearchLimits::default();
+    limits.set_infinite(true);
+    let handle = search_engine.start_search(&limits).unwrap();
+
+    assert_eq!(
+        search_engine.start_search(&limits).unwrap_err(),
+        SearchError::AlreadyRunning
+    );
+    assert_eq!(
+        search_engine
+            .search_with_observer(&limits, &mut SilentObserver)
+            .unwrap_err(),
+        SearchError::AlreadyRunning
+    );
+
+    drop(handle);
+
+    limits.set_infinite(false);
+    limits.set_iters(Some(100));
+    assert!(search_engine
+        .search_with_observer(&limits, &mut SilentObserver)
+        .is_ok());
+}
+
+struct SilentObserver;
+
+impl SearchObserver for SilentObserver {}
+
+struct PanickingObserver;
+
+impl SearchObserver for PanickingObserver {
+    fn on_search_started(&mut self, _: &SearchLimits, _: &SearchEngine) {
+        panic!("observer panicked");
+    }
+}
+
+#[test]
+fn search_after_panic() {
+    let search_engine = SearchEngine::new();
+
+    let mut limits = SearchLimits::default();
+    limits.set_iters(Some(100));
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        search_engine.search_with_observer(&limits, &mut PanickingObserver)
+    }));
+    assert!(result.is_err());
+
+    assert!(search_engine
+        .search_with_observer(&limits, &mut SilentObserver)
+        .is_ok());
+}
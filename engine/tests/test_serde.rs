@@ -0,0 +1,27 @@
+use engine::{GameState, WDLScore};
+
+#[test]
+fn wdl_score() {
+    let score = WDLScore::new(0.25, 0.5);
+    let json = serde_json::to_string(&score).unwrap();
+    assert_eq!(json, "[0.25,0.5]");
+    assert_eq!(serde_json::from_str::<WDLScore>(&json).unwrap(), score);
+}
+
+#[test]
+fn game_state() {
+    for state in [
+        GameState::Ongoing,
+        GameState::Draw,
+        GameState::Win(3),
+        GameState::Loss(12),
+    ] {
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(serde_json::from_str::<GameState>(&json).unwrap(), state);
+    }
+
+    assert_eq!(
+        serde_json::to_string(&GameState::Win(3)).unwrap(),
+        "{\"Win\":3}"
+    );
+}
@@ -0,0 +1,144 @@
+use chess::{ChessBoard, Game, FEN};
+use engine::{NoReport, NodeIndex, SearchEngine, SearchLimits, Tree};
+
+#[test]
+fn reuse_game_continuation() {
+    let mut search_engine = SearchEngine::new();
+
+    let mut limits = SearchLimits::default();
+    limits.set_iters(Some(2000));
+
+    search_engine.search::<NoReport>(&limits);
+
+    let pv = search_engine.tree().get_best_pv(0, search_engine.options());
+    let first_move = pv.first_move();
+
+    let tree = search_engine.tree();
+    let mut second_move = None;
+    tree.root_node().map_children(|child_idx| {
+        if tree[child_idx].mv() != first_move {
+            return;
+        }
+
+        tree[child_idx].map_children(|grandchild_idx| {
+            if second_move.is_none() {
+                second_move = Some(tree[grandchild_idx].mv());
+            }
+        });
+    });
+
+    let mut game = Game::try_from(&FEN::start_position()).unwrap();
+    game.make_move(first_move);
+    game.make_move(second_move.unwrap());
+
+    let reused =
+        search_engine
+            .tree()
+            .try_reuse_game(search_engine.game(), &game, search_engine.options());
+    search_engine.set_game(&game);
+
+    assert_eq!(search_engine.game_ply(), 2);
+    assert!(reused.is_some());
+
+    let root = search_engine.tree().root_node();
+    assert!(root.children_count() > 0);
+
+    let mut legal_moves = Vec::new();
+    game.board().map_legal_moves(|mv| legal_moves.push(mv));
+    root.map_children(|child_idx| {
+        assert!(legal_moves.contains(&search_engine.tree()[child_idx].mv()))
+    });
+}
+
+#[test]
+fn reuse_game_diverged() {
+    let mut search_engine = SearchEngine::new();
+
+    let mut game = Game::try_from(&FEN::start_position()).unwrap();
+    let mv = game.board().parse_move("e2e4").unwrap();
+    game.make_move(mv);
+    search_engine.set_game(&game);
+
+    let mut limits = SearchLimits::default();
+    limits.set_iters(Some(500));
+    search_engine.search::<NoReport>(&limits);
+
+    game.unmake_move();
+    let mv = game.board().parse_move("d2d4").unwrap();
+    game.make_move(mv);
+
+    let reused =
+        search_engine
+            .tree()
+            .try_reuse_game(search_engine.game(), &game, search_engine.options());
+
+    assert!(reused.is_none());
+    assert_eq!(search_engine.tree().root_node().children_count(), 0);
+}
+
+fn assert_legal_subtree(tree: &Tree, node_idx: NodeIndex, board: &ChessBoard, depth: u8) {
+    if depth == 0 || tree[node_idx].children_count() == 0 {
+        return;
+    }
+
+    let mut legal_moves = Vec::new();
+    board.map_legal_moves(|mv| legal_moves.push(mv));
+
+    let mask = board.castle_rights().get_castle_mask();
+    tree[node_idx].map_children(|child_idx| {
+        let mv = tree[child_idx].mv();
+        assert!(legal_moves.contains(&mv), "{}", mv.to_string(false));
+
+        let mut board = *board;
+        board.make_move(mv, &mask);
+        assert_legal_subtree(tree, child_idx, &board, depth - 1);
+    });
+}
+
+#[test]
+fn reuse_game_deep_continuation() {
+    let mut search_engine = SearchEngine::new();
+
+    let mut limits = SearchLimits::default();
+    limits.set_iters(Some(5000));
+    search_engine.search::<NoReport>(&limits);
+
+    //Follows the most visited line, whose root usually has more children than the start position
+    let tree = search_engine.tree();
+    let mut game = Game::try_from(&FEN::start_position()).unwrap();
+    let mut node_idx = tree.root_index();
+    for _ in 0..2 {
+        let mut best = None;
+        tree[node_idx].map_children(|child_idx| {
+            if best.is_none_or(|best_idx: NodeIndex| {
+                tree[child_idx].visits() > tree[best_idx].visits()
+            }) {
+                best = Some(child_idx);
+            }
+        });
+
+        node_idx = best.unwrap();
+        game.make_move(tree[node_idx].mv());
+    }
+
+    let children_count = tree[node_idx].children_count();
+    let visits = tree[node_idx].visits();
+    assert!(children_count > tree.root_node().children_count());
+
+    let reused = tree.try_reuse_game(search_engine.game(), &game, search_engine.options());
+    assert!(reused.is_some());
+
+    assert_eq!(tree.root_node().children_count(), children_count);
+    assert_eq!(tree.root_node().visits(), visits);
+    assert_legal_subtree(tree, tree.root_index(), game.board(), 4);
+
+    //The promoted tree keeps searching from the new root
+    search_engine.set_game(&game);
+    search_engine.search::<NoReport>(&limits);
+    assert_legal_subtree(
+        search_engine.tree(),
+        search_engine.tree().root_index(),
+        game.board(),
+        4,
+    );
+}
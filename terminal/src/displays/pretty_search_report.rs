@@ -236,6 +236,7 @@ fn print_search_report<const FINAL: bool>(
     let score = match pv.first_node().state() {
         engine::GameState::Loss(len) => format!("+M{}", (len + 1).div_ceil(2)),
         engine::GameState::Win(len) => format!("-M{}", (len + 1).div_ceil(2)),
+        engine::GameState::Draw => String::from("0.00"),
         _ => format!(
             "{}{:.2}",
             if pv_score.single() < 0.5 { "-" } else { "+" },
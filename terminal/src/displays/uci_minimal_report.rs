@@ -19,6 +19,7 @@ impl SearchReport for UciMinimalReport {
         let score = match state {
             engine::GameState::Loss(len) => format!("mate {}", (len + 1).div_ceil(2)),
             engine::GameState::Win(len) => format!("mate -{}", (len + 1).div_ceil(2)),
+            engine::GameState::Draw => String::from("cp 0"),
             _ => format!("cp {}", pv_score.cp()),
         };
 
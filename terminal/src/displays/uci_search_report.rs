@@ -1,4 +1,4 @@
-use engine::{SearchEngine, SearchLimits, SearchReport, SearchStats, WDLScore};
+use engine::{Node, SearchEngine, SearchLimits, SearchReport, SearchStats, WDLScore};
 
 pub struct UciSearchReport;
 impl SearchReport for UciSearchReport {
@@ -28,6 +28,7 @@ impl SearchReport for UciSearchReport {
             let score = match state {
                 engine::GameState::Loss(len) => format!("mate {}", (len + 1).div_ceil(2)),
                 engine::GameState::Win(len) => format!("mate -{}", (len + 1).div_ceil(2)),
+                engine::GameState::Draw => String::from("cp 0"),
                 _ => format!("cp {}", pv_score.cp()),
             };
 
@@ -61,9 +62,13 @@ impl SearchReport for UciSearchReport {
             let hashfull =
                 search_engine.tree().current_size() * 1000 / search_engine.tree().max_size();
 
-            let pv = pv.to_string(search_engine.options().chess960());
+            let pv_string = pv.to_string(search_engine.options().chess960());
 
-            println!("info depth {depth} seldepth {max_depth} score {score}{wdl} time {time} nodes {nodes} nps {nps} hashfull {hashfull} multipv {} pv {pv}", pv_idx + 1)
+            println!("info depth {depth} seldepth {max_depth} score {score}{wdl} time {time} nodes {nodes} nps {nps} hashfull {hashfull} multipv {} pv {pv_string}", pv_idx + 1);
+
+            if search_engine.options().show_confidence() {
+                print_confidence(&pv.first_node(), pv_idx, search_engine);
+            }
         }
     }
 
@@ -87,3 +92,23 @@ impl SearchReport for UciSearchReport {
         );
     }
 }
+
+//Standard error and confidence interval of the move score, both as Q and as cp
+fn print_confidence(node: &Node, pv_idx: usize, search_engine: &SearchEngine) {
+    let z = search_engine.options().confidence_z();
+    let lower = node.score_bound(-z);
+    let upper = node.score_bound(z);
+
+    println!(
+        "info string multipv {} move {} q {:.4} se {:.4} ci {:.4} {:.4} cp {} cpci {} {}",
+        pv_idx + 1,
+        node.mv().to_string(search_engine.options().chess960()),
+        node.score().single(),
+        node.standard_error(),
+        lower.single(),
+        upper.single(),
+        node.score().cp(),
+        lower.cp(),
+        upper.cp()
+    );
+}
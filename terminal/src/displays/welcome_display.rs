@@ -137,12 +137,14 @@ pub fn welcome_message() -> String {
         result[13] = "   draw".secondary(13.0 / 24.0);
         result[14] = "   clear".secondary(14.0 / 24.0);
         result[15] = "   tree  <depth> <node_idx>".secondary(15.0 / 24.0);
-        result[16] = "   perft <depth>".secondary(16.0 / 24.0);
+        result[16] = "   perft <depth> <mode>".secondary(16.0 / 24.0);
         result[17] = "   bulk  <depth>".secondary(17.0 / 24.0);
         result[18] = "   bench <depth>".secondary(18.0 / 24.0);
         result[19] = "   analyse <nodes>".secondary(19.0 / 24.0);
         result[20] = "   eval".secondary(20.0 / 24.0);
         result[21] = "   policy".secondary(21.0 / 24.0);
+        result[22] = "   undo  <count>".secondary(22.0 / 24.0);
+        result[23] = "   result".secondary(23.0 / 24.0);
 
         result
     };
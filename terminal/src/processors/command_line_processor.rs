@@ -1,5 +1,7 @@
 use engine::SearchEngine;
 
+use crate::processors::{epd_test, perft_suite};
+
 #[allow(clippy::ptr_arg)]
 pub fn process_command_line_args(args: &Vec<String>, search_engine: &mut SearchEngine) -> bool {
     let mut commmand_processed = false;
@@ -18,6 +20,14 @@ pub fn process_command_line_args(args: &Vec<String>, search_engine: &mut SearchE
                 println!("Bench: {result} nodes {:.0} nps", nps);
                 commmand_processed = true;
             }
+            "epdtest" => {
+                epd_test(&args[idx + 1..], search_engine);
+                commmand_processed = true;
+            }
+            "perft-suite" => {
+                perft_suite(&args[idx + 1..], search_engine);
+                commmand_processed = true;
+            }
             _ => continue,
         }
     }
@@ -0,0 +1,294 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, Write},
+    time::Instant,
+};
+
+use chess::{ChessBoard, ChessPosition, Epd, Move};
+use engine::{GameState, NoReport, SearchEngine, SearchLimits};
+use utils::{time_to_string, AlignString, Colors, Theme};
+
+const DEFAULT_EPD_TEST_NODES: u64 = 100_000;
+
+struct EpdTestResult {
+    id: String,
+    fen: String,
+    expected: String,
+    found: String,
+    score: String,
+    nodes: u64,
+    time: u128,
+    passed: bool,
+}
+
+pub fn epd_test(args: &[String], search_engine: &mut SearchEngine) {
+    let Some(path) = args.first() else {
+        println!("Usage: epdtest <file> [nodes <n> | movetime <ms>] [threads <n>] [output <file>]");
+        return;
+    };
+
+    let mut nodes = None;
+    let mut move_time = None;
+    let mut threads = None;
+    let mut output = None;
+
+    for (idx, arg) in args.iter().enumerate().skip(1) {
+        let value = args.get(idx + 1);
+        match arg.as_str() {
+            "nodes" => nodes = value.and_then(|value| value.parse::<u64>().ok()),
+            "movetime" => move_time = value.and_then(|value| value.parse::<u128>().ok()),
+            "threads" => threads = value.and_then(|value| value.parse::<i64>().ok()),
+            "output" => output = value.cloned(),
+            _ => continue,
+        }
+    }
+
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(err) => {
+            println!("Failed to open {path}: {err}");
+            return;
+        }
+    };
+
+    let mut search_limits = SearchLimits::default();
+    if move_time.is_some() {
+        search_limits.set_move_time(move_time);
+        search_limits.set_iters(nodes);
+    } else {
+        search_limits.set_iters(Some(nodes.unwrap_or(DEFAULT_EPD_TEST_NODES)));
+    }
+
+    let previous_game = search_engine.game().clone();
+    let previous_threads = search_engine.options().threads();
+    if let Some(threads) = threads {
+        if let Err(msg) = search_engine.set_option("Threads", &threads.to_string()) {
+            println!("{msg}");
+            return;
+        }
+
+        search_engine.resize_tree();
+    }
+
+    println!("-----------------------------------------------------------");
+    println!("  Running EPD test");
+    println!("  File: {path}");
+    match move_time {
+        Some(move_time) => println!("  Move time: {}", time_to_string(move_time)),
+        None => println!("  Nodes: {}", nodes.unwrap_or(DEFAULT_EPD_TEST_NODES)),
+    }
+    println!("  Threads: {}", search_engine.options().threads());
+    println!("-----------------------------------------------------------\n");
+
+    println!(
+        "{}",
+        format!(
+            " {} {} {} {} {} {}",
+            "#".align_to_left(5),
+            "Id".align_to_left(20),
+            "Expected".align_to_left(16),
+            "Found".align_to_left(8),
+            "Score".align_to_left(10),
+            "Result"
+        )
+        .primary(0.5)
+    );
+
+    let timer = Instant::now();
+    let mut results = Vec::new();
+
+    for (line_idx, line) in BufReader::new(file).lines().enumerate() {
+        let Ok(line) = line else {
+            break;
+        };
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let epd = match Epd::parse(&line) {
+            Ok(epd) => epd,
+            Err(err) => {
+                println!(" Line {}: skipped, {err}", line_idx + 1);
+                continue;
+            }
+        };
+
+        let board = match epd.board() {
+            Ok(board) => board,
+            Err(err) => {
+                println!(" Line {}: skipped, {err}", line_idx + 1);
+                continue;
+            }
+        };
+
+        let Some(result) = run_position(&epd, board, &search_limits, search_engine) else {
+            println!(" Line {}: skipped, no bm, am or dm operation", line_idx + 1);
+            continue;
+        };
+
+        let result_str = if result.passed {
+            "PASS".green()
+        } else {
+            "FAIL".red()
+        };
+
+        println!(
+            " {} {} {} {} {} {}",
+            (results.len() + 1).to_string().align_to_left(5),
+            result.id.align_to_left(20),
+            result.expected.align_to_left(16),
+            result.found.align_to_left(8),
+            result.score.align_to_left(10),
+            result_str
+        );
+
+        results.push(result);
+    }
+
+    let passed = results.iter().filter(|result| result.passed).count();
+    let total = results.len();
+    let duration = timer.elapsed().as_millis();
+
+    println!("\n-----------------------------------------------------------");
+    println!(
+        "  EPD test ended! {passed}/{total} passed ({:.1}%), {}",
+        passed as f64 * 100.0 / total.max(1) as f64,
+        time_to_string(duration)
+    );
+    println!("-----------------------------------------------------------\n");
+
+    if let Some(output) = output {
+        match write_summary(&output, &results) {
+            Ok(()) => println!("Summary saved to {output}"),
+            Err(err) => println!("Failed to write {output}: {err}"),
+        }
+    }
+
+    if search_engine
+        .set_option("Threads", &previous_threads.to_string())
+        .is_ok()
+    {
+        search_engine.resize_tree();
+    }
+
+    search_engine.set_game(&previous_game);
+    search_engine.tree().clear();
+}
+
+fn run_position(
+    epd: &Epd,
+    board: ChessBoard,
+    search_limits: &SearchLimits,
+    search_engine: &mut SearchEngine,
+) -> Option<EpdTestResult> {
+    let best_moves = epd.best_moves();
+    let avoid_moves = epd.avoid_moves();
+    let direct_mate = epd.direct_mate();
+
+    if best_moves.is_empty() && avoid_moves.is_empty() && direct_mate.is_none() {
+        return None;
+    }
+
+    search_engine.tree().clear();
+    search_engine.set_position(&ChessPosition::from(board), 0);
+
+    let timer = Instant::now();
+    let search_stats = search_engine.search::<NoReport>(search_limits).aggregate();
+    let time = timer.elapsed().as_millis();
+
+    let pv = search_engine.tree().get_best_pv(0, search_engine.options());
+    let found = pv.first_move();
+
+    let mate = match pv.first_node().state() {
+        GameState::Loss(len) => Some((len + 1).div_ceil(2) as u32),
+        _ => None,
+    };
+
+    //Null when the position has no legal moves or the search ended before the first iteration,
+    //which never solves the position
+    let mut passed = found != Move::NULL;
+    let mut expected = Vec::new();
+
+    if !best_moves.is_empty() {
+        passed &= best_moves.contains(&found);
+        expected.push(format!(
+            "bm {}",
+            best_moves
+                .iter()
+                .map(|&mv| board.to_san(mv))
+                .collect::<Vec<_>>()
+                .join(" ")
+        ));
+    }
+
+    if !avoid_moves.is_empty() {
+        passed &= !avoid_moves.contains(&found);
+        expected.push(format!(
+            "am {}",
+            avoid_moves
+                .iter()
+                .map(|&mv| board.to_san(mv))
+                .collect::<Vec<_>>()
+                .join(" ")
+        ));
+    }
+
+    if let Some(direct_mate) = direct_mate {
+        passed &= mate.is_some_and(|mate| mate <= direct_mate);
+        expected.push(format!("dm {direct_mate}"));
+    }
+
+    let score = match mate {
+        _ if found == Move::NULL => String::from("-"),
+        Some(mate) => format!("mate {mate}"),
+        None => match pv.first_node().state() {
+            GameState::Win(len) => format!("mate -{}", (len + 1).div_ceil(2)),
+            GameState::Draw => String::from("cp 0"),
+            _ => format!("cp {}", pv.score().cp()),
+        },
+    };
+
+    let nodes = if search_engine.options().iters_as_nodes() {
+        search_stats.iterations()
+    } else {
+        search_stats.cumulative_depth()
+    };
+
+    Some(EpdTestResult {
+        id: epd.id().unwrap_or("-").to_string(),
+        fen: epd.fen().to_string(),
+        expected: expected.join(", "),
+        found: if found == Move::NULL {
+            String::from("-")
+        } else {
+            board.to_san(found)
+        },
+        score,
+        nodes,
+        time,
+        passed,
+    })
+}
+
+fn write_summary(path: &str, results: &[EpdTestResult]) -> std::io::Result<()> {
+    let mut file = File::create(path)?;
+
+    writeln!(file, "id,fen,expected,found,score,nodes,time_ms,result")?;
+    for result in results {
+        writeln!(
+            file,
+            "\"{}\",\"{}\",\"{}\",{},{},{},{},{}",
+            result.id.replace('"', "\"\""),
+            result.fen,
+            result.expected,
+            result.found,
+            result.score,
+            result.nodes,
+            result.time,
+            if result.passed { "pass" } else { "fail" }
+        )?;
+    }
+
+    Ok(())
+}
@@ -1,6 +1,8 @@
 use std::io::Write;
 
-use chess::{ChessBoard, ChessPosition, Piece, Side, Square, DEFAULT_PERFT_DEPTH, FEN};
+use chess::{
+    Bitboard, ChessBoard, ChessPosition, PerftMode, Piece, Side, Square, DEFAULT_PERFT_DEPTH, FEN,
+};
 use engine::{
     BasePolicyNetwork, BaseValueNetwork, NoReport, NodeIndex, SearchEngine, SearchLimits,
     Stage1PolicyNetwork, Stage1ValueNetwork, Stage2PolicyNetwork, Stage3PolicyNetwork,
@@ -11,6 +13,8 @@ use utils::{
     AlignString, Colors, CustomColor, PieceColors, Theme, DRAW_COLOR, LOSE_COLOR, WIN_COLOR,
 };
 
+use crate::processors::{epd_test, perft_hashed, perft_suite};
+
 pub struct MiscProcessor;
 impl MiscProcessor {
     pub fn execute(
@@ -83,7 +87,14 @@ impl MiscProcessor {
                 } else {
                     None
                 };
-                perft::<false, false>(search_engine, depth);
+
+                match args.get(1).map(|mode| PerftMode::parse(mode)) {
+                    None | Some(Some(PerftMode::Legal)) => {
+                        perft::<false, false>(search_engine, depth)
+                    }
+                    Some(Some(mode)) => perft_mode(search_engine, depth, mode),
+                    Some(None) => println!("Unknown perft mode, expected legal, pseudo or staged."),
+                }
             }
             "bulk" => {
                 let depth = if !args.is_empty() {
@@ -93,6 +104,8 @@ impl MiscProcessor {
                 };
                 perft::<true, false>(search_engine, depth);
             }
+            "perft-hash" => perft_hashed(args, search_engine),
+            "perft-suite" => perft_suite(args, search_engine),
             "bench" => {
                 let depth = if !args.is_empty() {
                     args[0].parse::<u64>().ok()
@@ -103,6 +116,17 @@ impl MiscProcessor {
                 let nps = result as f64 / duration.as_secs_f64();
                 println!("Bench: {result} nodes {:.0} nps", nps);
             }
+            "epdtest" => epd_test(args, search_engine),
+            "undo" => {
+                let count = if !args.is_empty() {
+                    args[0].parse::<usize>().ok()
+                } else {
+                    None
+                };
+
+                undo(search_engine, count.unwrap_or(1));
+            }
+            "result" => game_result(search_engine),
             "eval-bench" => eval_bench(),
             "policy" => draw_policy(search_engine),
             "eval" => eval(search_engine),
@@ -147,6 +171,63 @@ fn perft<const BULK: bool, const CHESS_960: bool>(search_engine: &SearchEngine,
     println!("-----------------------------------------------------------\n");
 }
 
+//Runs perft without bulk counting through the chosen generator, to compare their speed
+fn perft_mode(search_engine: &SearchEngine, depth: Option<u8>, mode: PerftMode) {
+    println!();
+
+    search_engine.root_position().board().draw_board();
+
+    println!("-----------------------------------------------------------");
+    println!("  Running PERFT");
+    println!("  Depth: {}", depth.unwrap_or(DEFAULT_PERFT_DEPTH));
+    println!("  Mode: {mode:?}");
+    println!("-----------------------------------------------------------\n");
+
+    let (result, duration) =
+        chess::perft_with_mode(search_engine.root_position().board(), depth, mode);
+    let miliseconds = duration.as_millis().max(1);
+
+    println!("-----------------------------------------------------------");
+    println!(
+        "  Perft ended! {result} nodes, {}, {}n/s",
+        time_to_string(miliseconds),
+        number_to_string(((result * 1000) as f64 / miliseconds as f64) as u128)
+    );
+    println!("-----------------------------------------------------------\n");
+}
+
+fn undo(search_engine: &mut SearchEngine, count: usize) {
+    let mut game = search_engine.game().clone();
+
+    let mut undone_moves = Vec::new();
+    for _ in 0..count {
+        match game.unmake_move() {
+            Some(mv) => undone_moves.push(mv.to_string(search_engine.options().chess960())),
+            None => break,
+        }
+    }
+
+    if undone_moves.is_empty() {
+        println!("No moves to undo.");
+        return;
+    }
+
+    search_engine.tree().clear();
+    search_engine.set_game(&game);
+
+    println!("Undone moves: {}", undone_moves.join(" "));
+}
+
+fn game_result(search_engine: &SearchEngine) {
+    let game = search_engine.game();
+
+    match (game.result(), game.claimable_draw()) {
+        (Some(result), _) => println!("Game over: {result}"),
+        (None, Some(reason)) => println!("Game is ongoing, a draw can be claimed by {reason}."),
+        (None, None) => println!("Game is ongoing."),
+    }
+}
+
 fn eval_bench() {
     const FENS: [&str; 8] = [
         "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
@@ -161,7 +242,7 @@ fn eval_bench() {
 
     println!("{}", "Base".secondary(0.5));
     for fen in FENS {
-        let board = ChessBoard::from(&FEN::from(fen));
+        let board = ChessBoard::try_from(fen).expect("Invalid eval position");
         let wdl_score = BaseValueNetwork.forward(&board);
         println!(
             "{}",
@@ -181,7 +262,7 @@ fn eval_bench() {
 
     println!("\n{}", "Stage 1".secondary(0.5));
     for fen in FENS {
-        let board = ChessBoard::from(&FEN::from(fen));
+        let board = ChessBoard::try_from(fen).expect("Invalid eval position");
         let wdl_score = Stage1ValueNetwork.forward(&board);
         println!(
             "{}",
@@ -201,7 +282,7 @@ fn eval_bench() {
 
     // println!("\n{}", "Stage 2".secondary(0.5));
     // for fen in FENS {
-    //     let board = ChessBoard::from(&FEN::from(fen));
+    //     let board = ChessBoard::try_from(fen).expect("Invalid eval position");
     //     let wdl_score = Stage2ValueNetwork.forward(&board);
     //     println!(
     //         "{}",
@@ -296,6 +377,41 @@ fn eval(search_engine: &SearchEngine) {
 
     // println!("{}", "Stage 2".secondary(0.5));
     // print_network_eval(board, &Stage2ValueNetwork, search_engine);
+
+    println!("{}", "Tactics".secondary(0.5));
+    print_tactics(board);
+}
+
+fn print_tactics(board: &ChessBoard) {
+    let squares = |mask: Bitboard| {
+        let mut result = Vec::new();
+        mask.map(|square| result.push(square.to_string()));
+        if result.is_empty() {
+            String::from("-")
+        } else {
+            result.join(" ")
+        }
+    };
+
+    for (name, side) in [("White", Side::WHITE), ("Black", Side::BLACK)] {
+        println!("{}", format!("{name}:").primary(10.0 / 32.0));
+        for (label, mask, gradient) in [
+            ("Hanging:", board.hanging_pieces(side), 10.0),
+            ("Undefended:", board.undefended_pieces(side), 12.0),
+            ("Pinned:", board.pinned_pieces(side), 14.0),
+            (
+                "Discovered:",
+                board.discovered_attack_candidates(side),
+                16.0,
+            ),
+        ] {
+            println!(
+                "  {} {}",
+                label.align_to_left(19).primary(gradient / 32.0),
+                squares(mask).secondary(gradient / 32.0)
+            );
+        }
+    }
 }
 
 fn print_network_eval(board: &ChessBoard, network: &ValueNetwork, search_engine: &SearchEngine) {
@@ -1,7 +1,12 @@
 mod command_line_processor;
+mod epd_test;
 mod misc_processor;
+mod perft_suite;
 mod uci_processor;
 
 pub use command_line_processor::process_command_line_args;
+pub use epd_test::epd_test;
 pub use misc_processor::MiscProcessor;
+pub use perft_suite::perft_hashed;
+pub use perft_suite::perft_suite;
 pub use uci_processor::UciProcessor;
@@ -0,0 +1,192 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    time::Instant,
+};
+
+use chess::{perft_divide, perft_parallel, ChessBoard, Epd, PerftTable, DEFAULT_PERFT_DEPTH};
+use engine::SearchEngine;
+use utils::{number_to_string, time_to_string, AlignString, Colors};
+
+const DEFAULT_PERFT_HASH_MB: usize = 64;
+
+pub fn perft_suite(args: &[String], search_engine: &SearchEngine) {
+    let Some(path) = args.first() else {
+        println!("Usage: perft-suite <file> [threads <n>] [hash <mb>] [depth <max>]");
+        return;
+    };
+
+    let (threads, hash, max_depth) = parse_perft_args(&args[1..]);
+
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(err) => {
+            println!("Failed to open {path}: {err}");
+            return;
+        }
+    };
+
+    println!("-----------------------------------------------------------");
+    println!("  Running PERFT suite");
+    println!("  File: {path}");
+    println!("  Threads: {threads}");
+    println!("  Hash: {hash}MB");
+    if let Some(max_depth) = max_depth {
+        println!("  Max depth: {max_depth}");
+    }
+    println!("-----------------------------------------------------------\n");
+
+    let table = PerftTable::new(hash);
+    let chess960 = search_engine.options().chess960();
+    let timer = Instant::now();
+
+    let mut total_nodes = 0u128;
+    let mut passed = 0usize;
+    let mut failed = 0usize;
+
+    for (line_idx, line) in BufReader::new(file).lines().enumerate() {
+        let Ok(line) = line else {
+            break;
+        };
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let epd = match Epd::parse(&line) {
+            Ok(epd) => epd,
+            Err(err) => {
+                println!(" Line {}: skipped, {err}", line_idx + 1);
+                continue;
+            }
+        };
+
+        let board = match ChessBoard::from_fen_checked(epd.fen()) {
+            Ok(board) => board,
+            Err(err) => {
+                println!(" Line {}: skipped, {err}", line_idx + 1);
+                continue;
+            }
+        };
+
+        let mut depths = epd
+            .operations()
+            .filter_map(|(opcode, operands)| {
+                let depth = opcode.strip_prefix('D')?.parse::<u8>().ok()?;
+                let expected = operands.first()?.parse::<u128>().ok()?;
+                Some((depth, expected))
+            })
+            .filter(|&(depth, _)| max_depth.is_none_or(|max_depth| depth <= max_depth))
+            .collect::<Vec<_>>();
+        depths.sort_unstable();
+
+        if depths.is_empty() {
+            println!(" Line {}: skipped, no depth operations", line_idx + 1);
+            continue;
+        }
+
+        let mut mismatch = None;
+        for &(depth, expected) in &depths {
+            let (result, _) = perft_parallel(&board, Some(depth), threads, Some(&table));
+            total_nodes += result;
+
+            if result != expected {
+                mismatch = Some((depth, expected, result));
+                break;
+            }
+        }
+
+        let Some((depth, expected, result)) = mismatch else {
+            passed += 1;
+            println!(
+                " {} {} {}",
+                (line_idx + 1).to_string().align_to_left(5),
+                "PASS".green(),
+                epd.fen()
+            );
+            continue;
+        };
+
+        failed += 1;
+        println!(
+            " {} {} {}",
+            (line_idx + 1).to_string().align_to_left(5),
+            "FAIL".red(),
+            epd.fen()
+        );
+        println!("       Depth {depth}: expected {expected}, found {result}");
+
+        //Per root move counts, to continue the search for the bug from the faulty move
+        for (mv, count) in perft_divide(&board, depth, threads, Some(&table)) {
+            println!("         {} - {count}", mv.to_string(chess960));
+        }
+    }
+
+    let duration = timer.elapsed().as_millis().max(1);
+
+    println!("\n-----------------------------------------------------------");
+    println!(
+        "  PERFT suite ended! {passed}/{} passed, {} nodes, {}, {}n/s",
+        passed + failed,
+        number_to_string(total_nodes),
+        time_to_string(duration),
+        number_to_string(((total_nodes * 1000) as f64 / duration as f64) as u128)
+    );
+    println!("-----------------------------------------------------------\n");
+}
+
+pub fn perft_hashed(args: &[String], search_engine: &SearchEngine) {
+    let depth = args.first().and_then(|depth| depth.parse::<u8>().ok());
+    let (threads, hash, _) = parse_perft_args(args);
+
+    println!();
+
+    search_engine.root_position().board().draw_board();
+
+    println!("-----------------------------------------------------------");
+    println!("  Running PERFT");
+    println!("  Depth: {}", depth.unwrap_or(DEFAULT_PERFT_DEPTH));
+    println!("  Threads: {threads}");
+    println!("  Hash: {hash}MB");
+    println!("-----------------------------------------------------------\n");
+
+    let table = PerftTable::new(hash);
+    let (result, duration) = perft_parallel(
+        search_engine.root_position().board(),
+        depth,
+        threads,
+        Some(&table),
+    );
+    let miliseconds = duration.as_millis().max(1);
+
+    println!("-----------------------------------------------------------");
+    println!(
+        "  Perft ended! {result} nodes, {}, {}n/s",
+        time_to_string(miliseconds),
+        number_to_string(((result * 1000) as f64 / miliseconds as f64) as u128)
+    );
+    println!("-----------------------------------------------------------\n");
+}
+
+fn parse_perft_args(args: &[String]) -> (usize, usize, Option<u8>) {
+    let mut threads = 1;
+    let mut hash = DEFAULT_PERFT_HASH_MB;
+    let mut max_depth = None;
+
+    for (idx, arg) in args.iter().enumerate() {
+        let value = args.get(idx + 1);
+        match arg.as_str() {
+            "threads" => {
+                threads = value
+                    .and_then(|value| value.parse::<usize>().ok())
+                    .unwrap_or(threads)
+                    .max(1)
+            }
+            "hash" => hash = value.and_then(|value| value.parse().ok()).unwrap_or(hash),
+            "depth" => max_depth = value.and_then(|value| value.parse().ok()),
+            _ => continue,
+        }
+    }
+
+    (threads, hash, max_depth)
+}
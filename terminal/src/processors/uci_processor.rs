@@ -1,4 +1,4 @@
-use chess::{ChessBoard, ChessPosition, Side, FEN};
+use chess::{ChessBoard, Game, Side, Variant, FEN};
 use engine::{SearchEngine, SearchLimits};
 use utils::clear_terminal_screen;
 
@@ -75,7 +75,16 @@ impl UciProcessor {
                 return;
             }
 
-            if name.eq_ignore_ascii_case("hash") {
+            if [
+                "hash",
+                "threads",
+                "largepages",
+                "numapolicy",
+                "memorymanager",
+            ]
+            .iter()
+            .any(|option| name.eq_ignore_ascii_case(option))
+            {
                 search_engine.resize_tree();
             }
 
@@ -88,20 +97,40 @@ impl UciProcessor {
     }
 
     fn position(&self, args: &[String], search_engine: &mut SearchEngine) {
+        let variant = match Variant::parse(&search_engine.options().uci_variant()) {
+            Some(variant) => variant,
+            None => {
+                self.uci_print(
+                    "Provided UCI_Variant is not supported, expected chess, kingofthehill, 3check, horde or crazyhouse.",
+                    search_engine.options().minimal_print(),
+                );
+                return;
+            }
+        };
+
         let mut move_flag = false;
         let mut fen_flag = false;
+        let mut frc_flag = false;
         let mut fen = String::new();
+        let mut frc_indices = Vec::new();
         let mut moves = Vec::new();
 
         for arg in args {
             match arg.as_str() {
-                "startpos" => fen = String::from(FEN::start_position()),
+                "startpos" => fen = String::from(variant.start_position()),
                 "fen" => {
                     fen_flag = true;
+                    frc_flag = false;
+                    move_flag = false;
+                }
+                "frc" | "dfrc" => {
+                    fen_flag = false;
+                    frc_flag = true;
                     move_flag = false;
                 }
                 "moves" => {
                     fen_flag = false;
+                    frc_flag = false;
                     move_flag = true;
                 }
                 _ => {
@@ -109,6 +138,10 @@ impl UciProcessor {
                         fen.push_str(&format!("{arg} "))
                     }
 
+                    if frc_flag {
+                        frc_indices.push(arg.parse::<u16>().ok().filter(|&index| index < 960))
+                    }
+
                     if move_flag {
                         moves.push(arg)
                     }
@@ -116,30 +149,73 @@ impl UciProcessor {
             }
         }
 
-        if !FEN::validate_fen(&fen) {
+        if !frc_indices.is_empty() {
+            fen = match frc_indices.as_slice() {
+                [Some(index)] => String::from(FEN::chess960(*index)),
+                [Some(white_index), Some(black_index)] => {
+                    String::from(FEN::double_fischer_random(*white_index, *black_index))
+                }
+                _ => {
+                    self.uci_print(
+                        "Provided frc index is invalid, expected one or two numbers in range 0-959.",
+                        search_engine.options().minimal_print(),
+                    );
+                    return;
+                }
+            };
+        }
+
+        let fen = match FEN::parse(&fen) {
+            Ok(fen) => fen,
+            Err(err) => {
+                self.uci_print(
+                    format!("Provided fen is invalid: {err}.").as_str(),
+                    search_engine.options().minimal_print(),
+                );
+                return;
+            }
+        };
+
+        let board = match ChessBoard::from_fen_with_variant(&fen, variant) {
+            Ok(board) => board,
+            Err(err) => {
+                self.uci_print(
+                    format!("Provided position is illegal: {err}.").as_str(),
+                    search_engine.options().minimal_print(),
+                );
+                return;
+            }
+        };
+
+        //Castling in a shuffled position can only be written as king takes rook, so the notation
+        //follows the position instead of trusting the option
+        if board.castle_rights().is_chess960() && !search_engine.options().chess960() {
+            let _ = search_engine.set_option("UCI_Chess960", "true");
             self.uci_print(
-                "Provided fen is invalid.",
+                "UCI_Chess960 has been enabled to castle in this position.",
                 search_engine.options().minimal_print(),
             );
-            return;
         }
 
-        let mut chess_position = ChessPosition::from(ChessBoard::from(&FEN::from(fen)));
+        let mut game = Game::from(board);
         for &mv in &moves {
-            chess_position.board().clone().map_legal_moves(|legal_mv| {
+            let mut found_move = None;
+            game.board().map_legal_moves(|legal_mv| {
                 if *mv == legal_mv.to_string(search_engine.options().chess960()) {
-                    chess_position.make_move_no_mask(legal_mv);
+                    found_move = Some(legal_mv);
                 }
             });
+
+            if let Some(legal_mv) = found_move {
+                game.make_move(legal_mv);
+            }
         }
 
-        search_engine.tree().try_reuse(
-            search_engine.root_position(),
-            &chess_position,
-            search_engine.options(),
-        );
+        search_engine
+            .tree()
+            .try_reuse_game(search_engine.game(), &game, search_engine.options());
 
-        search_engine.set_position(&chess_position, moves.len() as u16);
+        search_engine.set_game(&game);
         self.uci_print(
             "Position has been set.",
             search_engine.options().minimal_print(),